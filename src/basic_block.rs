@@ -1,7 +1,11 @@
 use crate::core::context::ContextRef;
 use crate::core::values::ValueRef;
-use crate::CString;
-use llvm_sys::core::LLVMAppendBasicBlockInContext;
+use crate::error::Error;
+use crate::{CString, GetRef};
+use llvm_sys::core::{
+    LLVMAppendBasicBlockInContext, LLVMCountBasicBlocks, LLVMGetFirstBasicBlock,
+    LLVMGetLastBasicBlock, LLVMGetNextBasicBlock, LLVMGetPreviousBasicBlock,
+};
 use llvm_sys::prelude::LLVMBasicBlockRef;
 
 /// LLVM Basic block wrapper
@@ -15,16 +19,145 @@ impl BasicBlockRef {
     }
 
     /// Append basic block in context
-    /// TODO: return error
-    #[must_use]
-    pub fn append_in_context(context: &ContextRef, function: &ValueRef, name: &str) -> Self {
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NulInString`] if `name` contains an interior NUL byte.
+    pub fn append_in_context(
+        context: &ContextRef,
+        function: &ValueRef,
+        name: &str,
+    ) -> Result<Self, Error> {
+        let c_name = CString::try_from(name)?;
         unsafe {
-            let c_name = CString::from(name);
-            Self(LLVMAppendBasicBlockInContext(
+            Ok(Self(LLVMAppendBasicBlockInContext(
                 **context,
                 **function,
                 c_name.as_ptr(),
-            ))
+            )))
+        }
+    }
+
+    /// Obtain the first basic block in a function.
+    ///
+    /// This function wraps the `LLVMGetFirstBasicBlock` function from the LLVM core library.
+    #[must_use]
+    pub fn get_first(function: &ValueRef) -> Option<Self> {
+        let block = unsafe { LLVMGetFirstBasicBlock(function.get_ref()) };
+        if block.is_null() {
+            None
+        } else {
+            Some(Self(block))
+        }
+    }
+
+    /// Obtain the last basic block in a function.
+    ///
+    /// This function wraps the `LLVMGetLastBasicBlock` function from the LLVM core library.
+    #[must_use]
+    pub fn get_last(function: &ValueRef) -> Option<Self> {
+        let block = unsafe { LLVMGetLastBasicBlock(function.get_ref()) };
+        if block.is_null() {
+            None
+        } else {
+            Some(Self(block))
+        }
+    }
+
+    /// Advance to the next basic block in the function's block list.
+    ///
+    /// This function wraps the `LLVMGetNextBasicBlock` function from the LLVM core library.
+    /// Returns `None` if this was already the last basic block.
+    #[must_use]
+    pub fn get_next(&self) -> Option<Self> {
+        let block = unsafe { LLVMGetNextBasicBlock(self.0) };
+        if block.is_null() {
+            None
+        } else {
+            Some(Self(block))
+        }
+    }
+
+    /// Step back to the previous basic block in the function's block list.
+    ///
+    /// This function wraps the `LLVMGetPreviousBasicBlock` function from the LLVM core library.
+    /// Returns `None` if this was already the first basic block.
+    #[must_use]
+    pub fn get_previous(&self) -> Option<Self> {
+        let block = unsafe { LLVMGetPreviousBasicBlock(self.0) };
+        if block.is_null() {
+            None
+        } else {
+            Some(Self(block))
+        }
+    }
+
+    /// Obtain the number of basic blocks in a function.
+    ///
+    /// This function wraps the `LLVMCountBasicBlocks` function from the LLVM core library.
+    #[must_use]
+    pub fn count(function: &ValueRef) -> u32 {
+        unsafe { LLVMCountBasicBlocks(function.get_ref()) }
+    }
+}
+
+/// An iterator over a function's basic blocks.
+///
+/// Walks the block list using `LLVMGetFirstBasicBlock`/`LLVMGetNextBasicBlock` (and, from the
+/// back, `LLVMGetLastBasicBlock`/`LLVMGetPreviousBasicBlock`) internally, so callers can write
+/// `for bb in function.basic_blocks_iter()` instead of manual pointer-chasing loops.
+pub struct BasicBlocks {
+    front: Option<BasicBlockRef>,
+    back: Option<BasicBlockRef>,
+    remaining: usize,
+}
+
+impl Iterator for BasicBlocks {
+    type Item = BasicBlockRef;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.front.take()?;
+        self.remaining = self.remaining.saturating_sub(1);
+        if self.back.as_ref().is_some_and(|back| back.0 == current.0) {
+            self.back = None;
+        } else {
+            self.front = current.get_next();
+        }
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl DoubleEndedIterator for BasicBlocks {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let current = self.back.take()?;
+        self.remaining = self.remaining.saturating_sub(1);
+        if self.front.as_ref().is_some_and(|front| front.0 == current.0) {
+            self.front = None;
+        } else {
+            self.back = current.get_previous();
+        }
+        Some(current)
+    }
+}
+
+impl ExactSizeIterator for BasicBlocks {
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl ValueRef {
+    /// Returns an iterator over the basic blocks of this function, in order.
+    #[must_use]
+    pub fn basic_blocks_iter(&self) -> BasicBlocks {
+        BasicBlocks {
+            front: BasicBlockRef::get_first(self),
+            back: BasicBlockRef::get_last(self),
+            remaining: BasicBlockRef::count(self) as usize,
         }
     }
 }
@@ -0,0 +1,733 @@
+use crate::core::types::{DiagnosticSeverity, TypeRef};
+use crate::{CInt, CStr, CString, CUint, GetRef, SizeT, UnsafeMutVoidPtr};
+use llvm_sys::core;
+use llvm_sys::prelude::{LLVMAttributeRef, LLVMContextRef, LLVMDiagnosticInfoRef};
+use llvm_sys::{LLVMDiagnosticHandler, LLVMYieldCallback};
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::ops::Deref;
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+/// LLVM Context wrapper
+pub struct ContextRef(LLVMContextRef, bool, Cell<bool>);
+
+impl From<LLVMContextRef> for ContextRef {
+    /// Wrap a raw, borrowed context pointer.
+    ///
+    /// The resulting `ContextRef` does not own the underlying context: dropping it will
+    /// not call `LLVMContextDispose`. This matches the common case of wrapping a context
+    /// pointer handed back by LLVM (e.g. as a module's or value's parent context), whose
+    /// lifetime is managed elsewhere.
+    fn from(value: LLVMContextRef) -> Self {
+        Self(value, false, Cell::new(false))
+    }
+}
+
+impl ContextRef {
+    /// Create new LLVM Context
+    #[must_use]
+    pub fn new() -> Self {
+        Self::create()
+    }
+}
+
+/// LLVM specific implementations
+impl ContextRef {
+    /// Create a new context.
+    ///
+    /// ## Safety
+    /// Every call to this function should be paired with a call to
+    /// `Self::context_dispose` or the context will leak memory.
+    #[must_use]
+    pub fn create() -> Self {
+        unsafe { Self(core::LLVMContextCreate(), true, Cell::new(false)) }
+    }
+
+    /// Retrieves the global context instance.
+    ///
+    /// The global context is particularly convenient instance managed by LLVM
+    /// itself. It is the default context provided for any operations that
+    /// require it.
+    ///
+    /// ### Safety
+    /// Failure to specify the correct context in concurrent
+    /// environments can lead to data corruption.  In general, it is always
+    /// recommended that each thread of execution attempting to access the LLVM
+    /// API have its own `Context` instance, rather than rely on this global
+    /// context.
+    #[must_use]
+    pub fn get_global_context() -> Self {
+        unsafe { Self(core::LLVMGetGlobalContext(), false, Cell::new(false)) }
+    }
+
+    /// Whether this `ContextRef` owns the underlying LLVM context.
+    ///
+    /// An owning `ContextRef` (created via [`Self::create`] or [`Self::new`]) disposes of
+    /// the context when dropped. A non-owning one — such as [`Self::get_global_context`] or
+    /// a context wrapped `From<LLVMContextRef>` — leaves disposal to whoever actually owns
+    /// it, so `Drop` is a no-op.
+    #[must_use]
+    pub fn is_owned(&self) -> bool {
+        self.1
+    }
+
+    /// Set debug diagnostic handler for this context.
+    ///
+    /// ## Safety
+    /// To provide safe operations wi with diagnostic context should be set:
+    /// - `handler` - LLVM diagnostic function (handler)
+    /// - `diagnostic_context` - raw pointer for diagnostic
+    /// NOTE: it's much safer to use raw pointer in that case than `std::ptr::NonNull` structs.
+    pub fn set_diagnostic_handler(
+        &self,
+        handler: LLVMDiagnosticHandler,
+        diagnostic_context: UnsafeMutVoidPtr,
+    ) {
+        unsafe {
+            core::LLVMContextSetDiagnosticHandler(self.0, handler, *diagnostic_context);
+        }
+    }
+
+    /// Get the diagnostic handler of this context.
+    #[must_use]
+    pub fn get_diagnostic_handler(&self) -> LLVMDiagnosticHandler {
+        unsafe { core::LLVMContextGetDiagnosticHandler(self.0) }
+    }
+
+    /// Get the diagnostic context of this context.
+    #[must_use]
+    pub fn get_diagnostic_context(&self) -> UnsafeMutVoidPtr {
+        unsafe {
+            let raw_ptr = core::LLVMContextGetDiagnosticContext(self.0);
+            UnsafeMutVoidPtr(raw_ptr)
+        }
+    }
+
+    /// Set a safe, closure-based diagnostic handler for this context.
+    ///
+    /// ## Details
+    /// This is a safe alternative to [`Self::set_diagnostic_handler`]: instead of an
+    /// `extern "C"` function pointer and a raw opaque context pointer, it accepts an
+    /// ordinary Rust closure and takes care of boxing it, registering a trampoline with
+    /// LLVM, and routing each diagnostic back to the closure as a [`DiagnosticInfoRef`].
+    ///
+    /// The closure is stored in a process-wide table keyed by this context's raw pointer,
+    /// so it lives until either this method is called again for the same context or the
+    /// context is disposed.
+    ///
+    /// ## Parameters
+    /// - `f`: called with each [`DiagnosticInfoRef`] LLVM reports for this context.
+    pub fn set_diagnostic_handler_closure<F>(&self, f: F)
+    where
+        F: FnMut(DiagnosticInfoRef) + 'static,
+    {
+        diagnostic_handlers()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(self.0 as usize, Box::new(f));
+        unsafe {
+            core::LLVMContextSetDiagnosticHandler(
+                self.0,
+                Some(diagnostic_handler_trampoline),
+                self.0.cast::<c_void>(),
+            );
+        }
+    }
+
+    /// Set the yield callback function for this context.
+    pub fn set_yield_callback(&self, callback: LLVMYieldCallback, opaque_handle: UnsafeMutVoidPtr) {
+        unsafe { core::LLVMContextSetYieldCallback(self.0, callback, *opaque_handle) }
+    }
+
+    /// Set a safe, closure-based yield callback for this context.
+    ///
+    /// ## Details
+    /// This is a safe alternative to [`Self::set_yield_callback`]: LLVM's yield callback is
+    /// a cooperative-multitasking hook that fires periodically during long-running
+    /// operations so the embedder can service other work or request cancellation. Instead
+    /// of an `extern "C"` function pointer and a raw opaque handle, this accepts an
+    /// ordinary Rust closure, which is called with a borrowed (non-owning) [`ContextRef`]
+    /// reconstructed from the raw context pointer LLVM passes into the callback.
+    ///
+    /// The closure is stored in a process-wide table keyed by this context's raw pointer,
+    /// so it lives until either this method is called again for the same context or the
+    /// context is disposed.
+    pub fn set_yield_callback_closure<F>(&self, f: F)
+    where
+        F: FnMut(&ContextRef) + 'static,
+    {
+        yield_callbacks()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(self.0 as usize, Box::new(f));
+        unsafe {
+            core::LLVMContextSetYieldCallback(
+                self.0,
+                Some(yield_callback_trampoline),
+                self.0.cast::<c_void>(),
+            );
+        }
+    }
+
+    /// Retrieve whether the given context is set to discard all value names.
+    #[must_use]
+    pub fn should_discard_value_names(&self) -> bool {
+        unsafe { core::LLVMContextShouldDiscardValueNames(self.0) != 0 }
+    }
+
+    /// Set whether the given context discards all value names.
+    ///
+    /// If true, only the names of `GlobalValue` objects will be available in the IR.
+    /// This can be used to save memory and runtime, especially in release mode.
+    pub fn set_discard_value_names(&self, discard: bool) {
+        unsafe {
+            core::LLVMContextSetDiscardValueNames(self.get_ref(), *CInt::from(discard));
+        }
+    }
+
+    /// Deinitialize this value and dispose of its resources.
+    ///
+    /// Destroy a context instance.
+    ///
+    /// `ContextRef` disposes of an owned context automatically when dropped, so calling this
+    /// explicitly is only needed to free the context's resources earlier than the end of its
+    /// scope. It is idempotent: calling it more than once, or letting `Drop` run afterwards,
+    /// disposes the underlying context at most once.
+    pub fn dispose(&self) {
+        if self.2.replace(true) {
+            return;
+        }
+        diagnostic_handlers()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&(self.0 as usize));
+        yield_callbacks()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&(self.0 as usize));
+        unsafe { core::LLVMContextDispose(self.get_ref()) }
+    }
+
+    /// Get  Metadata `KindId` by name in current Context.
+    /// Useful for working with Metadata.
+    #[must_use]
+    pub fn get_md_kind_id_in_context(&self, name: &str) -> MetadataKindId {
+        MetadataKindId::get_md_kind_id_in_context(self, name)
+    }
+
+    /// Create an enum attribute.
+    #[must_use]
+    pub fn create_enum_attribute(&self, kind_id: u32, val: u64) -> AttributeRef {
+        AttributeRef::create_enum_attribute(self, kind_id, val)
+    }
+
+    /// Create the `align(bytes)` parameter/function attribute.
+    #[must_use]
+    pub fn create_align_attribute(&self, bytes: u64) -> AttributeRef {
+        AttributeRef::create_enum_attribute(self, EnumAttributeKind::Align.kind_id(), bytes)
+    }
+
+    /// Create the `dereferenceable(bytes)` parameter attribute.
+    #[must_use]
+    pub fn create_dereferenceable_attribute(&self, bytes: u64) -> AttributeRef {
+        AttributeRef::create_enum_attribute(
+            self,
+            EnumAttributeKind::Dereferenceable.kind_id(),
+            bytes,
+        )
+    }
+
+    /// Create the `dereferenceable_or_null(bytes)` parameter attribute.
+    #[must_use]
+    pub fn create_dereferenceable_or_null_attribute(&self, bytes: u64) -> AttributeRef {
+        AttributeRef::create_enum_attribute(
+            self,
+            EnumAttributeKind::DereferenceableOrNull.kind_id(),
+            bytes,
+        )
+    }
+
+    /// Create a valueless flag attribute such as `noalias` or `nounwind`.
+    #[must_use]
+    pub fn create_flag_attribute(&self, kind: EnumAttributeKind) -> AttributeRef {
+        AttributeRef::create_enum_attribute(self, kind.kind_id(), 0)
+    }
+
+    /// Create a type attribute in context
+    #[must_use]
+    pub fn create_type_attribute(&self, kind_id: u32, type_ref: &TypeRef<'_>) -> AttributeRef {
+        AttributeRef::create_type_attribute(self, kind_id, type_ref)
+    }
+
+    /// Create a string attribute in context
+    #[must_use]
+    pub fn create_string_attribute(&self, key: &str, value: &str) -> AttributeRef {
+        AttributeRef::create_string_attribute(self, key, value)
+    }
+
+    /// Obtain a Type from a context by its registered name.
+    #[must_use]
+    pub fn get_type_by_name2(&self, name: &str) -> Option<TypeRef<'_>> {
+        let c_name = CString::try_from(name).expect("string contains an interior NUL byte");
+        let type_ref = unsafe { core::LLVMGetTypeByName2(self.get_ref(), c_name.as_ptr()) };
+        if type_ref.is_null() {
+            None
+        } else {
+            Some(TypeRef::from(type_ref))
+        }
+    }
+}
+
+/// Process-wide table of boxed closures registered via
+/// [`ContextRef::set_diagnostic_handler_closure`], keyed by the raw `LLVMContextRef` pointer
+/// of the context they were registered for.
+type DiagnosticHandlerTable = Mutex<HashMap<usize, Box<dyn FnMut(DiagnosticInfoRef) + 'static>>>;
+
+fn diagnostic_handlers() -> &'static DiagnosticHandlerTable {
+    static HANDLERS: OnceLock<DiagnosticHandlerTable> = OnceLock::new();
+    HANDLERS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// `extern "C"` trampoline passed to `LLVMContextSetDiagnosticHandler` by
+/// [`ContextRef::set_diagnostic_handler_closure`]. Looks up the closure registered for
+/// `diagnostic_context` (the context's raw pointer) and invokes it with the diagnostic.
+unsafe extern "C" fn diagnostic_handler_trampoline(
+    info: LLVMDiagnosticInfoRef,
+    diagnostic_context: *mut c_void,
+) {
+    let key = diagnostic_context as usize;
+    if let Some(handler) = diagnostic_handlers()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get_mut(&key)
+    {
+        handler(DiagnosticInfoRef::from(info));
+    }
+}
+
+/// Process-wide table of boxed closures registered via
+/// [`ContextRef::set_yield_callback_closure`], keyed by the raw `LLVMContextRef` pointer of
+/// the context they were registered for.
+type YieldCallbackTable = Mutex<HashMap<usize, Box<dyn FnMut(&ContextRef) + 'static>>>;
+
+fn yield_callbacks() -> &'static YieldCallbackTable {
+    static CALLBACKS: OnceLock<YieldCallbackTable> = OnceLock::new();
+    CALLBACKS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// `extern "C"` trampoline passed to `LLVMContextSetYieldCallback` by
+/// [`ContextRef::set_yield_callback_closure`]. Looks up the closure registered for
+/// `opaque_handle` (the context's raw pointer) and invokes it with a borrowed `ContextRef`
+/// reconstructed from `context`.
+unsafe extern "C" fn yield_callback_trampoline(context: LLVMContextRef, opaque_handle: *mut c_void) {
+    let key = opaque_handle as usize;
+    let borrowed = ContextRef::from(context);
+    if let Some(handler) = yield_callbacks()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get_mut(&key)
+    {
+        handler(&borrowed);
+    }
+}
+
+impl Drop for ContextRef {
+    /// Dispose of the context, but only if this `ContextRef` owns it.
+    ///
+    /// Borrowed handles — [`ContextRef::get_global_context`] or anything built via
+    /// `From<LLVMContextRef>` — must not be disposed here, since LLVM or another
+    /// `ContextRef` is responsible for them.
+    fn drop(&mut self) {
+        if self.1 {
+            self.dispose();
+        }
+    }
+}
+
+impl Deref for ContextRef {
+    type Target = LLVMContextRef;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl GetRef for ContextRef {
+    type RawRef = LLVMContextRef;
+    fn get_ref(&self) -> Self::RawRef {
+        self.0
+    }
+}
+
+/// Wrapper for `MetadataKindId`
+pub struct MetadataKindId(pub u32);
+
+impl MetadataKindId {
+    /// Get `MetadataKindId` by name in current `Context`.
+    /// Useful for working with Metadata.
+    #[must_use]
+    pub fn get_md_kind_id_in_context(context: &ContextRef, name: &str) -> Self {
+        let c_name = CString::try_from(name).expect("string contains an interior NUL byte");
+        let id = unsafe {
+            core::LLVMGetMDKindIDInContext(
+                context.get_ref(),
+                c_name.as_ptr(),
+                *CUint::try_from(c_name.to_bytes().len()).expect("value does not fit in c_uint"),
+            )
+        };
+        Self(id)
+    }
+
+    /// Get  Metadata `KindId` by name.
+    /// Useful for working with Metadata.
+    #[must_use]
+    pub fn get_md_kind_id(name: &str) -> Self {
+        let c_name = CString::try_from(name).expect("string contains an interior NUL byte");
+        let id = unsafe {
+            core::LLVMGetMDKindID(c_name.as_ptr(), *CUint::try_from(c_name.to_bytes().len()).expect("value does not fit in c_uint"))
+        };
+        Self(id)
+    }
+}
+
+/// A subset of LLVM's enum (non-string) parameter/function attributes from the LangRef,
+/// identified by name rather than by a raw `u64` "kind id" looked up by hand.
+///
+/// Variants that take an integer operand — [`Self::Align`], [`Self::Dereferenceable`],
+/// [`Self::DereferenceableOrNull`] — are built through the dedicated `ContextRef` methods
+/// (e.g. [`ContextRef::create_align_attribute`]) that pair the kind id with the value.
+/// Valueless variants are built through [`ContextRef::create_flag_attribute`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EnumAttributeKind {
+    /// `align(<n>)`
+    Align,
+    /// `alwaysinline`
+    AlwaysInline,
+    /// `builtin`
+    Builtin,
+    /// `cold`
+    Cold,
+    /// `convergent`
+    Convergent,
+    /// `dereferenceable(<n>)`
+    Dereferenceable,
+    /// `dereferenceable_or_null(<n>)`
+    DereferenceableOrNull,
+    /// `inlinehint`
+    InlineHint,
+    /// `minsize`
+    MinSize,
+    /// `naked`
+    Naked,
+    /// `noalias`
+    NoAlias,
+    /// `nobuiltin`
+    NoBuiltin,
+    /// `nocapture`
+    NoCapture,
+    /// `noduplicate`
+    NoDuplicate,
+    /// `nofree`
+    NoFree,
+    /// `noinline`
+    NoInline,
+    /// `nonlazybind`
+    NonLazyBind,
+    /// `nonnull`
+    NonNull,
+    /// `noredzone`
+    NoRedZone,
+    /// `noreturn`
+    NoReturn,
+    /// `norecurse`
+    NoRecurse,
+    /// `nounwind`
+    NoUnwind,
+    /// `optsize`
+    OptimizeForSize,
+    /// `optnone`
+    OptimizeNone,
+    /// `readnone`
+    ReadNone,
+    /// `readonly`
+    ReadOnly,
+    /// `returned`
+    Returned,
+    /// `returns_twice`
+    ReturnsTwice,
+    /// `alignstack(<n>)`
+    StackAlignment,
+    /// `ssp`
+    StackProtect,
+    /// `sspreq`
+    StackProtectReq,
+    /// `sspstrong`
+    StackProtectStrong,
+    /// `speculatable`
+    Speculatable,
+    /// `strictfp`
+    StrictFp,
+    /// `sanitize_address`
+    SanitizeAddress,
+    /// `sanitize_memory`
+    SanitizeMemory,
+    /// `sanitize_thread`
+    SanitizeThread,
+    /// `uwtable`
+    UwTable,
+    /// `willreturn`
+    WillReturn,
+    /// `writeonly`
+    WriteOnly,
+}
+
+impl EnumAttributeKind {
+    /// The LLVM LangRef attribute name this variant corresponds to.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Align => "align",
+            Self::AlwaysInline => "alwaysinline",
+            Self::Builtin => "builtin",
+            Self::Cold => "cold",
+            Self::Convergent => "convergent",
+            Self::Dereferenceable => "dereferenceable",
+            Self::DereferenceableOrNull => "dereferenceable_or_null",
+            Self::InlineHint => "inlinehint",
+            Self::MinSize => "minsize",
+            Self::Naked => "naked",
+            Self::NoAlias => "noalias",
+            Self::NoBuiltin => "nobuiltin",
+            Self::NoCapture => "nocapture",
+            Self::NoDuplicate => "noduplicate",
+            Self::NoFree => "nofree",
+            Self::NoInline => "noinline",
+            Self::NonLazyBind => "nonlazybind",
+            Self::NonNull => "nonnull",
+            Self::NoRedZone => "noredzone",
+            Self::NoReturn => "noreturn",
+            Self::NoRecurse => "norecurse",
+            Self::NoUnwind => "nounwind",
+            Self::OptimizeForSize => "optsize",
+            Self::OptimizeNone => "optnone",
+            Self::ReadNone => "readnone",
+            Self::ReadOnly => "readonly",
+            Self::Returned => "returned",
+            Self::ReturnsTwice => "returns_twice",
+            Self::StackAlignment => "alignstack",
+            Self::StackProtect => "ssp",
+            Self::StackProtectReq => "sspreq",
+            Self::StackProtectStrong => "sspstrong",
+            Self::Speculatable => "speculatable",
+            Self::StrictFp => "strictfp",
+            Self::SanitizeAddress => "sanitize_address",
+            Self::SanitizeMemory => "sanitize_memory",
+            Self::SanitizeThread => "sanitize_thread",
+            Self::UwTable => "uwtable",
+            Self::WillReturn => "willreturn",
+            Self::WriteOnly => "writeonly",
+        }
+    }
+
+    /// Resolve this variant's LLVM attribute kind id, caching the lookup.
+    ///
+    /// The first call for a given variant resolves it via
+    /// [`AttributeRef::get_enum_attribute_kind_for_name`]; subsequent calls (for any
+    /// context) reuse the cached id, since kind ids are stable for the life of the process.
+    #[must_use]
+    pub fn kind_id(self) -> u32 {
+        *enum_attribute_kind_cache()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(self)
+            .or_insert_with(|| AttributeRef::get_enum_attribute_kind_for_name(self.name()))
+    }
+}
+
+type EnumAttributeKindCache = Mutex<HashMap<EnumAttributeKind, u32>>;
+
+fn enum_attribute_kind_cache() -> &'static EnumAttributeKindCache {
+    static CACHE: OnceLock<EnumAttributeKindCache> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// LLVM Attributes structure wrapper
+pub struct AttributeRef(LLVMAttributeRef);
+
+impl From<LLVMAttributeRef> for AttributeRef {
+    fn from(value: LLVMAttributeRef) -> Self {
+        Self(value)
+    }
+}
+
+impl GetRef for AttributeRef {
+    type RawRef = LLVMAttributeRef;
+    fn get_ref(&self) -> Self::RawRef {
+        self.0
+    }
+}
+
+impl AttributeRef {
+    /// Return the unique id given the name of the enum attribute,
+    /// or 0 if no attribute by that name exists.
+    ///
+    /// See <http://llvm.org/docs/LangRef.html#parameter-attributes>
+    /// and <http://llvm.org/docs/LangRef.html#function-attributes>
+    /// for the list of available attributes.
+    ///
+    /// NB: Attribute names and/or id are subject to change without
+    /// going through the C API deprecation cycle.
+    #[must_use]
+    pub fn get_enum_attribute_kind_for_name(name: &str) -> u32 {
+        let c_name = CString::try_from(name).expect("string contains an interior NUL byte");
+        unsafe {
+            core::LLVMGetEnumAttributeKindForName(c_name.as_ptr(), *SizeT(c_name.to_bytes().len()))
+        }
+    }
+
+    /// Get last enum attribute
+    #[must_use]
+    pub fn get_last_enum_attribute_kind() -> u32 {
+        unsafe { core::LLVMGetLastEnumAttributeKind() }
+    }
+
+    /// Create an enum attribute.
+    #[must_use]
+    pub fn create_enum_attribute(context: &ContextRef, kind_id: u32, val: u64) -> Self {
+        let attr =
+            unsafe { core::LLVMCreateEnumAttribute(context.get_ref(), *CUint::try_from(kind_id).expect("value does not fit in c_uint"), val) };
+        Self(attr)
+    }
+
+    /// Get the unique id corresponding to the enum attribute passed as argument.
+    #[must_use]
+    pub fn get_enum_attribute_kind(&self) -> u32 {
+        unsafe { core::LLVMGetEnumAttributeKind(self.0) }
+    }
+
+    /// Get the enum attribute's value. 0 is returned if none exists.
+    #[must_use]
+    pub fn get_enum_attribute_value(&self) -> u64 {
+        unsafe { core::LLVMGetEnumAttributeValue(self.0) }
+    }
+
+    /// Create a type attribute
+    #[must_use]
+    pub fn create_type_attribute(context: &ContextRef, kind_id: u32, type_ref: &TypeRef<'_>) -> Self {
+        let attr = unsafe {
+            core::LLVMCreateTypeAttribute(context.get_ref(), kind_id, type_ref.get_ref())
+        };
+        Self(attr)
+    }
+
+    /// Get the type attribute's value.
+    #[must_use]
+    pub fn get_type_attribute_value(&self) -> TypeRef<'_> {
+        let type_ref = unsafe { core::LLVMGetTypeAttributeValue(self.0) };
+        type_ref.into()
+    }
+
+    /// Create a string attribute.
+    #[must_use]
+    pub fn create_string_attribute(context: &ContextRef, key: &str, value: &str) -> Self {
+        let c_key = CString::try_from(key).expect("string contains an interior NUL byte");
+        let c_value = CString::try_from(value).expect("string contains an interior NUL byte");
+        let attr = unsafe {
+            core::LLVMCreateStringAttribute(
+                context.get_ref(),
+                c_key.as_ptr(),
+                *CUint::try_from(c_key.to_bytes().len()).expect("value does not fit in c_uint"),
+                c_value.as_ptr(),
+                *CUint::try_from(c_value.to_bytes().len()).expect("value does not fit in c_uint"),
+            )
+        };
+        Self(attr)
+    }
+
+    /// Get the string attribute's kind.
+    #[must_use]
+    pub fn get_string_attribute_kind(&self) -> Option<String> {
+        let mut length = *CUint::try_from(0_usize).expect("value does not fit in c_uint");
+        unsafe {
+            let c_str = core::LLVMGetStringAttributeKind(self.0, &mut length);
+            if c_str.is_null() {
+                return None;
+            }
+            Some(CStr::new(c_str).to_string())
+        }
+    }
+
+    /// Get the string attribute's value.
+    #[must_use]
+    pub fn get_string_attribute_value(&self) -> Option<String> {
+        let mut length = *CUint::try_from(0_usize).expect("value does not fit in c_uint");
+        unsafe {
+            let c_str = core::LLVMGetStringAttributeValue(self.get_ref(), &mut length);
+            if c_str.is_null() {
+                return None;
+            }
+            Some(CStr::new(c_str).to_string())
+        }
+    }
+
+    /// Check for the  types of attributes.
+    #[must_use]
+    pub fn is_enum(&self) -> bool {
+        unsafe { core::LLVMIsEnumAttribute(self.get_ref()) != 0 }
+    }
+
+    /// Check for the  types of attributes.
+    #[must_use]
+    pub fn is_string(&self) -> bool {
+        unsafe { core::LLVMIsStringAttribute(self.get_ref()) != 0 }
+    }
+
+    /// Check for the  types of attributes.
+    #[must_use]
+    pub fn is_type(&self) -> bool {
+        unsafe { core::LLVMIsTypeAttribute(self.get_ref()) != 0 }
+    }
+}
+
+/// LLVM Diagnostic Info structure wrapper
+pub struct DiagnosticInfoRef(LLVMDiagnosticInfoRef);
+
+impl From<LLVMDiagnosticInfoRef> for DiagnosticInfoRef {
+    fn from(value: LLVMDiagnosticInfoRef) -> Self {
+        Self(value)
+    }
+}
+
+impl GetRef for DiagnosticInfoRef {
+    type RawRef = LLVMDiagnosticInfoRef;
+    fn get_ref(&self) -> Self::RawRef {
+        self.0
+    }
+}
+
+impl DiagnosticInfoRef {
+    /// Return a string representation of the `DiagnosticInfo`. Use
+    /// [`crate::core::dispose_message`] (`LLVMDisposeMessage`) to free the string.
+    #[must_use]
+    pub fn get_description(&self) -> Option<String> {
+        unsafe {
+            let c_str = core::LLVMGetDiagInfoDescription(self.get_ref());
+            if c_str.is_null() {
+                return None;
+            }
+            let value = CStr::new(c_str).to_string();
+            // Dispose message
+            crate::core::dispose_message(c_str);
+            Some(value)
+        }
+    }
+
+    /// Return an enum `DiagnosticSeverity` type
+    #[must_use]
+    pub fn get_severity(&self) -> DiagnosticSeverity {
+        unsafe {
+            let severity = core::LLVMGetDiagInfoSeverity(self.get_ref());
+            DiagnosticSeverity::from(severity)
+        }
+    }
+}
@@ -0,0 +1,267 @@
+//! Functions in this section create debug info (DWARF) metadata for a module.
+//!
+//! Unlike the read-only `get_debug_loc_*` accessors on [`crate::core::values::ValueRef`], the
+//! types in this module let callers *produce* debug info via LLVM's `DIBuilder`.
+
+use crate::core::context::ContextRef;
+use crate::core::module::{MetadataRef, ModuleFlagBehavior, ModuleRef};
+use crate::{CString, CUint, GetRef};
+use llvm_sys::debuginfo::{
+    LLVMCreateDIBuilder, LLVMDIBuilderCreateBasicType, LLVMDIBuilderCreateCompileUnit,
+    LLVMDIBuilderCreateDebugLocation, LLVMDIBuilderCreateFile, LLVMDIBuilderCreateFunction,
+    LLVMDIBuilderCreateLexicalBlock, LLVMDIBuilderFinalize, LLVMDisposeDIBuilder,
+    LLVMDWARFEmissionKind, LLVMDWARFSourceLanguage,
+};
+use llvm_sys::prelude::{LLVMDIBuilderRef, LLVMMetadataRef};
+
+/// The `"Debug Info Version"` module flag value that the `DIBuilder` emits, matching the
+/// version `LLVMDIBuilderCreate*` functions produce metadata for.
+const DEBUG_INFO_VERSION: u64 = 3;
+
+/// Builder for creating debug info (DWARF) metadata within a module.
+///
+/// Constructed via [`ModuleRef::create_debug_info_builder`](crate::core::module::ModuleRef::create_debug_info_builder),
+/// which wraps `LLVMCreateDIBuilder` and sets the module's `"Debug Info Version"` flag.
+pub struct DebugInfoBuilder(LLVMDIBuilderRef);
+
+impl DebugInfoBuilder {
+    /// Create a `DebugInfoBuilder` for `module`, marking the module with the
+    /// `"Debug Info Version"` flag required for the emitted metadata to be recognized.
+    #[must_use]
+    pub fn new(module: &ModuleRef) -> Self {
+        let builder = unsafe { LLVMCreateDIBuilder(module.get_ref()) };
+        // `get_module_context` returns an owning `ContextRef`, but this context is borrowed from
+        // `module` and must not be disposed here, so forget it once we've read its raw pointer.
+        let context = module.get_module_context();
+        let context_ref = context.get_ref();
+        std::mem::forget(context);
+        let version = MetadataRef::from(unsafe {
+            llvm_sys::core::LLVMValueAsMetadata(llvm_sys::core::LLVMConstInt(
+                llvm_sys::core::LLVMInt32TypeInContext(context_ref),
+                DEBUG_INFO_VERSION,
+                0,
+            ))
+        });
+        module.add_module_flag(
+            &ModuleFlagBehavior::ModuleFlagBehaviorWarning,
+            "Debug Info Version",
+            &version,
+        );
+        Self(builder)
+    }
+
+    /// Create a compile unit, the root scope for all debug info emitted through this builder.
+    ///
+    /// This function wraps the `LLVMDIBuilderCreateCompileUnit` function from the LLVM core
+    /// library.
+    #[must_use]
+    pub fn create_compile_unit(
+        &self,
+        file: &MetadataRef,
+        producer: &str,
+        is_optimized: bool,
+        flags: &str,
+    ) -> MetadataRef {
+        let c_producer = CString::try_from(producer).expect("string contains an interior NUL byte");
+        let c_flags = CString::try_from(flags).expect("string contains an interior NUL byte");
+        let c_split_name = CString::try_from("").expect("string contains an interior NUL byte");
+        let c_sysroot = CString::try_from("").expect("string contains an interior NUL byte");
+        let c_sdk = CString::try_from("").expect("string contains an interior NUL byte");
+        let metadata = unsafe {
+            LLVMDIBuilderCreateCompileUnit(
+                self.0,
+                LLVMDWARFSourceLanguage::LLVMDWARFSourceLanguageRust,
+                file.get_ref(),
+                c_producer.as_ptr(),
+                c_producer.to_bytes().len(),
+                i32::from(is_optimized),
+                c_flags.as_ptr(),
+                c_flags.to_bytes().len(),
+                0,
+                c_split_name.as_ptr(),
+                c_split_name.to_bytes().len(),
+                LLVMDWARFEmissionKind::LLVMDWARFEmissionFull,
+                0,
+                0,
+                0,
+                c_sysroot.as_ptr(),
+                c_sysroot.to_bytes().len(),
+                c_sdk.as_ptr(),
+                c_sdk.to_bytes().len(),
+            )
+        };
+        MetadataRef::from(metadata)
+    }
+
+    /// Create a file scope for use as a unit or other scope's file reference.
+    ///
+    /// This function wraps the `LLVMDIBuilderCreateFile` function from the LLVM core library.
+    #[must_use]
+    pub fn create_file(&self, filename: &str, directory: &str) -> MetadataRef {
+        let c_filename = CString::try_from(filename).expect("string contains an interior NUL byte");
+        let c_directory = CString::try_from(directory).expect("string contains an interior NUL byte");
+        let metadata = unsafe {
+            LLVMDIBuilderCreateFile(
+                self.0,
+                c_filename.as_ptr(),
+                c_filename.to_bytes().len(),
+                c_directory.as_ptr(),
+                c_directory.to_bytes().len(),
+            )
+        };
+        MetadataRef::from(metadata)
+    }
+
+    /// Create a subprogram (function) debug info descriptor.
+    ///
+    /// This function wraps the `LLVMDIBuilderCreateFunction` function from the LLVM core library.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_function(
+        &self,
+        scope: &MetadataRef,
+        name: &str,
+        file: &MetadataRef,
+        line_no: u32,
+        subroutine_type: &MetadataRef,
+        is_local_to_unit: bool,
+        is_definition: bool,
+        scope_line: u32,
+    ) -> MetadataRef {
+        let c_name = CString::try_from(name).expect("string contains an interior NUL byte");
+        let c_linkage_name = CString::try_from(name).expect("string contains an interior NUL byte");
+        let metadata = unsafe {
+            LLVMDIBuilderCreateFunction(
+                self.0,
+                scope.get_ref(),
+                c_name.as_ptr(),
+                c_name.to_bytes().len(),
+                c_linkage_name.as_ptr(),
+                c_linkage_name.to_bytes().len(),
+                file.get_ref(),
+                *CUint::try_from(line_no).expect("value does not fit in c_uint"),
+                subroutine_type.get_ref(),
+                i32::from(is_local_to_unit),
+                i32::from(is_definition),
+                *CUint::try_from(scope_line).expect("value does not fit in c_uint"),
+                0,
+                0,
+            )
+        };
+        MetadataRef::from(metadata)
+    }
+
+    /// Alias for [`Self::create_function`], matching the `LLVMDIBuilderCreateFunction` naming
+    /// used elsewhere in LLVM's own documentation (a "subprogram" is DWARF's term for a function).
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_subprogram(
+        &self,
+        scope: &MetadataRef,
+        name: &str,
+        file: &MetadataRef,
+        line_no: u32,
+        subroutine_type: &MetadataRef,
+        is_local_to_unit: bool,
+        is_definition: bool,
+        scope_line: u32,
+    ) -> MetadataRef {
+        self.create_function(
+            scope,
+            name,
+            file,
+            line_no,
+            subroutine_type,
+            is_local_to_unit,
+            is_definition,
+            scope_line,
+        )
+    }
+
+    /// Create a basic type debug info descriptor (e.g. an `i32` or `f64`).
+    ///
+    /// This function wraps the `LLVMDIBuilderCreateBasicType` function from the LLVM core
+    /// library.
+    #[must_use]
+    pub fn create_basic_type(&self, name: &str, size_in_bits: u64, encoding: u32) -> MetadataRef {
+        let c_name = CString::try_from(name).expect("string contains an interior NUL byte");
+        let metadata = unsafe {
+            LLVMDIBuilderCreateBasicType(
+                self.0,
+                c_name.as_ptr(),
+                c_name.to_bytes().len(),
+                size_in_bits,
+                encoding,
+                0,
+            )
+        };
+        MetadataRef::from(metadata)
+    }
+
+    /// Create a descriptor for a lexical block with the specified parent scope.
+    ///
+    /// This function wraps the `LLVMDIBuilderCreateLexicalBlock` function from the LLVM core
+    /// library.
+    #[must_use]
+    pub fn create_lexical_block(
+        &self,
+        scope: &MetadataRef,
+        file: &MetadataRef,
+        line: u32,
+        column: u32,
+    ) -> MetadataRef {
+        let metadata = unsafe {
+            LLVMDIBuilderCreateLexicalBlock(
+                self.0,
+                scope.get_ref(),
+                file.get_ref(),
+                *CUint::try_from(line).expect("value does not fit in c_uint"),
+                *CUint::try_from(column).expect("value does not fit in c_uint"),
+            )
+        };
+        MetadataRef::from(metadata)
+    }
+
+    /// Create a debug location for the given line, column and scope, within `context`.
+    ///
+    /// This function wraps the `LLVMDIBuilderCreateDebugLocation` function from the LLVM core
+    /// library.
+    #[must_use]
+    pub fn create_debug_location(
+        context: &ContextRef,
+        line: u32,
+        column: u32,
+        scope: &MetadataRef,
+        inlined_at: Option<&MetadataRef>,
+    ) -> MetadataRef {
+        let inlined_at: LLVMMetadataRef = match inlined_at {
+            Some(metadata) => metadata.get_ref(),
+            None => std::ptr::null_mut(),
+        };
+        let metadata = unsafe {
+            LLVMDIBuilderCreateDebugLocation(
+                context.get_ref(),
+                *CUint::try_from(line).expect("value does not fit in c_uint"),
+                *CUint::try_from(column).expect("value does not fit in c_uint"),
+                scope.get_ref(),
+                inlined_at,
+            )
+        };
+        MetadataRef::from(metadata)
+    }
+
+    /// Construct any deferred debug info descriptors, finalizing the builder's output.
+    ///
+    /// This function wraps the `LLVMDIBuilderFinalize` function from the LLVM core library. It
+    /// must be called once all debug info for the module has been created.
+    pub fn finalize(&self) {
+        unsafe { LLVMDIBuilderFinalize(self.0) }
+    }
+}
+
+impl Drop for DebugInfoBuilder {
+    /// Dispose the `DIBuilder`, releasing its resources.
+    fn drop(&mut self) {
+        unsafe { LLVMDisposeDIBuilder(self.0) }
+    }
+}
@@ -0,0 +1,79 @@
+//! LLVM's process-wide fatal error handler.
+//!
+//! Unlike the diagnostic and yield callbacks on [`super::context::ContextRef`], the fatal
+//! error handler is a single, global hook (`LLVMInstallFatalErrorHandler` /
+//! `LLVMResetFatalErrorHandler`) shared by every context in the process. LLVM calls it
+//! immediately before aborting the process via `exit()`, on whichever thread triggered the
+//! fatal error, so it is the last chance to log or clean up.
+
+use libc::c_char;
+use llvm_sys::error_handling::{
+    LLVMFatalErrorHandler, LLVMInstallFatalErrorHandler, LLVMResetFatalErrorHandler,
+};
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+/// Install a raw `extern "C"` fatal error handler.
+///
+/// ## Safety
+/// `handler` is invoked by LLVM with a `*const c_char` reason and must not unwind across
+/// the FFI boundary. The handler is process-global: installing a new one replaces any
+/// previously installed handler (raw or closure-based).
+pub unsafe fn install_fatal_error_handler(handler: LLVMFatalErrorHandler) {
+    unsafe { LLVMInstallFatalErrorHandler(handler) }
+}
+
+/// Reset LLVM's fatal error handler to its default (printing the reason to `stderr`).
+///
+/// Also clears any closure registered via [`install_fatal_error_handler_closure`].
+pub fn reset_fatal_error_handler() {
+    fatal_error_handler()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take();
+    unsafe { LLVMResetFatalErrorHandler() }
+}
+
+/// Install a safe, closure-based fatal error handler.
+///
+/// ## Details
+/// This is a safe alternative to [`install_fatal_error_handler`]: instead of an
+/// `extern "C"` function pointer, it accepts an ordinary Rust closure and takes care of
+/// boxing it and registering a trampoline that wraps the incoming `*const c_char` in a
+/// `CStr` before calling it.
+///
+/// The handler is global, not per-context, and runs on whichever thread triggered the
+/// fatal error. LLVM calls it right before terminating the process, so it is not a place
+/// to recover; use it for last-chance logging or flushing.
+pub fn install_fatal_error_handler_closure<F>(f: F)
+where
+    F: FnMut(&str) + Send + 'static,
+{
+    *fatal_error_handler().lock().unwrap_or_else(PoisonError::into_inner) = Some(Box::new(f));
+    unsafe {
+        LLVMInstallFatalErrorHandler(Some(fatal_error_handler_trampoline));
+    }
+}
+
+type FatalErrorHandlerSlot = Mutex<Option<Box<dyn FnMut(&str) + Send + 'static>>>;
+
+fn fatal_error_handler() -> &'static FatalErrorHandlerSlot {
+    static HANDLER: OnceLock<FatalErrorHandlerSlot> = OnceLock::new();
+    HANDLER.get_or_init(|| Mutex::new(None))
+}
+
+/// `extern "C"` trampoline passed to `LLVMInstallFatalErrorHandler` by
+/// [`install_fatal_error_handler_closure`]. Converts the raw reason string to a `&str`
+/// (lossily, since LLVM gives no UTF-8 guarantee) and forwards it to the registered closure.
+unsafe extern "C" fn fatal_error_handler_trampoline(reason: *const c_char) {
+    if reason.is_null() {
+        return;
+    }
+    let reason = unsafe { std::ffi::CStr::from_ptr(reason) }.to_string_lossy();
+    if let Some(handler) = fatal_error_handler()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .as_mut()
+    {
+        handler(&reason);
+    }
+}
@@ -0,0 +1,34 @@
+//! Functions in this section operate on memory buffers.
+
+use crate::GetRef;
+use llvm_sys::core;
+use llvm_sys::prelude::LLVMMemoryBufferRef;
+
+/// LLVM Memory Buffer wrapper.
+///
+/// A memory buffer owns a region of memory and is used, among other things, to hand compact
+/// binary bitcode to and from LLVM without going through a file on disk.
+#[derive(Debug)]
+pub struct MemoryBufferRef(LLVMMemoryBufferRef);
+
+impl From<LLVMMemoryBufferRef> for MemoryBufferRef {
+    fn from(value: LLVMMemoryBufferRef) -> Self {
+        Self(value)
+    }
+}
+
+impl GetRef for MemoryBufferRef {
+    type RawRef = LLVMMemoryBufferRef;
+    fn get_ref(&self) -> Self::RawRef {
+        self.0
+    }
+}
+
+impl Drop for MemoryBufferRef {
+    /// Deinitialize this value and dispose of its resources.
+    ///
+    /// Destroy a memory buffer instance.
+    fn drop(&mut self) {
+        unsafe { core::LLVMDisposeMemoryBuffer(self.0) }
+    }
+}
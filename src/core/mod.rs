@@ -1,3 +1,4 @@
+use crate::error::{Error, ParseLinkageError};
 use crate::CUint;
 use llvm_sys::{
     core, LLVMDLLStorageClass, LLVMIntPredicate, LLVMLinkage, LLVMOpcode, LLVMRealPredicate,
@@ -5,9 +6,14 @@ use llvm_sys::{
 };
 use std::fmt::Display;
 use std::ops::Deref;
+use std::str::FromStr;
 
 pub mod context;
+pub mod debug_info;
+pub mod fatal_error;
+pub mod memory_buffer;
 pub mod module;
+pub mod target_data;
 pub mod types;
 pub mod values;
 
@@ -26,7 +32,7 @@ pub struct AddressSpace(CUint);
 
 impl From<u32> for AddressSpace {
     fn from(value: u32) -> Self {
-        Self(CUint::from(value))
+        Self(CUint::try_from(value).expect("value does not fit in c_uint"))
     }
 }
 
@@ -44,17 +50,15 @@ impl AddressSpace {
     }
 }
 
-/// Dispose LLVM message
+/// Dispose a message string allocated by LLVM (e.g. an error message returned via an out
+/// parameter such as `LLVMVerifyModule`'s).
 ///
-/// ## Panics
-/// This function is purely informative and panics with a message about the call
-/// being unavailable. Since there are no cases in which it can be called in
-/// safe code. For raw access, if there is such a need, must be called
-/// `LLVMDisposeMessage` directly.
-pub fn dispose_message(_message: libc::c_char) {
-    unreachable!(
-        "LLVMDisposeMessage is unsafe adn restricted to operated to operate directly for safe code"
-    );
+/// ## Safety
+/// `message` must be a pointer LLVM allocated and handed back to the caller to free, such as
+/// the error-message out parameter of `LLVMVerifyModule` or `LLVMPrintModuleToFile`. It must
+/// not be null, and must not be disposed more than once.
+pub unsafe fn dispose_message(message: *mut libc::c_char) {
+    unsafe { core::LLVMDisposeMessage(message) }
 }
 
 /// LLVM version representation
@@ -88,9 +92,9 @@ impl Version {
     /// ```
     #[must_use]
     pub fn new() -> Self {
-        let mut major = CUint::from(0_u32);
-        let mut minor = CUint::from(0_u32);
-        let mut patch = CUint::from(0_u32);
+        let mut major = CUint::try_from(0_u32).expect("value does not fit in c_uint");
+        let mut minor = CUint::try_from(0_u32).expect("value does not fit in c_uint");
+        let mut patch = CUint::try_from(0_u32).expect("value does not fit in c_uint");
         unsafe {
             core::LLVMGetVersion(&mut *major, &mut *minor, &mut *patch);
         }
@@ -657,6 +661,120 @@ impl From<Linkage> for LLVMLinkage {
     }
 }
 
+impl Linkage {
+    /// Whether this linkage kind is a legacy name that modern LLVM folds into another kind.
+    ///
+    /// # Details
+    ///
+    /// `GhostLinkage` and the `LinkerPrivateLinkage`/`LinkerPrivateWeakLinkage` pair are kept
+    /// around in `llvm-sys` for raw round-tripping with older bitcode/IR, but current LLVM no
+    /// longer treats them as distinct from the kinds `normalized()` maps them to. See
+    /// `normalized()` for the mapping.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` for `GhostLinkage`, `LinkerPrivateLinkage`, and
+    /// `LinkerPrivateWeakLinkage`, otherwise `false`.
+    #[must_use]
+    pub const fn is_deprecated(self) -> bool {
+        matches!(
+            self,
+            Self::GhostLinkage | Self::LinkerPrivateLinkage | Self::LinkerPrivateWeakLinkage
+        )
+    }
+
+    /// Maps a deprecated/renamed linkage kind to its canonical modern equivalent, leaving any
+    /// other kind unchanged.
+    ///
+    /// # Details
+    ///
+    /// `GhostLinkage` is an internal alias for `AvailableExternallyLinkage`, and the
+    /// `LinkerPrivateLinkage`/`LinkerPrivateWeakLinkage` family was folded into `PrivateLinkage`/
+    /// `LinkOnceODRAutoHideLinkage` (the `linker_private_weak_def_auto` → `linkonce_odr_auto_hide`
+    /// rename) in modern LLVM. This lets code migrating older bitcode/IR upgrade a parsed
+    /// `Linkage` to the kind current LLVM actually uses, without hand-writing the mapping.
+    ///
+    /// # Returns
+    ///
+    /// Returns `AvailableExternallyLinkage` for `GhostLinkage`, `PrivateLinkage` for
+    /// `LinkerPrivateLinkage`, `LinkOnceODRAutoHideLinkage` for `LinkerPrivateWeakLinkage`, and
+    /// `self` unchanged for every other kind.
+    #[must_use]
+    pub const fn normalized(self) -> Self {
+        match self {
+            Self::GhostLinkage => Self::AvailableExternallyLinkage,
+            Self::LinkerPrivateLinkage => Self::PrivateLinkage,
+            Self::LinkerPrivateWeakLinkage => Self::LinkOnceODRAutoHideLinkage,
+            other => other,
+        }
+    }
+}
+
+impl Display for Linkage {
+    /// Formats the linkage as its `.ll`-syntax keyword, always emitting the canonical modern
+    /// spelling — deprecated kinds (`GhostLinkage`, `LinkerPrivateLinkage`,
+    /// `LinkerPrivateWeakLinkage`) print as the keyword for what `normalized()` maps them to.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let keyword = match self {
+            Self::ExternalLinkage => "external",
+            Self::AvailableExternallyLinkage | Self::GhostLinkage => "available_externally",
+            Self::LinkOnceAnyLinkage => "linkonce",
+            Self::LinkOnceODRLinkage => "linkonce_odr",
+            Self::LinkOnceODRAutoHideLinkage | Self::LinkerPrivateWeakLinkage => {
+                "linkonce_odr_auto_hide"
+            }
+            Self::WeakAnyLinkage => "weak",
+            Self::WeakODRLinkage => "weak_odr",
+            Self::AppendingLinkage => "appending",
+            Self::InternalLinkage => "internal",
+            Self::PrivateLinkage | Self::LinkerPrivateLinkage => "private",
+            Self::DLLImportLinkage => "dllimport",
+            Self::DLLExportLinkage => "dllexport",
+            Self::ExternalWeakLinkage => "extern_weak",
+            Self::CommonLinkage => "common",
+        };
+        write!(f, "{keyword}")
+    }
+}
+
+impl FromStr for Linkage {
+    type Err = Error;
+
+    /// Parses a `.ll`-syntax linkage keyword, accepting the historical aliases
+    /// `linker_private_weak_def_auto` (for `linkonce_odr_auto_hide`) and `external_weak` (for
+    /// `extern_weak`) in addition to the current canonical spellings.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ParseLinkage`] if `s` is not a recognized linkage keyword.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "external" => Self::ExternalLinkage,
+            "available_externally" => Self::AvailableExternallyLinkage,
+            "linkonce" => Self::LinkOnceAnyLinkage,
+            "linkonce_odr" => Self::LinkOnceODRLinkage,
+            "linkonce_odr_auto_hide" | "linker_private_weak_def_auto" => {
+                Self::LinkOnceODRAutoHideLinkage
+            }
+            "weak" => Self::WeakAnyLinkage,
+            "weak_odr" => Self::WeakODRLinkage,
+            "appending" => Self::AppendingLinkage,
+            "internal" => Self::InternalLinkage,
+            "private" => Self::PrivateLinkage,
+            "dllimport" => Self::DLLImportLinkage,
+            "dllexport" => Self::DLLExportLinkage,
+            "extern_weak" | "external_weak" => Self::ExternalWeakLinkage,
+            "common" => Self::CommonLinkage,
+            _ => {
+                return Err(Error::ParseLinkage(ParseLinkageError {
+                    text: s.to_string(),
+                    kind: "Linkage",
+                }));
+            }
+        })
+    }
+}
+
 /// `Visibility` is an enumeration in LLVM that represents the
 /// visibility of global values such as functions and global
 /// variables. Visibility determines how symbols are treated by
@@ -694,6 +812,41 @@ impl From<Visibility> for LLVMVisibility {
     }
 }
 
+impl Display for Visibility {
+    /// Formats the visibility as its `.ll`-syntax keyword (`default`, `hidden`, or `protected`).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let keyword = match self {
+            Self::DefaultVisibility => "default",
+            Self::HiddenVisibility => "hidden",
+            Self::ProtectedVisibility => "protected",
+        };
+        write!(f, "{keyword}")
+    }
+}
+
+impl FromStr for Visibility {
+    type Err = Error;
+
+    /// Parses a `.ll`-syntax visibility keyword.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ParseLinkage`] if `s` is not `default`, `hidden`, or `protected`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "default" => Self::DefaultVisibility,
+            "hidden" => Self::HiddenVisibility,
+            "protected" => Self::ProtectedVisibility,
+            _ => {
+                return Err(Error::ParseLinkage(ParseLinkageError {
+                    text: s.to_string(),
+                    kind: "Visibility",
+                }));
+            }
+        })
+    }
+}
+
 /// Represents the DLL storage classes in LLVM, that specifies how a global value,
 /// such as a function or global variable, should be treated with respect to
 /// dynamic link libraries (DLLs) on platforms like Windows. The `DLLStorageClass`
@@ -734,6 +887,44 @@ impl From<LLVMDLLStorageClass> for DLLStorageClass {
     }
 }
 
+impl Display for DLLStorageClass {
+    /// Formats the DLL storage class as its `.ll`-syntax keyword. `DefaultStorageClass` has no
+    /// keyword of its own — it is the absence of `dllimport`/`dllexport` in IR text — so it
+    /// formats as the empty string.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let keyword = match self {
+            Self::DefaultStorageClass => "",
+            Self::DLLImportStorageClass => "dllimport",
+            Self::DLLExportStorageClass => "dllexport",
+        };
+        write!(f, "{keyword}")
+    }
+}
+
+impl FromStr for DLLStorageClass {
+    type Err = Error;
+
+    /// Parses a `.ll`-syntax DLL storage class keyword, with the empty string parsing to
+    /// `DefaultStorageClass`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ParseLinkage`] if `s` is not `""`, `dllimport`, or `dllexport`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "" => Self::DefaultStorageClass,
+            "dllimport" => Self::DLLImportStorageClass,
+            "dllexport" => Self::DLLExportStorageClass,
+            _ => {
+                return Err(Error::ParseLinkage(ParseLinkageError {
+                    text: s.to_string(),
+                    kind: "DLLStorageClass",
+                }));
+            }
+        })
+    }
+}
+
 /// Represents the unnamed address attribute for global values in LLVM.
 ///
 /// `UnnamedAddr` is an enumeration that specifies whether a global variable or function's address is significant.
@@ -774,3 +965,187 @@ impl From<LLVMUnnamedAddr> for UnnamedAddr {
         }
     }
 }
+
+impl Display for UnnamedAddr {
+    /// Formats the unnamed-address attribute as its `.ll`-syntax keyword. `NoUnnamedAddr` has no
+    /// keyword of its own — it is the absence of `unnamed_addr`/`local_unnamed_addr` in IR text —
+    /// so it formats as the empty string.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let keyword = match self {
+            Self::NoUnnamedAddr => "",
+            Self::LocalUnnamedAddr => "local_unnamed_addr",
+            Self::GlobalUnnamedAddr => "unnamed_addr",
+        };
+        write!(f, "{keyword}")
+    }
+}
+
+impl FromStr for UnnamedAddr {
+    type Err = Error;
+
+    /// Parses a `.ll`-syntax unnamed-address keyword, with the empty string parsing to
+    /// `NoUnnamedAddr`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ParseLinkage`] if `s` is not `""`, `local_unnamed_addr`, or
+    /// `unnamed_addr`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "" => Self::NoUnnamedAddr,
+            "local_unnamed_addr" => Self::LocalUnnamedAddr,
+            "unnamed_addr" => Self::GlobalUnnamedAddr,
+            _ => {
+                return Err(Error::ParseLinkage(ParseLinkageError {
+                    text: s.to_string(),
+                    kind: "UnnamedAddr",
+                }));
+            }
+        })
+    }
+}
+
+/// Bundles the three symbol-level attributes LLVM associates with a global value —
+/// `Linkage`, `Visibility`, and `DLLStorageClass` — so they can be validated together before
+/// being applied to a global value, since LLVM decoupled `dllimport`/`dllexport` from linkage
+/// and its verifier enforces invariants that span all three attributes at once.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SymbolAttributes {
+    /// The symbol's linkage.
+    pub linkage: Linkage,
+    /// The symbol's visibility.
+    pub visibility: Visibility,
+    /// The symbol's DLL storage class.
+    pub dll_storage_class: DLLStorageClass,
+}
+
+impl SymbolAttributes {
+    /// Checks that this combination of linkage, visibility, and DLL storage class is one that
+    /// LLVM's verifier would accept for a symbol that is (or is not) a declaration.
+    ///
+    /// # Details
+    ///
+    /// Enforces the invariants from LLVM's "Decouple dllimport/dllexport from linkage" rules:
+    ///
+    /// - A `DLLImportStorageClass` symbol must be either a declaration with `ExternalLinkage` or
+    ///   a definition with `AvailableExternallyLinkage`.
+    /// - A `DLLExportStorageClass` symbol's linkage must be identical to what it would be
+    ///   without the export marker, so `InternalLinkage` and `PrivateLinkage` (local linkage)
+    ///   cannot be exported.
+    /// - A non-`DefaultStorageClass` symbol must have `DefaultVisibility`; `dllimport`/
+    ///   `dllexport` combined with `HiddenVisibility` or `ProtectedVisibility` is rejected.
+    ///
+    /// # Parameters
+    ///
+    /// - `is_declaration`: Whether the symbol this combination will be applied to is a
+    ///   declaration (no defining body) rather than a definition.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SymbolAttributesError::InvalidDllImportLinkage`],
+    /// [`SymbolAttributesError::InvalidDllExportLinkage`], or
+    /// [`SymbolAttributesError::DllStorageClassWithNonDefaultVisibility`] if the combination
+    /// violates one of the rules above.
+    pub fn validate(&self, is_declaration: bool) -> Result<(), SymbolAttributesError> {
+        match self.dll_storage_class {
+            DLLStorageClass::DLLImportStorageClass => {
+                let ok = if is_declaration {
+                    self.linkage == Linkage::ExternalLinkage
+                } else {
+                    self.linkage == Linkage::AvailableExternallyLinkage
+                };
+                if !ok {
+                    return Err(SymbolAttributesError::InvalidDllImportLinkage {
+                        is_declaration,
+                        linkage: self.linkage,
+                    });
+                }
+            }
+            DLLStorageClass::DLLExportStorageClass => {
+                if matches!(
+                    self.linkage,
+                    Linkage::InternalLinkage | Linkage::PrivateLinkage
+                ) {
+                    return Err(SymbolAttributesError::InvalidDllExportLinkage {
+                        linkage: self.linkage,
+                    });
+                }
+            }
+            DLLStorageClass::DefaultStorageClass => {}
+        }
+        if self.dll_storage_class != DLLStorageClass::DefaultStorageClass
+            && self.visibility != Visibility::DefaultVisibility
+        {
+            return Err(SymbolAttributesError::DllStorageClassWithNonDefaultVisibility {
+                visibility: self.visibility,
+                dll_storage_class: self.dll_storage_class,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Errors returned by [`SymbolAttributes::validate`] when a combination of linkage, visibility,
+/// and DLL storage class would be rejected by LLVM's verifier.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SymbolAttributesError {
+    /// A `DLLImportStorageClass` symbol must be either a declaration with `ExternalLinkage` or a
+    /// definition with `AvailableExternallyLinkage`.
+    InvalidDllImportLinkage {
+        /// Whether the symbol this was checked against is a declaration (no defining body).
+        is_declaration: bool,
+        /// The linkage that was combined with `DLLImportStorageClass`.
+        linkage: Linkage,
+    },
+    /// A `DLLExportStorageClass` symbol must keep the linkage it would have without the export
+    /// marker; `InternalLinkage` and `PrivateLinkage` symbols are local to the module and cannot
+    /// be exported.
+    InvalidDllExportLinkage {
+        /// The local linkage that was combined with `DLLExportStorageClass`.
+        linkage: Linkage,
+    },
+    /// `dllimport`/`dllexport` require default visibility; `HiddenVisibility` and
+    /// `ProtectedVisibility` are meaningless for a symbol that is also marked for DLL import or
+    /// export.
+    DllStorageClassWithNonDefaultVisibility {
+        /// The non-default visibility that was combined with a DLL storage class.
+        visibility: Visibility,
+        /// The DLL storage class it was combined with.
+        dll_storage_class: DLLStorageClass,
+    },
+}
+
+impl Display for SymbolAttributesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidDllImportLinkage {
+                is_declaration,
+                linkage,
+            } => {
+                let kind = if *is_declaration { "a declaration" } else { "a definition" };
+                write!(
+                    f,
+                    "DLLImportStorageClass on {kind} requires {:?}, found {linkage:?}",
+                    if *is_declaration {
+                        Linkage::ExternalLinkage
+                    } else {
+                        Linkage::AvailableExternallyLinkage
+                    }
+                )
+            }
+            Self::InvalidDllExportLinkage { linkage } => write!(
+                f,
+                "DLLExportStorageClass cannot be combined with local linkage {linkage:?}"
+            ),
+            Self::DllStorageClassWithNonDefaultVisibility {
+                visibility,
+                dll_storage_class,
+            } => write!(
+                f,
+                "{dll_storage_class:?} requires DefaultVisibility, found {visibility:?}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SymbolAttributesError {}
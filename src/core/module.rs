@@ -2,26 +2,31 @@ use std::ops::Deref;
 
 use llvm_sys::core::{
     LLVMAddFunction, LLVMAddModuleFlag, LLVMAddNamedMetadataOperand, LLVMAppendModuleInlineAsm,
-    LLVMCloneModule, LLVMCopyModuleFlagsMetadata, LLVMDisposeModule,
+    LLVMCloneModule, LLVMCopyModuleFlagsMetadata, LLVMDeleteBasicBlock, LLVMDisposeModule,
     LLVMDisposeModuleFlagsMetadata, LLVMDumpModule, LLVMGetDataLayoutStr,
-    LLVMGetFirstNamedMetadata, LLVMGetInlineAsm, LLVMGetLastNamedMetadata, LLVMGetModuleContext,
-    LLVMGetModuleFlag, LLVMGetModuleIdentifier, LLVMGetModuleInlineAsm, LLVMGetNamedMetadata,
-    LLVMGetNamedMetadataName, LLVMGetNamedMetadataNumOperands, LLVMGetNamedMetadataOperands,
-    LLVMGetNextNamedMetadata, LLVMGetOrInsertNamedMetadata, LLVMGetPreviousNamedMetadata,
-    LLVMGetSourceFileName, LLVMGetTarget, LLVMModuleCreateWithName,
-    LLVMModuleCreateWithNameInContext, LLVMModuleFlagEntriesGetFlagBehavior,
-    LLVMModuleFlagEntriesGetKey, LLVMModuleFlagEntriesGetMetadata, LLVMPrintModuleToFile,
-    LLVMPrintModuleToString, LLVMSetDataLayout, LLVMSetModuleIdentifier, LLVMSetModuleInlineAsm2,
-    LLVMSetSourceFileName, LLVMSetTarget,
+    LLVMGetFirstBasicBlock, LLVMGetFirstFunction, LLVMGetFirstNamedMetadata,
+    LLVMGetLastNamedMetadata, LLVMGetModuleContext, LLVMGetModuleFlag, LLVMGetModuleIdentifier,
+    LLVMGetModuleInlineAsm, LLVMGetNamedMetadata, LLVMGetNamedMetadataName,
+    LLVMGetNamedMetadataNumOperands, LLVMGetNamedMetadataOperands, LLVMGetNextBasicBlock,
+    LLVMGetNextFunction, LLVMGetNextNamedMetadata, LLVMGetOrInsertNamedMetadata,
+    LLVMGetPreviousNamedMetadata, LLVMGetSourceFileName, LLVMGetTarget, LLVMGetValueName2,
+    LLVMIsDeclaration, LLVMModuleCreateWithName, LLVMModuleCreateWithNameInContext,
+    LLVMModuleFlagEntriesGetFlagBehavior, LLVMModuleFlagEntriesGetKey,
+    LLVMModuleFlagEntriesGetMetadata, LLVMPrintModuleToFile, LLVMPrintModuleToString,
+    LLVMSetDataLayout, LLVMSetModuleIdentifier, LLVMSetModuleInlineAsm2, LLVMSetSourceFileName,
+    LLVMSetTarget,
 };
 use llvm_sys::prelude::{
     LLVMMetadataRef, LLVMModuleFlagEntry, LLVMModuleRef, LLVMNamedMDNodeRef, LLVMValueRef,
 };
+use llvm_sys::analysis::LLVMVerifierFailureAction;
 use llvm_sys::{LLVMInlineAsmDialect, LLVMModuleFlagBehavior};
 
-use crate::context::ContextRef;
-use crate::types::TypeRef;
-use crate::value::ValueRef;
+use crate::core::context::ContextRef;
+use crate::core::memory_buffer::MemoryBufferRef;
+use crate::core::types::TypeRef;
+use crate::core::values::constants::{global_aliases, global_variables};
+use crate::core::values::ValueRef;
 use crate::{CInt, CStr, CString, GetRef, SizeT};
 
 /// Inline Asm Dialect
@@ -94,7 +99,7 @@ impl NamedMetadataNodeRef {
     /// Retrieve the name of a `NamedMetadataNode`.
     #[must_use]
     pub fn get_name(&self) -> Option<String> {
-        let mut length = SizeT::from(0_usize);
+        let mut length = SizeT::try_from(0_usize).expect("value does not fit in size_t");
         unsafe {
             let c_str = LLVMGetNamedMetadataName(self.0, &mut *length);
             if c_str.is_null() {
@@ -109,6 +114,19 @@ impl NamedMetadataNodeRef {
 #[derive(Debug)]
 pub struct MetadataRef(LLVMMetadataRef);
 
+impl From<LLVMMetadataRef> for MetadataRef {
+    fn from(value: LLVMMetadataRef) -> Self {
+        Self(value)
+    }
+}
+
+impl GetRef for MetadataRef {
+    type RawRef = LLVMMetadataRef;
+    fn get_ref(&self) -> Self::RawRef {
+        self.0
+    }
+}
+
 /// Represents flags that describe information about the module for use by
 /// an external entity e.g. the dynamic linker.
 #[allow(dead_code)]
@@ -217,6 +235,182 @@ impl From<ModuleFlagBehavior> for LLVMModuleFlagBehavior {
     }
 }
 
+/// The action `verify_with_action` should take if the module is broken.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerifierFailureAction {
+    /// Print a message to stderr and `abort()`.
+    AbortProcessAction,
+    /// Print a message to stderr and return `true`.
+    PrintMessageAction,
+    /// Return `true` and print nothing.
+    ReturnStatusAction,
+}
+
+impl From<VerifierFailureAction> for LLVMVerifierFailureAction {
+    fn from(value: VerifierFailureAction) -> Self {
+        match value {
+            VerifierFailureAction::AbortProcessAction => Self::LLVMAbortProcessAction,
+            VerifierFailureAction::PrintMessageAction => Self::LLVMPrintMessageAction,
+            VerifierFailureAction::ReturnStatusAction => Self::LLVMReturnStatusAction,
+        }
+    }
+}
+
+impl From<LLVMVerifierFailureAction> for VerifierFailureAction {
+    fn from(value: LLVMVerifierFailureAction) -> Self {
+        match value {
+            LLVMVerifierFailureAction::LLVMAbortProcessAction => Self::AbortProcessAction,
+            LLVMVerifierFailureAction::LLVMPrintMessageAction => Self::PrintMessageAction,
+            LLVMVerifierFailureAction::LLVMReturnStatusAction => Self::ReturnStatusAction,
+        }
+    }
+}
+
+/// An iterator over a module's functions.
+///
+/// Walks the function list using `LLVMGetFirstFunction`/`LLVMGetNextFunction` internally, via
+/// `ModuleRef::functions_iter`. Also supports iterating from the back via
+/// `LLVMGetLastFunction`/`LLVMGetPreviousFunction`, so `.rev()` and `.next_back()` work as
+/// expected.
+pub struct FunctionIter {
+    front: Option<ValueRef>,
+    back: Option<ValueRef>,
+}
+
+impl Iterator for FunctionIter {
+    type Item = ValueRef;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.front.take()?;
+        if self
+            .back
+            .as_ref()
+            .is_some_and(|back| back.get_ref() == current.get_ref())
+        {
+            self.back = None;
+        } else {
+            self.front = current.get_next_function();
+        }
+        Some(current)
+    }
+}
+
+impl DoubleEndedIterator for FunctionIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let current = self.back.take()?;
+        if self
+            .front
+            .as_ref()
+            .is_some_and(|front| front.get_ref() == current.get_ref())
+        {
+            self.front = None;
+        } else {
+            self.back = current.get_previous_function();
+        }
+        Some(current)
+    }
+}
+
+/// Converts the raw, possibly-null `ValueRef` returned by the `global_aliases` first/last
+/// wrappers into an `Option`, matching the `Option`-returning convention used everywhere else
+/// in this iterator.
+fn non_null_alias(val: ValueRef) -> Option<ValueRef> {
+    if val.get_ref().is_null() {
+        None
+    } else {
+        Some(val)
+    }
+}
+
+/// An iterator over a module's global variables.
+///
+/// Walks the global variable list using `LLVMGetFirstGlobal`/`LLVMGetNextGlobal` internally, via
+/// `ModuleRef::global_variables_iter`. Also supports iterating from the back via
+/// `LLVMGetLastGlobal`/`LLVMGetPreviousGlobal`, so `.rev()` and `.next_back()` work as expected.
+pub struct GlobalVariableIter {
+    front: Option<ValueRef>,
+    back: Option<ValueRef>,
+}
+
+impl Iterator for GlobalVariableIter {
+    type Item = ValueRef;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.front.take()?;
+        if self
+            .back
+            .as_ref()
+            .is_some_and(|back| back.get_ref() == current.get_ref())
+        {
+            self.back = None;
+        } else {
+            self.front = global_variables::get_next_global(&current);
+        }
+        Some(current)
+    }
+}
+
+impl DoubleEndedIterator for GlobalVariableIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let current = self.back.take()?;
+        if self
+            .front
+            .as_ref()
+            .is_some_and(|front| front.get_ref() == current.get_ref())
+        {
+            self.front = None;
+        } else {
+            self.back = global_variables::get_previous_global(&current);
+        }
+        Some(current)
+    }
+}
+
+/// An iterator over a module's global aliases.
+///
+/// Walks the global alias list using `LLVMGetFirstGlobalAlias`/`LLVMGetNextGlobalAlias`
+/// internally, via `ModuleRef::global_aliases_iter`. Also supports iterating from the back via
+/// `LLVMGetLastGlobalAlias`/`LLVMGetPreviousGlobalAlias`, so `.rev()` and `.next_back()` work as
+/// expected.
+pub struct GlobalAliasIter {
+    front: Option<ValueRef>,
+    back: Option<ValueRef>,
+}
+
+impl Iterator for GlobalAliasIter {
+    type Item = ValueRef;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.front.take()?;
+        if self
+            .back
+            .as_ref()
+            .is_some_and(|back| back.get_ref() == current.get_ref())
+        {
+            self.back = None;
+        } else {
+            self.front = global_aliases::get_next_global_alias(&current);
+        }
+        Some(current)
+    }
+}
+
+impl DoubleEndedIterator for GlobalAliasIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let current = self.back.take()?;
+        if self
+            .front
+            .as_ref()
+            .is_some_and(|front| front.get_ref() == current.get_ref())
+        {
+            self.front = None;
+        } else {
+            self.back = global_aliases::get_previous_global_alias(&current);
+        }
+        Some(current)
+    }
+}
+
 /// LLVM Module wrapper
 pub struct ModuleRef(LLVMModuleRef);
 
@@ -234,7 +428,7 @@ impl ModuleRef {
     /// It panics if module creation is null
     #[must_use]
     pub fn create_module_with_name(module_name: &str) -> Self {
-        let c_name = CString::from(module_name);
+        let c_name = CString::try_from(module_name).expect("string contains an interior NUL byte");
         let module_ref = unsafe { LLVMModuleCreateWithName(c_name.as_ptr()) };
         // Force panic as it's unexpected situation
         assert!(!module_ref.is_null(), "Failed to create LLVM module");
@@ -245,7 +439,7 @@ impl ModuleRef {
     /// It panics if module creation is null
     #[must_use]
     pub fn create_module_with_name_in_context(module_name: &str, context: &ContextRef) -> Self {
-        let c_name = CString::from(module_name);
+        let c_name = CString::try_from(module_name).expect("string contains an interior NUL byte");
         let module_ref =
             unsafe { LLVMModuleCreateWithNameInContext(c_name.as_ptr(), context.get_ref()) };
         // Force panic as it's unexpected situation
@@ -263,7 +457,7 @@ impl ModuleRef {
     /// Obtain the identifier of a module.
     #[must_use]
     pub fn get_module_identifier(&self) -> Option<String> {
-        let mut length = *SizeT::from(0_usize);
+        let mut length = *SizeT::try_from(0_usize).expect("value does not fit in size_t");
         unsafe {
             let c_str = LLVMGetModuleIdentifier(self.0, &mut length);
             if c_str.is_null() {
@@ -276,12 +470,12 @@ impl ModuleRef {
 
     /// Set the identifier of a module to a string Ident with length Len.
     pub fn set_module_identifier(&self, ident: &str) {
-        let c_ident = CString::from(ident);
+        let c_ident = CString::try_from(ident).expect("string contains an interior NUL byte");
         unsafe {
             LLVMSetModuleIdentifier(
                 self.0,
                 c_ident.as_ptr(),
-                *SizeT::from(c_ident.to_bytes().len()),
+                *SizeT::try_from(c_ident.to_bytes().len()).expect("value does not fit in size_t"),
             );
         }
     }
@@ -289,7 +483,7 @@ impl ModuleRef {
     /// Obtain the module's original source file name.
     #[must_use]
     pub fn get_source_file_name(&self) -> Option<String> {
-        let mut length = *SizeT::from(0_usize);
+        let mut length = *SizeT::try_from(0_usize).expect("value does not fit in size_t");
         unsafe {
             let c_str = LLVMGetSourceFileName(self.0, &mut length);
             if c_str.is_null() {
@@ -302,12 +496,12 @@ impl ModuleRef {
 
     /// Set the original source file name of a module to a string Name with length Len.
     pub fn set_source_file_name(&self, name: &str) {
-        let c_name = CString::from(name);
+        let c_name = CString::try_from(name).expect("string contains an interior NUL byte");
         unsafe {
             LLVMSetSourceFileName(
                 self.0,
                 c_name.as_ptr(),
-                *SizeT::from(c_name.to_bytes().len()),
+                *SizeT::try_from(c_name.to_bytes().len()).expect("value does not fit in size_t"),
             );
         }
     }
@@ -327,7 +521,7 @@ impl ModuleRef {
 
     /// Set the data layout for a module.
     pub fn set_data_layout(&self, data_layout_str: &str) {
-        let c_data_layout_str = CString::from(data_layout_str);
+        let c_data_layout_str = CString::try_from(data_layout_str).expect("string contains an interior NUL byte");
         unsafe {
             LLVMSetDataLayout(self.0, c_data_layout_str.as_ptr());
         }
@@ -348,7 +542,7 @@ impl ModuleRef {
 
     /// Set the target triple for a module.
     pub fn set_target(&self, triple: &str) {
-        let c_triple = CString::from(triple);
+        let c_triple = CString::try_from(triple).expect("string contains an interior NUL byte");
         unsafe {
             LLVMSetTarget(self.0, c_triple.as_ptr());
         }
@@ -372,14 +566,14 @@ impl ModuleRef {
     /// Add a module-level flag to the module-level flags metadata if it doesn't already exist.
     #[must_use]
     pub fn get_module_flag(&self, key: &str) -> MetadataRef {
-        let c_key = CString::from(key);
+        let c_key = CString::try_from(key).expect("string contains an interior NUL byte");
         let metadata =
             unsafe { LLVMGetModuleFlag(self.0, c_key.as_ptr(), *SizeT(c_key.to_bytes().len())) };
         MetadataRef(metadata)
     }
 
     pub fn add_module_flag(&self, behavior: &ModuleFlagBehavior, key: &str, val: &MetadataRef) {
-        let c_key = CString::from(key);
+        let c_key = CString::try_from(key).expect("string contains an interior NUL byte");
         unsafe {
             LLVMAddModuleFlag(
                 self.0,
@@ -404,7 +598,7 @@ impl ModuleRef {
     /// # Errors
     /// Return error as `String` if print module fails
     pub fn print_module_to_file(&self, filename: &str) -> Result<(), String> {
-        let c_filename = CString::from(filename);
+        let c_filename = CString::try_from(filename).expect("string contains an interior NUL byte");
         let mut error_message: *mut std::ffi::c_char = std::ptr::null_mut();
         let result =
             unsafe { LLVMPrintModuleToFile(self.0, c_filename.as_ptr(), &mut error_message) };
@@ -438,7 +632,7 @@ impl ModuleRef {
     #[must_use]
     pub fn get_module_inline_asm(&self) -> Option<String> {
         unsafe {
-            let mut len = SizeT::from(0_usize);
+            let mut len = SizeT::try_from(0_usize).expect("value does not fit in size_t");
             let c_str = LLVMGetModuleInlineAsm(self.0, &mut *len);
             if c_str.is_null() {
                 None
@@ -450,7 +644,7 @@ impl ModuleRef {
 
     /// Set inline assembly for a module.
     pub fn set_module_inline_asm(&self, asm: &str) {
-        let c_asm = CString::from(asm);
+        let c_asm = CString::try_from(asm).expect("string contains an interior NUL byte");
         unsafe {
             LLVMSetModuleInlineAsm2(self.0, c_asm.as_ptr(), *SizeT(c_asm.to_bytes().len()));
         }
@@ -458,7 +652,7 @@ impl ModuleRef {
 
     /// Append inline assembly to a module.
     pub fn append_module_inline_asm(&self, asm: &str) {
-        let c_asm = CString::from(asm);
+        let c_asm = CString::try_from(asm).expect("string contains an interior NUL byte");
         unsafe {
             LLVMAppendModuleInlineAsm(self.0, c_asm.as_ptr(), *SizeT(c_asm.to_bytes().len()));
         }
@@ -466,9 +660,9 @@ impl ModuleRef {
 
     /// Set add function value based on Function type
     #[must_use]
-    pub fn add_function(&self, fn_name: &str, fn_type: &TypeRef) -> ValueRef {
+    pub fn add_function(&self, fn_name: &str, fn_type: &TypeRef<'_>) -> ValueRef {
         unsafe {
-            let c_name = CString::from(fn_name);
+            let c_name = CString::try_from(fn_name).expect("string contains an interior NUL byte");
             ValueRef::from(LLVMAddFunction(self.0, c_name.as_ptr(), **fn_type))
         }
     }
@@ -479,6 +673,73 @@ impl ModuleRef {
         ContextRef::from(unsafe { LLVMGetModuleContext(self.0) })
     }
 
+    /// Obtain a function in this module by its name.
+    ///
+    /// This function wraps the `LLVMGetNamedFunction` function from the LLVM core library.
+    #[must_use]
+    pub fn get_named_function(&self, name: &str) -> Option<ValueRef> {
+        let c_name = CString::try_from(name).expect("string contains an interior NUL byte");
+        let func = unsafe { llvm_sys::core::LLVMGetNamedFunction(self.0, c_name.as_ptr()) };
+        if func.is_null() {
+            None
+        } else {
+            Some(ValueRef::from(func))
+        }
+    }
+
+    /// Obtain the first function in this module.
+    ///
+    /// This function wraps the `LLVMGetFirstFunction` function from the LLVM core library.
+    #[must_use]
+    pub fn get_first_function(&self) -> Option<ValueRef> {
+        let func = unsafe { LLVMGetFirstFunction(self.0) };
+        if func.is_null() {
+            None
+        } else {
+            Some(ValueRef::from(func))
+        }
+    }
+
+    /// Obtain the last function in this module.
+    ///
+    /// This function wraps the `LLVMGetLastFunction` function from the LLVM core library.
+    #[must_use]
+    pub fn get_last_function(&self) -> Option<ValueRef> {
+        let func = unsafe { llvm_sys::core::LLVMGetLastFunction(self.0) };
+        if func.is_null() {
+            None
+        } else {
+            Some(ValueRef::from(func))
+        }
+    }
+
+    /// Returns an iterator over the functions defined or declared in this module, in order.
+    #[must_use]
+    pub fn functions_iter(&self) -> FunctionIter {
+        FunctionIter {
+            front: self.get_first_function(),
+            back: self.get_last_function(),
+        }
+    }
+
+    /// Returns an iterator over the global variables defined in this module, in order.
+    #[must_use]
+    pub fn global_variables_iter(&self) -> GlobalVariableIter {
+        GlobalVariableIter {
+            front: global_variables::get_first_global(self),
+            back: global_variables::get_last_global(self),
+        }
+    }
+
+    /// Returns an iterator over the global aliases defined in this module, in order.
+    #[must_use]
+    pub fn global_aliases_iter(&self) -> GlobalAliasIter {
+        GlobalAliasIter {
+            front: non_null_alias(global_aliases::get_first_global_alias(self)),
+            back: non_null_alias(global_aliases::get_last_global_alias(self)),
+        }
+    }
+
     /// Obtain an iterator to the first `NamedMDNode` in a `Module`.
     #[must_use]
     pub fn get_first_named_metadata(&self) -> Option<NamedMetadataNodeRef> {
@@ -504,7 +765,7 @@ impl ModuleRef {
     ///  Retrieve a `NamedMetadataNode` with the given name, returning `None` if no such node exists.
     #[must_use]
     pub fn get_named_metadata(&self, name: &str) -> Option<NamedMetadataNodeRef> {
-        let c_name = CString::from(name);
+        let c_name = CString::try_from(name).expect("string contains an interior NUL byte");
         let md = unsafe {
             LLVMGetNamedMetadata(self.0, c_name.as_ptr(), *SizeT(c_name.as_bytes().len()))
         };
@@ -518,7 +779,7 @@ impl ModuleRef {
     /// Retrieve a `NamedMetadataNode` with the given name, creating a new node if no such node exists.
     #[must_use]
     pub fn get_or_insert_named_metadata(&self, name: &str) -> NamedMetadataNodeRef {
-        let c_name = CString::from(name);
+        let c_name = CString::try_from(name).expect("string contains an interior NUL byte");
         let md = unsafe {
             LLVMGetOrInsertNamedMetadata(self.0, c_name.as_ptr(), *SizeT(c_name.as_bytes().len()))
         };
@@ -528,7 +789,7 @@ impl ModuleRef {
     /// Obtain the number of operands for named metadata in a module.
     #[must_use]
     pub fn get_named_metadata_num_operands(&self, name: &str) -> u32 {
-        let c_name = CString::from(name);
+        let c_name = CString::try_from(name).expect("string contains an interior NUL byte");
         unsafe { LLVMGetNamedMetadataNumOperands(self.0, c_name.as_ptr()) }
     }
 
@@ -540,7 +801,7 @@ impl ModuleRef {
     /// instance corresponds to a Metadata Node.
     #[must_use]
     pub fn get_named_metadata_operands(&self, name: &str) -> Vec<ValueRef> {
-        let c_name = CString::from(name);
+        let c_name = CString::try_from(name).expect("string contains an interior NUL byte");
         let num_operands = self.get_named_metadata_num_operands(name);
         let mut raw_operands: Vec<LLVMValueRef> = Vec::with_capacity(num_operands as usize);
         unsafe {
@@ -552,9 +813,208 @@ impl ModuleRef {
 
     /// Add an operand to named metadata.
     pub fn add_named_metadata_operand(&self, name: &str, val: &ValueRef) {
-        let c_name = CString::from(name);
+        let c_name = CString::try_from(name).expect("string contains an interior NUL byte");
         unsafe { LLVMAddNamedMetadataOperand(self.0, c_name.as_ptr(), val.get_ref()) };
     }
+
+    /// Write the module's bitcode to a file at the given path.
+    ///
+    /// This function wraps the `LLVMWriteBitcodeToFile` function from the LLVM core library.
+    ///
+    /// # Errors
+    /// Returns `Err` with a description if the bitcode could not be written to `path`.
+    pub fn write_bitcode_to_path(&self, path: &str) -> Result<(), String> {
+        let c_path = CString::try_from(path).expect("string contains an interior NUL byte");
+        let result = unsafe { llvm_sys::bit_writer::LLVMWriteBitcodeToFile(self.0, c_path.as_ptr()) };
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(format!("Failed to write bitcode to path: {path}"))
+        }
+    }
+
+    /// Write the module's bitcode into an in-memory buffer.
+    ///
+    /// This function wraps the `LLVMWriteBitcodeToMemoryBuffer` function from the LLVM core library.
+    #[must_use]
+    pub fn write_bitcode_to_memory_buffer(&self) -> MemoryBufferRef {
+        let buffer = unsafe { llvm_sys::bit_writer::LLVMWriteBitcodeToMemoryBuffer(self.0) };
+        MemoryBufferRef::from(buffer)
+    }
+
+    /// Parse bitcode from a memory buffer into a module, within the given context.
+    ///
+    /// This function wraps the `LLVMParseBitcodeInContext2` function from the LLVM core library.
+    ///
+    /// # Errors
+    /// Returns `Err` with a description if the buffer does not contain valid bitcode.
+    pub fn parse_bitcode_in_context(
+        context: &ContextRef,
+        buffer: &MemoryBufferRef,
+    ) -> Result<Self, String> {
+        let mut module_ref: LLVMModuleRef = std::ptr::null_mut();
+        let result = unsafe {
+            llvm_sys::bit_reader::LLVMParseBitcodeInContext2(
+                context.get_ref(),
+                buffer.get_ref(),
+                &mut module_ref,
+            )
+        };
+        if result == 0 && !module_ref.is_null() {
+            Ok(Self(module_ref))
+        } else {
+            Err("Failed to parse bitcode in context".to_string())
+        }
+    }
+
+    /// Link `other` into this module, consuming it.
+    ///
+    /// This function wraps the `LLVMLinkModules2` function from the LLVM core library. LLVM
+    /// destroys the source module as part of linking, so `other` is taken by value and its
+    /// `Drop` is suppressed afterwards to avoid a double free. Flag conflicts between the two
+    /// modules are resolved according to each flag's `ModuleFlagBehavior`.
+    ///
+    /// # Errors
+    /// Returns `Err` with a linker diagnostic if linking fails.
+    pub fn link_in_module(&self, other: Self) -> Result<(), String> {
+        let result = unsafe { llvm_sys::linker::LLVMLinkModules2(self.0, other.0) };
+        std::mem::forget(other);
+        if result == 0 {
+            Ok(())
+        } else {
+            Err("Failed to link module".to_string())
+        }
+    }
+
+    /// Create a `DebugInfoBuilder` for emitting debug metadata into this module.
+    ///
+    /// This function wraps the `LLVMCreateDIBuilder` function from the LLVM core library.
+    #[must_use]
+    pub fn create_debug_info_builder(&self) -> crate::core::debug_info::DebugInfoBuilder {
+        crate::core::debug_info::DebugInfoBuilder::new(self)
+    }
+
+    /// Split this module's defined functions into `n` independently codegen-able units.
+    ///
+    /// Each defined (non-declaration) function's name is hashed into one of `n` buckets to get a
+    /// stable partition. The module is then cloned `n` times via `LLVMCloneModule`, and in clone
+    /// *i* every defined function **not** assigned to bucket *i* has its body stripped (its basic
+    /// blocks deleted via `LLVMDeleteBasicBlock`), turning it into an external declaration so
+    /// cross-unit calls still resolve. Global variables and type definitions, as well as the
+    /// `data_layout`/`target`, are preserved in every clone because `LLVMCloneModule` copies them
+    /// unconditionally.
+    ///
+    /// This gives callers a build-time parallelism knob (each unit can be optimized/codegen'd on
+    /// its own thread) at the cost of losing cross-unit inlining.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    #[must_use]
+    pub fn split_into_units(&self, n: usize) -> Vec<Self> {
+        assert!(n > 0, "split_into_units requires at least one unit");
+
+        let mut bucket_of_name = std::collections::HashMap::new();
+        let mut current = unsafe { LLVMGetFirstFunction(self.0) };
+        while !current.is_null() {
+            if unsafe { LLVMIsDeclaration(current) } == 0 {
+                let bucket = function_name_hash(current) % n;
+                bucket_of_name.insert(function_name(current), bucket);
+            }
+            current = unsafe { LLVMGetNextFunction(current) };
+        }
+
+        (0..n)
+            .map(|unit| {
+                let clone = self.clone_module();
+                let mut func = unsafe { LLVMGetFirstFunction(clone.0) };
+                while !func.is_null() {
+                    let next = unsafe { LLVMGetNextFunction(func) };
+                    let is_defined = unsafe { LLVMIsDeclaration(func) } == 0;
+                    let assigned_bucket = bucket_of_name.get(&function_name(func)).copied();
+                    if is_defined && assigned_bucket != Some(unit) {
+                        strip_function_body(func);
+                    }
+                    func = next;
+                }
+                clone
+            })
+            .collect()
+    }
+
+    /// Verify that this module is valid, returning a description of any invalidity found.
+    ///
+    /// This wraps `LLVMVerifyModule` with `LLVMReturnStatusAction`, so LLVM never aborts or
+    /// prints to stderr on failure; the diagnostic is instead captured and returned.
+    ///
+    /// # Errors
+    /// Returns `Err` with LLVM's description of the problems found if the module is invalid.
+    pub fn verify(&self) -> Result<(), String> {
+        self.verify_with_action(VerifierFailureAction::ReturnStatusAction)
+    }
+
+    /// Verify that this module is valid, using the given action on failure.
+    ///
+    /// This function wraps the `LLVMVerifyModule` function from the LLVM core library.
+    ///
+    /// # Errors
+    /// Returns `Err` with LLVM's description of the problems found if the module is invalid.
+    pub fn verify_with_action(&self, action: VerifierFailureAction) -> Result<(), String> {
+        let mut error_message: *mut std::ffi::c_char = std::ptr::null_mut();
+        let result = unsafe {
+            llvm_sys::analysis::LLVMVerifyModule(self.0, action.into(), &mut error_message)
+        };
+        if result == 0 {
+            Ok(())
+        } else {
+            unsafe {
+                let error = if error_message.is_null() {
+                    String::new()
+                } else {
+                    let message = CStr::new(error_message).to_string();
+                    crate::core::dispose_message(error_message);
+                    message
+                };
+                Err(error)
+            }
+        }
+    }
+}
+
+/// Reads the name of a raw function value, used by `ModuleRef::split_into_units` to key its
+/// bucket assignment. Unnamed functions fall back to an empty string, which buckets them all
+/// together; LLVM functions referenced across units are always named.
+fn function_name(func: LLVMValueRef) -> String {
+    let mut length = 0_usize;
+    unsafe {
+        let c_str = LLVMGetValueName2(func, &mut length);
+        if c_str.is_null() {
+            String::new()
+        } else {
+            CStr::new(c_str).to_string()
+        }
+    }
+}
+
+/// Hashes a raw function value's name into a stable, deterministic bucket index.
+fn function_name_hash(func: LLVMValueRef) -> usize {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    function_name(func).hash(&mut hasher);
+    hasher.finish() as usize
+}
+
+/// Strips a function's body by deleting all of its basic blocks, turning it from a definition
+/// into an external declaration while keeping the function itself (and therefore calls to it)
+/// intact.
+fn strip_function_body(func: LLVMValueRef) {
+    unsafe {
+        let mut block = LLVMGetFirstBasicBlock(func);
+        while !block.is_null() {
+            let next = LLVMGetNextBasicBlock(block);
+            LLVMDeleteBasicBlock(block);
+            block = next;
+        }
+    }
 }
 
 /// Get the template string used for an inline assembly snippet.
@@ -566,7 +1026,7 @@ pub fn get_inline_asm_asm_string(inline_asm_val: &ValueRef) -> Option<String> {
 /// Create the specified unique inline asm string.
 #[must_use]
 pub fn get_inline_asm(
-    ty: &TypeRef,
+    ty: &TypeRef<'_>,
     asm_string: &str,
     constraints: &str,
     has_side_effects: bool,
@@ -574,22 +1034,15 @@ pub fn get_inline_asm(
     dialect: InlineAsmDialect,
     can_throw: bool,
 ) -> ValueRef {
-    let c_asm_string = CString::from(asm_string);
-    let c_constraints = CString::from(constraints);
-    let value_ref = unsafe {
-        LLVMGetInlineAsm(
-            ty.get_ref(),
-            c_asm_string.as_ptr(),
-            *SizeT(c_asm_string.to_bytes().len()),
-            c_constraints.as_ptr(),
-            *SizeT(c_constraints.to_bytes().len()),
-            *CInt::from(has_side_effects),
-            *CInt::from(is_align_stack),
-            dialect.into(),
-            *CInt::from(can_throw),
-        )
-    };
-    ValueRef::from(value_ref)
+    ValueRef::get_inline_asm(
+        ty,
+        asm_string,
+        constraints,
+        has_side_effects,
+        is_align_stack,
+        dialect,
+        can_throw,
+    )
 }
 
 /// Get the raw constraint string for an inline assembly snippet.
@@ -608,7 +1061,7 @@ pub fn get_inline_asm_dialect(inline_asm_val: &ValueRef) -> InlineAsmDialect {
 ///
 /// This is the same type that was passed into `LLVMGetInlineAsm` originally.
 #[must_use]
-pub fn get_inline_asm_function_type(inline_asm_val: &ValueRef) -> TypeRef {
+pub fn get_inline_asm_function_type(inline_asm_val: &ValueRef) -> TypeRef<'_> {
     inline_asm_val.get_inline_asm_function_type()
 }
 
@@ -658,6 +1111,18 @@ pub fn get_debug_loc_column(val: &ValueRef) -> u32 {
     val.get_debug_loc_column()
 }
 
+/// Attach `loc` as the debug location of this value, which must be an LLVM `Instruction`.
+pub fn set_debug_loc(val: &ValueRef, loc: &MetadataRef) {
+    val.set_debug_loc(loc);
+}
+
+/// Return the `DILocation` metadata node attached to this value, which must be an LLVM
+/// `Instruction`, or `None` if no debug location is attached.
+#[must_use]
+pub fn get_debug_loc(val: &ValueRef) -> Option<MetadataRef> {
+    val.get_debug_loc()
+}
+
 impl Deref for ModuleRef {
     type Target = LLVMModuleRef;
     fn deref(&self) -> &Self::Target {
@@ -0,0 +1,252 @@
+//! Functions in this section operate on target data layouts.
+
+use crate::core::types::structs::StructTypeRef;
+use crate::core::types::TypeRef;
+use crate::{CString, CUint, GetRef};
+use llvm_sys::target;
+use llvm_sys::target::LLVMTargetDataRef;
+
+/// LLVM `TargetData` wrapper.
+///
+/// A `TargetDataRef` describes the size, byte order and alignment properties of a target machine
+/// as a parsed data layout string. Types have no inherent size or alignment of their own until
+/// related to a data layout, since that information is target-specific; a `TargetDataRef` is what
+/// makes ABI-aware size/alignment queries on `TypeRef` (see
+/// [`TypeRef::abi_size_of_type`](crate::core::types::TypeRef::abi_size_of_type) and friends)
+/// possible.
+#[derive(Debug)]
+pub struct TargetDataRef(LLVMTargetDataRef);
+
+impl From<LLVMTargetDataRef> for TargetDataRef {
+    fn from(value: LLVMTargetDataRef) -> Self {
+        Self(value)
+    }
+}
+
+impl GetRef for TargetDataRef {
+    type RawRef = LLVMTargetDataRef;
+    fn get_ref(&self) -> Self::RawRef {
+        self.0
+    }
+}
+
+impl TargetDataRef {
+    /// Creates a target data layout from its string representation.
+    ///
+    /// # Details
+    ///
+    /// Wraps the `LLVMCreateTargetData` function from the LLVM target library. It parses
+    /// `data_layout_str` (the same textual data layout format accepted by
+    /// `ModuleRef::set_data_layout`) and returns a `TargetDataRef` describing the target's size,
+    /// alignment and byte order properties.
+    ///
+    /// # Parameters
+    ///
+    /// - `data_layout_str`: The textual data layout of the target, e.g. as obtained from
+    ///   `ModuleRef::get_data_layout_str` or a `TargetMachine`.
+    ///
+    /// # Returns
+    ///
+    /// Returns an instance of `Self` representing the parsed target data layout.
+    #[must_use]
+    pub fn new(data_layout_str: &str) -> Self {
+        let c_data_layout_str = CString::try_from(data_layout_str).expect("string contains an interior NUL byte");
+        unsafe { Self(target::LLVMCreateTargetData(c_data_layout_str.as_ptr())) }
+    }
+}
+
+impl Drop for TargetDataRef {
+    /// Deinitialize this value and dispose of its resources.
+    ///
+    /// Destroy a target data layout instance.
+    fn drop(&mut self) {
+        unsafe { target::LLVMDisposeTargetData(self.0) }
+    }
+}
+
+impl<'ctx> TypeRef<'ctx> {
+    /// Computes the number of bits necessary to hold a value of this type for a given target,
+    /// ignoring alignment/padding.
+    ///
+    /// # Details
+    ///
+    /// Wraps the `LLVMSizeOfTypeInBits` function from the LLVM target library.
+    ///
+    /// # Parameters
+    ///
+    /// - `target_data`: The `TargetDataRef` describing the layout to compute the size against.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `u64` representing the size of the type in bits.
+    #[must_use]
+    pub fn size_of_type_in_bits(&self, target_data: &TargetDataRef) -> u64 {
+        unsafe { target::LLVMSizeOfTypeInBits(target_data.get_ref(), self.get_ref()) }
+    }
+
+    /// Computes the ABI size of a type in bytes for a given target.
+    ///
+    /// # Details
+    ///
+    /// Wraps the `LLVMABISizeOfType` function from the LLVM target library.
+    ///
+    /// # Parameters
+    ///
+    /// - `target_data`: The `TargetDataRef` describing the layout to compute the size against.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `u64` representing the ABI size of the type in bytes.
+    #[must_use]
+    pub fn abi_size_of_type(&self, target_data: &TargetDataRef) -> u64 {
+        unsafe { target::LLVMABISizeOfType(target_data.get_ref(), self.get_ref()) }
+    }
+
+    /// Computes the store size of a type in bytes for a given target.
+    ///
+    /// # Details
+    ///
+    /// Wraps the `LLVMStoreSizeOfType` function from the LLVM target library. The store size may
+    /// differ from the ABI size for types whose in-memory representation is rounded up to the
+    /// nearest byte (e.g. `i1`).
+    ///
+    /// # Parameters
+    ///
+    /// - `target_data`: The `TargetDataRef` describing the layout to compute the size against.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `u64` representing the store size of the type in bytes.
+    #[must_use]
+    pub fn store_size_of_type(&self, target_data: &TargetDataRef) -> u64 {
+        unsafe { target::LLVMStoreSizeOfType(target_data.get_ref(), self.get_ref()) }
+    }
+
+    /// Computes the ABI alignment of a type in bytes for a given target.
+    ///
+    /// # Details
+    ///
+    /// Wraps the `LLVMABIAlignmentOfType` function from the LLVM target library.
+    ///
+    /// # Parameters
+    ///
+    /// - `target_data`: The `TargetDataRef` describing the layout to compute the alignment against.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `u32` representing the ABI alignment of the type in bytes.
+    #[must_use]
+    pub fn abi_alignment_of_type(&self, target_data: &TargetDataRef) -> u32 {
+        unsafe { target::LLVMABIAlignmentOfType(target_data.get_ref(), self.get_ref()) }
+    }
+
+    /// Computes the preferred alignment of a type in bytes for a given target.
+    ///
+    /// # Details
+    ///
+    /// Wraps the `LLVMPreferredAlignmentOfType` function from the LLVM target library. The
+    /// preferred alignment is the alignment LLVM's optimizer tries to use for allocas of this
+    /// type; it is always at least the ABI alignment.
+    ///
+    /// # Parameters
+    ///
+    /// - `target_data`: The `TargetDataRef` describing the layout to compute the alignment against.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `u32` representing the preferred alignment of the type in bytes.
+    #[must_use]
+    pub fn preferred_alignment_of_type(&self, target_data: &TargetDataRef) -> u32 {
+        unsafe { target::LLVMPreferredAlignmentOfType(target_data.get_ref(), self.get_ref()) }
+    }
+}
+
+impl<'ctx> StructTypeRef<'ctx> {
+    /// Computes the ABI size of the structure in bytes for a given target.
+    ///
+    /// # Details
+    ///
+    /// Since the LangRef notes that padding between non-packed struct fields is inserted "as
+    /// defined by the `TargetData` string in the module", a structure's in-memory size is only
+    /// knowable relative to a `TargetDataRef`. Wraps `LLVMABISizeOfType` via
+    /// [`TypeRef::abi_size_of_type`].
+    ///
+    /// # Parameters
+    ///
+    /// - `target_data`: The `TargetDataRef` describing the layout to compute the size against.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `u64` representing the ABI size of the structure in bytes.
+    #[must_use]
+    pub fn abi_size_in_bytes(&self, target_data: &TargetDataRef) -> u64 {
+        TypeRef::from(self.get_ref()).abi_size_of_type(target_data)
+    }
+
+    /// Computes the ABI alignment of the structure in bytes for a given target.
+    ///
+    /// # Details
+    ///
+    /// Wraps `LLVMABIAlignmentOfType` via [`TypeRef::abi_alignment_of_type`].
+    ///
+    /// # Parameters
+    ///
+    /// - `target_data`: The `TargetDataRef` describing the layout to compute the alignment against.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `u32` representing the ABI alignment of the structure in bytes.
+    #[must_use]
+    pub fn abi_alignment(&self, target_data: &TargetDataRef) -> u32 {
+        TypeRef::from(self.get_ref()).abi_alignment_of_type(target_data)
+    }
+
+    /// Computes the byte offset of a field within the structure for a given target.
+    ///
+    /// # Details
+    ///
+    /// Wraps the `LLVMOffsetOfElement` function from the LLVM target library. This accounts for
+    /// any padding LLVM inserts ahead of `index` under the given data layout, so it is the
+    /// authoritative way to compute a field's real in-memory offset (e.g. for FFI-compatible
+    /// layouts or manual GEP index computation).
+    ///
+    /// # Parameters
+    ///
+    /// - `target_data`: The `TargetDataRef` describing the layout to compute the offset against.
+    /// - `index`: The index of the field (element) within the structure. The index is zero-based.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `u64` representing the byte offset of the field at `index`.
+    #[must_use]
+    pub fn element_offset(&self, target_data: &TargetDataRef, index: u32) -> u64 {
+        unsafe {
+            target::LLVMOffsetOfElement(
+                target_data.get_ref(),
+                self.get_ref(),
+                *CUint::try_from(index).expect("value does not fit in c_uint"),
+            )
+        }
+    }
+
+    /// Determines which field of the structure contains a given byte offset for a given target.
+    ///
+    /// # Details
+    ///
+    /// Wraps the `LLVMElementAtOffset` function from the LLVM target library. This is the inverse
+    /// of `element_offset`: given a byte offset into the structure's in-memory layout, it returns
+    /// the index of the field that offset falls within.
+    ///
+    /// # Parameters
+    ///
+    /// - `target_data`: The `TargetDataRef` describing the layout to compute the field against.
+    /// - `offset`: The byte offset into the structure's in-memory layout.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `u32` representing the index of the field containing `offset`.
+    #[must_use]
+    pub fn element_at_offset(&self, target_data: &TargetDataRef, offset: u64) -> u32 {
+        unsafe { target::LLVMElementAtOffset(target_data.get_ref(), self.get_ref(), offset) }
+    }
+}
@@ -0,0 +1,105 @@
+//! Native Rust type to LLVM `TypeRef` mapping.
+
+use crate::core::context::ContextRef;
+use crate::core::types::float::FloatTypeRef;
+use crate::core::types::int::IntTypeRef;
+use crate::core::types::other::VoidTypeRef;
+use crate::core::types::sequential::SequentialTypeRef;
+use crate::core::types::TypeRef;
+
+/// Maps a native Rust type to the `TypeRef` it compiles to in a given context.
+///
+/// Implemented for Rust's integer and floating-point primitives, `bool`, `()`, raw pointers and
+/// fixed-size arrays, mirroring the `Compile`/`Type::get::<T>(context)` pattern from the llvm-alt
+/// and llvm-rs wrappers. This lets callers write type-safe codegen, e.g. `TypeRef::of::<i32>(ctx)`,
+/// without hand-threading `LLVMInt32TypeInContext` and friends.
+pub trait Compile {
+    /// Returns the `TypeRef` that `Self` compiles to in `context`.
+    fn compile(context: &ContextRef) -> TypeRef<'_>;
+}
+
+macro_rules! impl_compile_int {
+    ($($ty:ty => $ctor:ident),* $(,)?) => {
+        $(
+            impl Compile for $ty {
+                fn compile(context: &ContextRef) -> TypeRef<'_> {
+                    IntTypeRef::$ctor(context).into()
+                }
+            }
+        )*
+    };
+}
+
+impl_compile_int! {
+    i8 => int8_type_in_context,
+    u8 => int8_type_in_context,
+    i16 => int16_type_in_context,
+    u16 => int16_type_in_context,
+    i32 => int32_type_in_context,
+    u32 => int32_type_in_context,
+    i64 => int64_type_in_context,
+    u64 => int64_type_in_context,
+    i128 => int128_type_in_context,
+    u128 => int128_type_in_context,
+}
+
+impl Compile for bool {
+    fn compile(context: &ContextRef) -> TypeRef<'_> {
+        IntTypeRef::int1_type_in_context(context).into()
+    }
+}
+
+impl Compile for f32 {
+    fn compile(context: &ContextRef) -> TypeRef<'_> {
+        FloatTypeRef::float_type_in_context(context).into()
+    }
+}
+
+impl Compile for f64 {
+    fn compile(context: &ContextRef) -> TypeRef<'_> {
+        FloatTypeRef::double_type_in_context(context).into()
+    }
+}
+
+impl Compile for () {
+    fn compile(context: &ContextRef) -> TypeRef<'_> {
+        VoidTypeRef::void_type_in_context(context).into()
+    }
+}
+
+impl<T> Compile for *const T {
+    fn compile(context: &ContextRef) -> TypeRef<'_> {
+        SequentialTypeRef::opaque_pointer_type_in_context(context, 0).into()
+    }
+}
+
+impl<T> Compile for *mut T {
+    fn compile(context: &ContextRef) -> TypeRef<'_> {
+        SequentialTypeRef::opaque_pointer_type_in_context(context, 0).into()
+    }
+}
+
+impl<T: Compile, const N: usize> Compile for [T; N] {
+    fn compile(context: &ContextRef) -> TypeRef<'_> {
+        let element_type = T::compile(context);
+        SequentialTypeRef::array_type2(&element_type, N as u64).into()
+    }
+}
+
+impl<'ctx> TypeRef<'ctx> {
+    /// Returns the `TypeRef` that Rust type `T` compiles to in `context`.
+    ///
+    /// # Details
+    ///
+    /// A generic entry point over [`Compile`], letting callers write `TypeRef::of::<i32>(ctx)`
+    /// instead of `IntTypeRef::int32_type_in_context(ctx).into()`. Fixed-size array types compose
+    /// for free via the blanket `[T; N]` implementation.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `TypeRef` corresponding to `T` in `context`.
+    #[must_use]
+    pub fn of<T: Compile>(context: &'ctx ContextRef) -> Self {
+        T::compile(context)
+    }
+}
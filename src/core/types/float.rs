@@ -1,28 +1,34 @@
 //! Functions in this section operate on floating point types.
 
 use crate::core::context::ContextRef;
-use crate::core::types::TypeRef;
+use crate::core::types::{TypeKind, TypeRef};
+use crate::core::values::constants::scalar;
+use crate::core::values::ValueRef;
 use crate::GetRef;
 use llvm_sys::core;
 use llvm_sys::prelude::LLVMTypeRef;
+use std::marker::PhantomData;
 
 /// Wrapper `LLVMTypeRef` for floating point types.
+///
+/// Carries the same `'ctx` lifetime as [`TypeRef`], tying a `FloatTypeRef` to the context it was
+/// created from (or to `'static` for types obtained from the global context).
 #[derive(Debug, Clone)]
-pub struct FloatTypeRef(LLVMTypeRef);
+pub struct FloatTypeRef<'ctx>(LLVMTypeRef, PhantomData<&'ctx ContextRef>);
 
-impl From<LLVMTypeRef> for FloatTypeRef {
+impl<'ctx> From<LLVMTypeRef> for FloatTypeRef<'ctx> {
     fn from(value: LLVMTypeRef) -> Self {
-        Self(value)
+        Self(value, PhantomData)
     }
 }
 
-impl From<FloatTypeRef> for TypeRef {
-    fn from(value: FloatTypeRef) -> Self {
-        Self(value.0)
+impl<'ctx> From<FloatTypeRef<'ctx>> for TypeRef<'ctx> {
+    fn from(value: FloatTypeRef<'ctx>) -> Self {
+        Self::from(value.0)
     }
 }
 
-impl GetRef for FloatTypeRef {
+impl<'ctx> GetRef for FloatTypeRef<'ctx> {
     type RawRef = LLVMTypeRef;
     fn get_ref(&self) -> Self::RawRef {
         self.0
@@ -30,7 +36,7 @@ impl GetRef for FloatTypeRef {
 }
 
 /// Obtain a floating point type from the context.
-impl FloatTypeRef {
+impl<'ctx> FloatTypeRef<'ctx> {
     /// Obtain a 16-bit floating point type from a context.
     ///
     /// # Details
@@ -49,8 +55,8 @@ impl FloatTypeRef {
     ///
     /// Returns an instance of `Self` representing the `half` type in the specified context.
     #[must_use]
-    pub fn half_type_in_context(context: &ContextRef) -> Self {
-        unsafe { Self(core::LLVMHalfTypeInContext(context.get_ref())) }
+    pub fn half_type_in_context(context: &'ctx ContextRef) -> Self {
+        unsafe { Self(core::LLVMHalfTypeInContext(context.get_ref()), PhantomData) }
     }
 
     /// Obtain a 16-bit brain floating point type from a context.
@@ -72,8 +78,13 @@ impl FloatTypeRef {
     ///
     /// Returns an instance of `Self` representing the `bfloat` type in the specified context.
     #[must_use]
-    pub fn bfloat_type_in_context(context: &ContextRef) -> Self {
-        unsafe { Self(core::LLVMBFloatTypeInContext(context.get_ref())) }
+    pub fn bfloat_type_in_context(context: &'ctx ContextRef) -> Self {
+        unsafe {
+            Self(
+                core::LLVMBFloatTypeInContext(context.get_ref()),
+                PhantomData,
+            )
+        }
     }
 
     /// Obtain a 32-bit floating point type from a context.
@@ -94,8 +105,13 @@ impl FloatTypeRef {
     ///
     /// Returns an instance of `Self` representing the `float` type in the specified context.
     #[must_use]
-    pub fn float_type_in_context(context: &ContextRef) -> Self {
-        unsafe { Self(core::LLVMFloatTypeInContext(context.get_ref())) }
+    pub fn float_type_in_context(context: &'ctx ContextRef) -> Self {
+        unsafe {
+            Self(
+                core::LLVMFloatTypeInContext(context.get_ref()),
+                PhantomData,
+            )
+        }
     }
 
     /// Obtain a 64-bit floating point type from a context.
@@ -116,8 +132,13 @@ impl FloatTypeRef {
     ///
     /// Returns an instance of `Self` representing the `double` type in the specified context.
     #[must_use]
-    pub fn double_type_in_context(context: &ContextRef) -> Self {
-        unsafe { Self(core::LLVMDoubleTypeInContext(context.get_ref())) }
+    pub fn double_type_in_context(context: &'ctx ContextRef) -> Self {
+        unsafe {
+            Self(
+                core::LLVMDoubleTypeInContext(context.get_ref()),
+                PhantomData,
+            )
+        }
     }
 
     /// Obtain an 80-bit floating point type (X87) from a context.
@@ -139,8 +160,13 @@ impl FloatTypeRef {
     ///
     /// Returns an instance of `Self` representing the `x86_fp80` type in the specified context.
     #[must_use]
-    pub fn x86_fp80_type_in_context(context: &ContextRef) -> Self {
-        unsafe { Self(core::LLVMX86FP80TypeInContext(context.get_ref())) }
+    pub fn x86_fp80_type_in_context(context: &'ctx ContextRef) -> Self {
+        unsafe {
+            Self(
+                core::LLVMX86FP80TypeInContext(context.get_ref()),
+                PhantomData,
+            )
+        }
     }
 
     /// Obtain a 128-bit floating point type (112-bit mantissa) from a context.
@@ -159,8 +185,13 @@ impl FloatTypeRef {
     ///
     /// Returns an instance of `Self` representing the `fp128` type in the specified context.
     #[must_use]
-    pub fn fp128_type_in_context(context: &ContextRef) -> Self {
-        unsafe { Self(core::LLVMFP128TypeInContext(context.get_ref())) }
+    pub fn fp128_type_in_context(context: &'ctx ContextRef) -> Self {
+        unsafe {
+            Self(
+                core::LLVMFP128TypeInContext(context.get_ref()),
+                PhantomData,
+            )
+        }
     }
 
     /// Obtain a 128-bit floating point type (two 64-bits) from a context.
@@ -181,13 +212,131 @@ impl FloatTypeRef {
     ///
     /// Returns an instance of `Self` representing the `ppc_fp128` type in the specified context.
     #[must_use]
-    pub fn ppc_fp128_type_in_context(context: &ContextRef) -> Self {
-        unsafe { Self(core::LLVMPPCFP128TypeInContext(context.get_ref())) }
+    pub fn ppc_fp128_type_in_context(context: &'ctx ContextRef) -> Self {
+        unsafe {
+            Self(
+                core::LLVMPPCFP128TypeInContext(context.get_ref()),
+                PhantomData,
+            )
+        }
+    }
+}
+
+impl<'ctx> FloatTypeRef<'ctx> {
+    /// Obtain the enumerated type of this floating-point type instance.
+    ///
+    /// This function wraps the `LLVMGetTypeKind` function from the LLVM core library.
+    #[must_use]
+    pub fn get_type_kind(&self) -> TypeKind {
+        unsafe { TypeKind::from(core::LLVMGetTypeKind(self.0)) }
+    }
+
+    /// Returns the IEEE-754 (or, for `ppc_fp128`, double-double) semantics of this floating-point
+    /// type.
+    ///
+    /// # Details
+    ///
+    /// `half` and `bfloat` are both 16 bits wide but otherwise unrelated formats: `half` spends
+    /// more of its bits on precision (10 mantissa / 5 exponent bits), while `bfloat` spends more on
+    /// range (7 mantissa / 8 exponent bits, matching `float`'s exponent). [`FloatSemantics`] makes
+    /// that distinction (and the rest of the LLVM float zoo) inspectable without hard-coding it at
+    /// every call site.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` somehow carries a non-floating-point [`TypeKind`]; every constructor on
+    /// this type produces one of the eight floating-point kinds, so this should not occur in
+    /// practice.
+    #[must_use]
+    pub fn semantics(&self) -> FloatSemantics {
+        FloatSemantics::for_kind(self.get_type_kind())
+    }
+}
+
+/// Constant floating-point values of this type.
+impl<'ctx> FloatTypeRef<'ctx> {
+    /// Obtain a constant floating-point value of this type.
+    ///
+    /// # Details
+    ///
+    /// This function wraps the `LLVMConstReal` function from the LLVM core library. It generates
+    /// a constant floating-point value of this type from the provided `f64`. For `x86_fp80` and
+    /// `fp128`, the resulting constant cannot carry more precision than `f64` has to offer; use
+    /// [`Self::const_real_of_string`] when the exact value matters.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The floating-point value to convert into a constant of this type.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `ValueRef` representing the constant floating-point value.
+    #[must_use]
+    pub fn const_real(&self, value: f64) -> ValueRef {
+        scalar::const_real(&TypeRef::from(self.0), value)
+    }
+
+    /// Obtain a constant floating-point value of this type, parsed from a string.
+    ///
+    /// # Details
+    ///
+    /// This function wraps the `LLVMConstRealOfStringAndSize` function from the LLVM core
+    /// library. Unlike [`Self::const_real`], the literal is parsed directly into this type's
+    /// semantics rather than being rounded through an `f64` first, so this is the only correct
+    /// way to construct an exact `fp128` or `x86_fp80` constant whose value cannot be represented
+    /// by a double.
+    ///
+    /// # Parameters
+    ///
+    /// - `text`: A string slice containing the floating-point literal to parse.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `ValueRef` representing the constant floating-point value parsed from `text`.
+    #[must_use]
+    pub fn const_real_of_string(&self, text: &str) -> ValueRef {
+        scalar::const_real_of_string_and_size(&TypeRef::from(self.0), text)
+    }
+
+    /// Obtain a quiet NaN constant of this type.
+    ///
+    /// # Details
+    ///
+    /// NaN is representable exactly in every floating-point format LLVM supports, so this is
+    /// implemented in terms of [`Self::const_real`] without the precision caveats that apply to
+    /// arbitrary values.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `ValueRef` representing a quiet NaN constant of this type.
+    #[must_use]
+    pub fn const_nan(&self) -> ValueRef {
+        self.const_real(f64::NAN)
+    }
+
+    /// Obtain an infinity constant of this type.
+    ///
+    /// # Details
+    ///
+    /// Infinity is representable exactly in every floating-point format LLVM supports, so this is
+    /// implemented in terms of [`Self::const_real`] without the precision caveats that apply to
+    /// arbitrary values.
+    ///
+    /// # Parameters
+    ///
+    /// - `negative`: Whether to produce negative infinity instead of positive infinity.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `ValueRef` representing an infinity constant of this type.
+    #[must_use]
+    pub fn const_inf(&self, negative: bool) -> ValueRef {
+        self.const_real(if negative { f64::NEG_INFINITY } else { f64::INFINITY })
     }
 }
 
 /// Obtain a floating point type from the global context.
-impl FloatTypeRef {
+impl FloatTypeRef<'static> {
     /// Creates a 16-bit floating-point (`half`) type in the global LLVM context.
     ///
     /// This function wraps the `LLVMHalfType` function from the LLVM core library. It creates and returns
@@ -199,7 +348,7 @@ impl FloatTypeRef {
     /// Returns an instance of `Self` representing the `half` type in the global context.
     #[must_use]
     pub fn half_type() -> Self {
-        unsafe { Self(core::LLVMHalfType()) }
+        unsafe { Self(core::LLVMHalfType(), PhantomData) }
     }
 
     /// Creates a 16-bit floating-point (`bfloat`) type in the global LLVM context.
@@ -214,7 +363,7 @@ impl FloatTypeRef {
     /// Returns an instance of `Self` representing the `bfloat` type in the global context.
     #[must_use]
     pub fn bfloat_type() -> Self {
-        unsafe { Self(core::LLVMBFloatType()) }
+        unsafe { Self(core::LLVMBFloatType(), PhantomData) }
     }
 
     /// Creates a 32-bit floating-point (`float`) type in the global LLVM context.
@@ -228,7 +377,7 @@ impl FloatTypeRef {
     /// Returns an instance of `Self` representing the `float` type in the global context.
     #[must_use]
     pub fn float_type() -> Self {
-        unsafe { Self(core::LLVMFloatType()) }
+        unsafe { Self(core::LLVMFloatType(), PhantomData) }
     }
 
     /// Creates a 64-bit floating-point (`double`) type in the global LLVM context.
@@ -242,7 +391,7 @@ impl FloatTypeRef {
     /// Returns an instance of `Self` representing the `double` type in the global context.
     #[must_use]
     pub fn double_type() -> Self {
-        unsafe { Self(core::LLVMDoubleType()) }
+        unsafe { Self(core::LLVMDoubleType(), PhantomData) }
     }
 
     /// Creates an 80-bit floating-point (`x86_fp80`) type in the global LLVM context.
@@ -257,7 +406,7 @@ impl FloatTypeRef {
     /// Returns an instance of `Self` representing the `x86_fp80` type in the global context.
     #[must_use]
     pub fn x86_fp80_type() -> Self {
-        unsafe { Self(core::LLVMX86FP80Type()) }
+        unsafe { Self(core::LLVMX86FP80Type(), PhantomData) }
     }
 
     /// Creates a 128-bit floating-point (`fp128`) type in the global LLVM context.
@@ -271,7 +420,7 @@ impl FloatTypeRef {
     /// Returns an instance of `Self` representing the `fp128` type in the global context.
     #[must_use]
     pub fn fp128_type() -> Self {
-        unsafe { Self(core::LLVMFP128Type()) }
+        unsafe { Self(core::LLVMFP128Type(), PhantomData) }
     }
 
     /// Creates a 128-bit floating-point (`ppc_fp128`) type in the global LLVM context, specific to `PowerPC` architecture.
@@ -285,6 +434,141 @@ impl FloatTypeRef {
     /// Returns an instance of `Self` representing the `ppc_fp128` type in the global context.
     #[must_use]
     pub fn ppc_fp128_type() -> Self {
-        unsafe { Self(core::LLVMPPCFP128Type()) }
+        unsafe { Self(core::LLVMPPCFP128Type(), PhantomData) }
+    }
+}
+
+/// The IEEE-754 (or, for `ppc_fp128`, double-double) semantics of an LLVM floating-point type, as
+/// returned by [`FloatTypeRef::semantics`].
+///
+/// Bundles the storage width, significand/exponent bit counts and exponent bias that the C API
+/// does not expose directly, so formats of equal storage width (`half` vs. `bfloat`) or with
+/// unusual layouts (`x86_fp80`'s explicit integer bit, `ppc_fp128`'s double-double
+/// representation) remain distinguishable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FloatSemantics {
+    kind: TypeKind,
+    bit_width: u32,
+    significand_bits: u32,
+    exponent_bits: u32,
+    exponent_bias: i32,
+    is_brain_float: bool,
+}
+
+impl FloatSemantics {
+    /// Hard-coded semantics for each LLVM floating-point [`TypeKind`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `kind` is not one of the eight floating-point kinds.
+    #[must_use]
+    fn for_kind(kind: TypeKind) -> Self {
+        let (bit_width, significand_bits, exponent_bits, exponent_bias, is_brain_float) = match kind
+        {
+            TypeKind::HalfTypeKind => (16, 10, 5, 15, false),
+            TypeKind::BFloatTypeKind => (16, 7, 8, 127, true),
+            TypeKind::FloatTypeKind => (32, 23, 8, 127, false),
+            TypeKind::DoubleTypeKind => (64, 52, 11, 1023, false),
+            // The explicit integer bit is included in `significand_bits`, unlike the other
+            // (implicit-leading-one) formats.
+            TypeKind::X86_FP80TypeKind => (80, 64, 15, 16383, false),
+            TypeKind::FP128TypeKind => (128, 112, 15, 16383, false),
+            // A pair of `double`s (the "double-double" trick), not a true IEEE-754 format:
+            // `significand_bits` approximates the combined precision when the two components'
+            // exponents are adjacent, and `exponent_bits`/`exponent_bias` match `double`'s, since
+            // the high-order double alone determines the overall magnitude.
+            TypeKind::PPC_FP128TypeKind => (128, 106, 11, 1023, false),
+            _ => panic!("FloatSemantics::for_kind called with a non-floating-point TypeKind"),
+        };
+        Self {
+            kind,
+            bit_width,
+            significand_bits,
+            exponent_bits,
+            exponent_bias,
+            is_brain_float,
+        }
+    }
+
+    /// The total storage width of the format, in bits.
+    #[must_use]
+    pub const fn bit_width(&self) -> u32 {
+        self.bit_width
+    }
+
+    /// The number of significand (mantissa) bits, including any explicit integer bit
+    /// (`x86_fp80`) but excluding any implicit leading one.
+    #[must_use]
+    pub const fn significand_bits(&self) -> u32 {
+        self.significand_bits
+    }
+
+    /// The number of exponent bits.
+    #[must_use]
+    pub const fn exponent_bits(&self) -> u32 {
+        self.exponent_bits
+    }
+
+    /// The exponent bias: the value subtracted from the stored (unsigned) exponent to obtain the
+    /// true, signed exponent.
+    #[must_use]
+    pub const fn exponent_bias(&self) -> i32 {
+        self.exponent_bias
+    }
+
+    /// Returns `true` for `bfloat`, LLVM's "brain floating-point" format.
+    #[must_use]
+    pub const fn is_brain_float(&self) -> bool {
+        self.is_brain_float
+    }
+
+    /// Returns `true` if this format is a true IEEE-754 binary format.
+    ///
+    /// `ppc_fp128` is the only format this crate models that is not: it is a pair of `double`s
+    /// (the "double-double" trick) rather than a single IEEE-754-conformant encoding.
+    #[must_use]
+    pub const fn is_ieee(&self) -> bool {
+        !matches!(self.kind, TypeKind::PPC_FP128TypeKind)
+    }
+
+    /// The largest finite value representable in this format, as an `f64` approximation.
+    ///
+    /// For `x86_fp80`/`fp128`/`ppc_fp128`, whose range exceeds `f64`'s, this saturates to `f64`'s
+    /// own maximum finite value rather than overflowing to infinity.
+    #[must_use]
+    pub fn max_finite(&self) -> f64 {
+        let max_mantissa = 2.0 - 2.0_f64.powi(-(self.mantissa_fraction_bits() as i32));
+        let value = max_mantissa * 2.0_f64.powi(self.exponent_bias);
+        if value.is_finite() {
+            value
+        } else {
+            f64::MAX
+        }
+    }
+
+    /// The smallest positive normal value representable in this format, as an `f64`
+    /// approximation.
+    ///
+    /// For `x86_fp80`/`fp128`/`ppc_fp128`, whose smallest normal value underflows `f64`, this
+    /// saturates to `f64`'s own smallest positive normal value rather than flushing to zero.
+    #[must_use]
+    pub fn min_positive(&self) -> f64 {
+        let value = 2.0_f64.powi(1 - self.exponent_bias);
+        if value > 0.0 {
+            value
+        } else {
+            f64::MIN_POSITIVE
+        }
+    }
+
+    /// The number of fractional significand bits assuming an implicit leading one, used by
+    /// [`Self::max_finite`]'s `(2 - 2^-bits) * 2^emax` formula. `x86_fp80` stores its integer bit
+    /// explicitly, so one is subtracted back off to get the equivalent implicit-leading-one
+    /// fraction width.
+    const fn mantissa_fraction_bits(&self) -> u32 {
+        match self.kind {
+            TypeKind::X86_FP80TypeKind => self.significand_bits - 1,
+            _ => self.significand_bits,
+        }
     }
 }
@@ -1,34 +1,55 @@
 //! Functions in this section operate on function types.
 
-use crate::core::types::TypeRef;
-use crate::{CInt, CUint, GetRef};
+use crate::core::context::ContextRef;
+use crate::core::types::{TypeKind, TypeRef};
+use crate::{CInt, CStr, CUint, GetRef};
 use llvm_sys::core;
 use llvm_sys::prelude::LLVMTypeRef;
+use std::marker::PhantomData;
 
 /// These functions relate to `TypeRef` of `LLVMTypeRef` instances.
-#[derive(Debug)]
-pub struct FunctionTypeRef(LLVMTypeRef);
+///
+/// Carries the same `'ctx` lifetime as [`TypeRef`], tying a `FunctionTypeRef` to the context its
+/// return type and parameter types were created from.
+pub struct FunctionTypeRef<'ctx>(LLVMTypeRef, PhantomData<&'ctx ContextRef>);
 
-impl From<LLVMTypeRef> for FunctionTypeRef {
+impl<'ctx> From<LLVMTypeRef> for FunctionTypeRef<'ctx> {
     fn from(value: LLVMTypeRef) -> Self {
-        Self(value)
+        Self(value, PhantomData)
     }
 }
 
-impl From<FunctionTypeRef> for TypeRef {
-    fn from(value: FunctionTypeRef) -> Self {
-        Self(value.0)
+impl<'ctx> From<FunctionTypeRef<'ctx>> for TypeRef<'ctx> {
+    fn from(value: FunctionTypeRef<'ctx>) -> Self {
+        Self::from(value.0)
     }
 }
 
-impl GetRef for FunctionTypeRef {
+impl<'ctx> GetRef for FunctionTypeRef<'ctx> {
     type RawRef = LLVMTypeRef;
     fn get_ref(&self) -> Self::RawRef {
         self.0
     }
 }
 
-impl FunctionTypeRef {
+/// Fallibly downcasts a `TypeRef` into a `FunctionTypeRef`, checked via `LLVMGetTypeKind`.
+///
+/// The infallible `From<LLVMTypeRef>` above blindly wraps any type handle and remains available
+/// for internal use where the kind is already known; this conversion is the safe entry point
+/// for callers holding an erased `TypeRef`.
+impl<'ctx> TryFrom<TypeRef<'ctx>> for FunctionTypeRef<'ctx> {
+    type Error = TypeRef<'ctx>;
+
+    fn try_from(value: TypeRef<'ctx>) -> Result<Self, Self::Error> {
+        if value.get_type_kind() == TypeKind::FunctionTypeKind {
+            Ok(Self(value.get_ref(), PhantomData))
+        } else {
+            Err(value)
+        }
+    }
+}
+
+impl<'ctx> FunctionTypeRef<'ctx> {
     /// Obtain a function type consisting of a specified signature.
     ///
     /// The function is defined as a tuple of a return Type, a list of
@@ -53,15 +74,22 @@ impl FunctionTypeRef {
     ///
     /// Returns an instance of `Self` representing the function type with the specified return type, parameters, and varargs setting.
     #[must_use]
-    pub fn function_type(return_type: &TypeRef, param_types: &[TypeRef], is_var_arg: bool) -> Self {
+    pub fn function_type(
+        return_type: &TypeRef<'ctx>,
+        param_types: &[TypeRef<'ctx>],
+        is_var_arg: bool,
+    ) -> Self {
         let parameters = crate::to_mut_ptr!(param_types);
         unsafe {
-            Self(core::LLVMFunctionType(
-                return_type.0,
-                parameters,
-                *CUint::from(param_types.len()),
-                *CInt::from(is_var_arg),
-            ))
+            Self(
+                core::LLVMFunctionType(
+                    return_type.0,
+                    parameters,
+                    *CUint::try_from(param_types.len()).expect("value does not fit in c_uint"),
+                    *CInt::from(is_var_arg),
+                ),
+                PhantomData,
+            )
         }
     }
 
@@ -97,8 +125,8 @@ impl FunctionTypeRef {
     ///
     /// Returns a `TypeRef` representing the return type of the function type.
     #[must_use]
-    pub fn get_return_type(&self) -> TypeRef {
-        unsafe { TypeRef(core::LLVMGetReturnType(self.0)) }
+    pub fn get_return_type(&self) -> TypeRef<'ctx> {
+        unsafe { TypeRef::from(core::LLVMGetReturnType(self.0)) }
     }
 
     /// Obtain the number of parameters this function accepts.
@@ -138,13 +166,138 @@ impl FunctionTypeRef {
     ///
     /// Returns a `Vec<TypeRef>` representing the types of the parameters in the function type.
     #[must_use]
-    pub fn get_param_types(&self) -> Vec<TypeRef> {
+    pub fn get_param_types(&self) -> Vec<TypeRef<'ctx>> {
         let count = self.count_param_types() as usize;
         let mut param_types: Vec<LLVMTypeRef> = Vec::with_capacity(count);
         unsafe {
             core::LLVMGetParamTypes(self.0, param_types.as_mut_ptr());
             param_types.set_len(count);
         }
-        param_types.into_iter().map(TypeRef).collect()
+        param_types.into_iter().map(TypeRef::from).collect()
+    }
+
+    /// Returns a string representation of the type. Use
+    /// `LLVMDisposeMessage` to free the string.
+    ///
+    /// # Details
+    ///
+    /// Converts the LLVM function type to a human-readable string representation.
+    ///
+    /// This function wraps the `LLVMPrintTypeToString` function from the LLVM core library. It returns a `String`
+    /// containing a human-readable representation of the type represented by `self`. This is useful for debugging
+    /// or logging the type information in a readable format.
+    ///
+    /// If the conversion fails, the function returns an empty string.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `String` containing the string representation of the type.
+    #[must_use]
+    pub fn print_type_to_string(&self) -> String {
+        unsafe {
+            let c_str = core::LLVMPrintTypeToString(self.0);
+            if c_str.is_null() {
+                return String::new();
+            }
+            let rust_string = CStr::new(c_str).to_string();
+            core::LLVMDisposeMessage(c_str);
+            rust_string
+        }
+    }
+}
+
+/// An owned, round-trippable view of a function type's signature.
+///
+/// Bundles the pieces callers otherwise have to stitch together from `get_return_type`,
+/// `count_param_types`/`get_param_types` and `is_function_var_arg`. A `FunctionSignature` can be
+/// cloned, matched on and compared like any other value, and rebuilt into a `FunctionTypeRef` via
+/// [`FunctionTypeRef::from_signature`].
+#[derive(Debug, PartialEq, Eq)]
+pub struct FunctionSignature<'ctx> {
+    /// The type returned by the function.
+    pub return_type: TypeRef<'ctx>,
+    /// The types of the function's fixed parameters, in order.
+    pub param_types: Vec<TypeRef<'ctx>>,
+    /// Whether the function accepts a variable number of arguments.
+    pub is_var_arg: bool,
+}
+
+/// Clones by re-wrapping the underlying `LLVMTypeRef` pointers, consistent with LLVM's type
+/// uniquing: a cloned `TypeRef` still points at the same, single type instance.
+impl<'ctx> Clone for FunctionSignature<'ctx> {
+    fn clone(&self) -> Self {
+        Self {
+            return_type: TypeRef::from(self.return_type.get_ref()),
+            param_types: self
+                .param_types
+                .iter()
+                .map(|param_type| TypeRef::from(param_type.get_ref()))
+                .collect(),
+            is_var_arg: self.is_var_arg,
+        }
+    }
+}
+
+impl<'ctx> FunctionTypeRef<'ctx> {
+    /// Decomposes this function type into an owned, round-trippable `FunctionSignature`.
+    ///
+    /// # Details
+    ///
+    /// Gathers the return type, parameter types and varargs flag of the function type represented
+    /// by `self` into a single value, mirroring the `(arg_types, result_type, varargs)` tuple this
+    /// type was constructed from.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `FunctionSignature` describing the function type.
+    #[must_use]
+    pub fn signature(&self) -> FunctionSignature<'ctx> {
+        FunctionSignature {
+            return_type: self.get_return_type(),
+            param_types: self.get_param_types(),
+            is_var_arg: self.is_function_var_arg(),
+        }
+    }
+
+    /// Rebuilds a `FunctionTypeRef` from a previously decomposed `FunctionSignature`.
+    ///
+    /// # Details
+    ///
+    /// Wraps `LLVMFunctionType` via [`FunctionTypeRef::function_type`], reconstructing a function
+    /// type from its return type, parameter types and varargs flag. This is the reciprocal of
+    /// [`FunctionTypeRef::signature`], useful when generating trampolines or wrappers that need to
+    /// reconstruct a modified signature.
+    ///
+    /// # Parameters
+    ///
+    /// - `signature`: The `FunctionSignature` to rebuild a function type from.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `FunctionTypeRef` representing the function type described by `signature`.
+    #[must_use]
+    pub fn from_signature(signature: &FunctionSignature<'ctx>) -> Self {
+        Self::function_type(
+            &signature.return_type,
+            &signature.param_types,
+            signature.is_var_arg,
+        )
+    }
+}
+
+/// Displays the type using its LLVM textual form (e.g. `i32 (i8*, ...)`), as produced by
+/// `LLVMPrintTypeToString`.
+impl<'ctx> std::fmt::Display for FunctionTypeRef<'ctx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.print_type_to_string())
+    }
+}
+
+/// Debug representation showing the LLVM textual form of the type instead of the opaque pointer.
+impl<'ctx> std::fmt::Debug for FunctionTypeRef<'ctx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("FunctionTypeRef")
+            .field(&self.print_type_to_string())
+            .finish()
     }
 }
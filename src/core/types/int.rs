@@ -1,28 +1,32 @@
 //! Functions in this section operate on integer types.
 
 use crate::core::context::ContextRef;
-use crate::core::types::TypeRef;
-use crate::GetRef;
+use crate::core::types::{TypeKind, TypeRef};
+use crate::{CStr, GetRef};
 use llvm_sys::core;
 use llvm_sys::prelude::LLVMTypeRef;
+use std::marker::PhantomData;
 
 /// Wrapper `LLVMTypeRef` for integer types.
-#[derive(Debug)]
-pub struct IntTypeRef(LLVMTypeRef);
+///
+/// Carries the same `'ctx` lifetime as [`TypeRef`], tying an `IntTypeRef` to the context it was
+/// created from (or to `'static` for types obtained from the global context).
+#[derive(Clone)]
+pub struct IntTypeRef<'ctx>(LLVMTypeRef, PhantomData<&'ctx ContextRef>);
 
-impl From<LLVMTypeRef> for IntTypeRef {
+impl<'ctx> From<LLVMTypeRef> for IntTypeRef<'ctx> {
     fn from(value: LLVMTypeRef) -> Self {
-        Self(value)
+        Self(value, PhantomData)
     }
 }
 
-impl From<IntTypeRef> for TypeRef {
-    fn from(value: IntTypeRef) -> Self {
-        Self(value.0)
+impl<'ctx> From<IntTypeRef<'ctx>> for TypeRef<'ctx> {
+    fn from(value: IntTypeRef<'ctx>) -> Self {
+        Self::from(value.0)
     }
 }
 
-impl GetRef for IntTypeRef {
+impl<'ctx> GetRef for IntTypeRef<'ctx> {
     type RawRef = LLVMTypeRef;
     fn get_ref(&self) -> Self::RawRef {
         self.0
@@ -30,7 +34,7 @@ impl GetRef for IntTypeRef {
 }
 
 /// Obtain an integer type from a context with specified bit width.
-impl IntTypeRef {
+impl<'ctx> IntTypeRef<'ctx> {
     /// Creates a 1-bit integer (`i1`) type in the specified LLVM context.
     ///
     /// This function wraps the `LLVMInt1TypeInContext` function from the LLVM core library. It creates and returns
@@ -45,8 +49,8 @@ impl IntTypeRef {
     ///
     /// Returns an instance of `Self` representing the `i1` type in the specified context.
     #[must_use]
-    pub fn int1_type_in_context(context: &ContextRef) -> Self {
-        unsafe { Self(core::LLVMInt1TypeInContext(context.get_ref())) }
+    pub fn int1_type_in_context(context: &'ctx ContextRef) -> Self {
+        unsafe { Self(core::LLVMInt1TypeInContext(context.get_ref()), PhantomData) }
     }
 
     /// Creates an 8-bit integer (`i8`) type in the specified LLVM context.
@@ -63,8 +67,8 @@ impl IntTypeRef {
     ///
     /// Returns an instance of `Self` representing the `i8` type in the specified context.
     #[must_use]
-    pub fn int8_type_in_context(context: &ContextRef) -> Self {
-        unsafe { Self(core::LLVMInt8TypeInContext(context.get_ref())) }
+    pub fn int8_type_in_context(context: &'ctx ContextRef) -> Self {
+        unsafe { Self(core::LLVMInt8TypeInContext(context.get_ref()), PhantomData) }
     }
 
     /// Creates a 16-bit integer (`i16`) type in the specified LLVM context.
@@ -81,8 +85,13 @@ impl IntTypeRef {
     ///
     /// Returns an instance of `Self` representing the `i16` type in the specified context.
     #[must_use]
-    pub fn int16_type_in_context(context: &ContextRef) -> Self {
-        unsafe { Self(core::LLVMInt16TypeInContext(context.get_ref())) }
+    pub fn int16_type_in_context(context: &'ctx ContextRef) -> Self {
+        unsafe {
+            Self(
+                core::LLVMInt16TypeInContext(context.get_ref()),
+                PhantomData,
+            )
+        }
     }
 
     /// Creates a 32-bit integer (`i32`) type in the specified LLVM context.
@@ -99,8 +108,13 @@ impl IntTypeRef {
     ///
     /// Returns an instance of `Self` representing the `i32` type in the specified context.
     #[must_use]
-    pub fn int32_type_in_context(context: &ContextRef) -> Self {
-        unsafe { Self(core::LLVMInt32TypeInContext(context.get_ref())) }
+    pub fn int32_type_in_context(context: &'ctx ContextRef) -> Self {
+        unsafe {
+            Self(
+                core::LLVMInt32TypeInContext(context.get_ref()),
+                PhantomData,
+            )
+        }
     }
 
     /// Creates a 64-bit integer (`i64`) type in the specified LLVM context.
@@ -117,8 +131,13 @@ impl IntTypeRef {
     ///
     /// Returns an instance of `Self` representing the `i64` type in the specified context.
     #[must_use]
-    pub fn int64_type_in_context(context: &ContextRef) -> Self {
-        unsafe { Self(core::LLVMInt64TypeInContext(context.get_ref())) }
+    pub fn int64_type_in_context(context: &'ctx ContextRef) -> Self {
+        unsafe {
+            Self(
+                core::LLVMInt64TypeInContext(context.get_ref()),
+                PhantomData,
+            )
+        }
     }
 
     /// Creates a 128-bit integer (`i128`) type in the specified LLVM context.
@@ -135,8 +154,13 @@ impl IntTypeRef {
     ///
     /// Returns an instance of `Self` representing the `i128` type in the specified context.
     #[must_use]
-    pub fn int128_type_in_context(context: &ContextRef) -> Self {
-        unsafe { Self(core::LLVMInt128TypeInContext(context.get_ref())) }
+    pub fn int128_type_in_context(context: &'ctx ContextRef) -> Self {
+        unsafe {
+            Self(
+                core::LLVMInt128TypeInContext(context.get_ref()),
+                PhantomData,
+            )
+        }
     }
 
     /// Creates an integer type with a specified bit width in the given LLVM context.
@@ -154,13 +178,93 @@ impl IntTypeRef {
     ///
     /// Returns an instance of `Self` representing the integer type with the specified bit width in the given context.
     #[must_use]
-    pub fn int_type_in_context(context: &ContextRef, num_bits: u32) -> Self {
-        unsafe { Self(core::LLVMIntTypeInContext(context.get_ref(), num_bits)) }
+    pub fn int_type_in_context(context: &'ctx ContextRef, num_bits: u32) -> Self {
+        unsafe {
+            Self(
+                core::LLVMIntTypeInContext(context.get_ref(), num_bits),
+                PhantomData,
+            )
+        }
+    }
+
+    /// Retrieves the bit width of an integer type.
+    ///
+    /// This function wraps the `LLVMGetIntTypeWidth` function from the LLVM core library. It returns the bit width
+    /// of the integer type represented by `self`. This is useful for determining the size of an integer type in bits,
+    /// such as whether it is an 8-bit, 32-bit, 64-bit, or other integer type.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `u32` representing the bit width of the integer type.
+    #[must_use]
+    pub fn get_int_type_width(&self) -> u32 {
+        unsafe { core::LLVMGetIntTypeWidth(self.0) }
+    }
+
+    /// Obtain the enumerated type of this integer type instance.
+    ///
+    /// # Details
+    ///
+    /// This function wraps the `LLVMGetTypeKind` function from the LLVM core library. It returns the `TypeKind`
+    /// representing the kind of this type, which is always `TypeKind::IntegerTypeKind` for an `IntTypeRef`. It is
+    /// provided so callers can branch on type categories without first converting to `TypeRef`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `TypeKind` representing the kind of the type.
+    #[must_use]
+    pub fn get_type_kind(&self) -> TypeKind {
+        unsafe { TypeKind::from(core::LLVMGetTypeKind(self.0)) }
+    }
+
+    /// Obtain the context to which this integer type instance is associated.
+    ///
+    /// # Details
+    ///
+    /// This function wraps the `LLVMGetTypeContext` function from the LLVM core library. It returns the
+    /// `ContextRef` in which this type was created, so callers can create further types in the same context
+    /// without having to thread the original `ContextRef` through.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `ContextRef` representing the context that owns this type.
+    #[must_use]
+    pub fn get_type_context(&self) -> ContextRef {
+        unsafe { ContextRef::from(core::LLVMGetTypeContext(self.0)) }
+    }
+
+    /// Returns a string representation of the type. Use
+    /// `LLVMDisposeMessage` to free the string.
+    ///
+    /// # Details
+    ///
+    /// Converts the LLVM integer type to a human-readable string representation.
+    ///
+    /// This function wraps the `LLVMPrintTypeToString` function from the LLVM core library. It returns a `String`
+    /// containing a human-readable representation of the type represented by `self`. This is useful for debugging
+    /// or logging the type information in a readable format.
+    ///
+    /// If the conversion fails, the function returns an empty string.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `String` containing the string representation of the type.
+    #[must_use]
+    pub fn print_type_to_string(&self) -> String {
+        unsafe {
+            let c_str = core::LLVMPrintTypeToString(self.0);
+            if c_str.is_null() {
+                return String::new();
+            }
+            let rust_string = CStr::new(c_str).to_string();
+            core::LLVMDisposeMessage(c_str);
+            rust_string
+        }
     }
 }
 
 /// Obtain an integer type from the global context with a specified bit width.
-impl IntTypeRef {
+impl IntTypeRef<'static> {
     /// Creates a 1-bit integer (`i1`) type in the global LLVM context.
     ///
     /// This function wraps the `LLVMInt1Type` function from the LLVM core library. It creates and returns
@@ -172,7 +276,7 @@ impl IntTypeRef {
     /// Returns an instance of `Self` representing the `i1` type in the global context.
     #[must_use]
     pub fn int1_type() -> Self {
-        unsafe { Self(core::LLVMInt1Type()) }
+        unsafe { Self(core::LLVMInt1Type(), PhantomData) }
     }
 
     /// Creates an 8-bit integer (`i8`) type in the global LLVM context.
@@ -186,7 +290,7 @@ impl IntTypeRef {
     /// Returns an instance of `Self` representing the `i8` type in the global context.
     #[must_use]
     pub fn int8_type() -> Self {
-        unsafe { Self(core::LLVMInt8Type()) }
+        unsafe { Self(core::LLVMInt8Type(), PhantomData) }
     }
 
     /// Creates a 16-bit integer (`i16`) type in the global LLVM context.
@@ -200,7 +304,7 @@ impl IntTypeRef {
     /// Returns an instance of `Self` representing the `i16` type in the global context.
     #[must_use]
     pub fn int16_type() -> Self {
-        unsafe { Self(core::LLVMInt16Type()) }
+        unsafe { Self(core::LLVMInt16Type(), PhantomData) }
     }
 
     /// Creates a 32-bit integer (`i32`) type in the global LLVM context.
@@ -214,7 +318,7 @@ impl IntTypeRef {
     /// Returns an instance of `Self` representing the `i32` type in the global context.
     #[must_use]
     pub fn int32_type() -> Self {
-        unsafe { Self(core::LLVMInt32Type()) }
+        unsafe { Self(core::LLVMInt32Type(), PhantomData) }
     }
 
     /// Creates a 64-bit integer (`i64`) type in the global LLVM context.
@@ -228,7 +332,7 @@ impl IntTypeRef {
     /// Returns an instance of `Self` representing the `i64` type in the global context.
     #[must_use]
     pub fn int64_type() -> Self {
-        unsafe { Self(core::LLVMInt64Type()) }
+        unsafe { Self(core::LLVMInt64Type(), PhantomData) }
     }
 
     /// Creates a 128-bit integer (`i128`) type in the global LLVM context.
@@ -242,7 +346,7 @@ impl IntTypeRef {
     /// Returns an instance of `Self` representing the `i128` type in the global context.
     #[must_use]
     pub fn int128_type() -> Self {
-        unsafe { Self(core::LLVMInt128Type()) }
+        unsafe { Self(core::LLVMInt128Type(), PhantomData) }
     }
 
     /// Creates an integer type with a specified bit width in the global LLVM context.
@@ -260,20 +364,40 @@ impl IntTypeRef {
     /// Returns an instance of `Self` representing the integer type with the specified bit width in the global context.
     #[must_use]
     pub fn int_type(num_bits: u32) -> Self {
-        unsafe { Self(core::LLVMIntType(num_bits)) }
+        unsafe { Self(core::LLVMIntType(num_bits), PhantomData) }
     }
+}
 
-    /// Retrieves the bit width of an integer type.
-    ///
-    /// This function wraps the `LLVMGetIntTypeWidth` function from the LLVM core library. It returns the bit width
-    /// of the integer type represented by `self`. This is useful for determining the size of an integer type in bits,
-    /// such as whether it is an 8-bit, 32-bit, 64-bit, or other integer type.
-    ///
-    /// # Returns
-    ///
-    /// Returns a `u32` representing the bit width of the integer type.
-    #[must_use]
-    pub fn get_int_type_width(&self) -> u32 {
-        unsafe { core::LLVMGetIntTypeWidth(self.0) }
+/// Displays the type using its LLVM textual form (e.g. `i32`, `i128`), as produced by
+/// `LLVMPrintTypeToString`.
+impl<'ctx> std::fmt::Display for IntTypeRef<'ctx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.print_type_to_string())
+    }
+}
+
+/// Debug representation showing the LLVM textual form of the type instead of the opaque pointer.
+impl<'ctx> std::fmt::Debug for IntTypeRef<'ctx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("IntTypeRef")
+            .field(&self.print_type_to_string())
+            .finish()
+    }
+}
+
+/// Types are compared by pointer identity: LLVM uniques types within a context, so two
+/// `IntTypeRef` handles to the same type always share one pointer.
+impl<'ctx> PartialEq for IntTypeRef<'ctx> {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.0, other.0)
+    }
+}
+
+impl<'ctx> Eq for IntTypeRef<'ctx> {}
+
+/// Hashes the underlying pointer address, consistent with the pointer-identity `PartialEq` impl.
+impl<'ctx> std::hash::Hash for IntTypeRef<'ctx> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
     }
 }
@@ -1,3 +1,4 @@
+pub mod compile;
 pub mod float;
 pub mod function;
 pub mod int;
@@ -5,12 +6,56 @@ pub mod other;
 pub mod sequential;
 pub mod structs;
 
+use std::collections::HashSet;
+use std::marker::PhantomData;
 use std::ops::Deref;
 
 use crate::core::context::ContextRef;
+use crate::core::types::function::FunctionTypeRef;
+use crate::core::types::int::IntTypeRef;
+use crate::core::types::sequential::SequentialTypeRef;
+use crate::core::types::structs::StructTypeRef;
 use crate::{CStr, GetRef};
 use llvm_sys::prelude::LLVMTypeRef;
-use llvm_sys::{core, LLVMTypeKind};
+use llvm_sys::{core, LLVMDiagnosticSeverity, LLVMTypeKind};
+
+/// Represents the severity of an LLVM diagnostic.
+///
+/// Diagnostics (reported e.g. through a context's diagnostic handler) carry a severity level
+/// indicating how serious the reported condition is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiagnosticSeverity {
+    /// A fatal error that prevents further processing.
+    Error,
+    /// A non-fatal warning.
+    Warning,
+    /// A remark, typically produced by optimization diagnostics.
+    Remark,
+    /// An informational note.
+    Note,
+}
+
+impl From<LLVMDiagnosticSeverity> for DiagnosticSeverity {
+    fn from(severity: LLVMDiagnosticSeverity) -> Self {
+        match severity {
+            LLVMDiagnosticSeverity::LLVMDSError => Self::Error,
+            LLVMDiagnosticSeverity::LLVMDSWarning => Self::Warning,
+            LLVMDiagnosticSeverity::LLVMDSRemark => Self::Remark,
+            LLVMDiagnosticSeverity::LLVMDSNote => Self::Note,
+        }
+    }
+}
+
+impl From<DiagnosticSeverity> for LLVMDiagnosticSeverity {
+    fn from(severity: DiagnosticSeverity) -> Self {
+        match severity {
+            DiagnosticSeverity::Error => Self::LLVMDSError,
+            DiagnosticSeverity::Warning => Self::LLVMDSWarning,
+            DiagnosticSeverity::Remark => Self::LLVMDSRemark,
+            DiagnosticSeverity::Note => Self::LLVMDSNote,
+        }
+    }
+}
 
 /// Represents the different kinds of types in LLVM IR.
 ///
@@ -128,23 +173,28 @@ impl From<TypeKind> for LLVMTypeKind {
 /// deduplicates types so there is only 1 instance of a specific type
 /// alive at a time. In other words, a unique type is shared among all
 /// consumers within a context.
-#[derive(Debug)]
-pub struct TypeRef(LLVMTypeRef);
+///
+/// The `'ctx` lifetime ties a `TypeRef` to the `ContextRef` it was created from (or to `'static`
+/// for types obtained from the global context), so a type cannot outlive the context that owns
+/// it. Constructing a `TypeRef` directly from a raw `LLVMTypeRef` via `From` is unchecked with
+/// respect to this lifetime and remains available for internal use where the owning context is
+/// already known.
+pub struct TypeRef<'ctx>(LLVMTypeRef, PhantomData<&'ctx ContextRef>);
 
-impl From<LLVMTypeRef> for TypeRef {
+impl<'ctx> From<LLVMTypeRef> for TypeRef<'ctx> {
     fn from(value: LLVMTypeRef) -> Self {
-        Self(value)
+        Self(value, PhantomData)
     }
 }
 
-impl GetRef for TypeRef {
+impl<'ctx> GetRef for TypeRef<'ctx> {
     type RawRef = LLVMTypeRef;
     fn get_ref(&self) -> Self::RawRef {
         self.0
     }
 }
 
-impl TypeRef {
+impl<'ctx> TypeRef<'ctx> {
     /// Obtain the enumerated type of a Type instance.
     ///
     /// # Details
@@ -230,11 +280,724 @@ impl TypeRef {
             rust_string
         }
     }
+
+    /// Recursively visits this type and every nested subtype depth-first, calling `f` with each
+    /// visited type and its depth (this type is depth `0`).
+    ///
+    /// # Details
+    ///
+    /// Walks the type tree produced by `LLVMGetNumContainedTypes`/`LLVMGetSubtypes`, descending
+    /// into composite types such as structs, arrays, vectors and pointers. A set of already-visited
+    /// `LLVMTypeRef` pointers guards against infinite recursion on self-referential/recursive
+    /// struct types, at the cost of visiting a type already seen higher in the tree only once.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: A closure invoked for every visited type, receiving the type and its depth relative
+    ///   to `self`.
+    pub fn walk<F: FnMut(&Self, usize)>(&self, f: &mut F) {
+        let mut visited = HashSet::new();
+        Self::walk_inner(self.0, 0, f, &mut visited);
+    }
+
+    fn walk_inner<F: FnMut(&Self, usize)>(
+        type_ref: LLVMTypeRef,
+        depth: usize,
+        f: &mut F,
+        visited: &mut HashSet<LLVMTypeRef>,
+    ) {
+        if !visited.insert(type_ref) {
+            return;
+        }
+        f(&Self(type_ref, PhantomData), depth);
+        let count = unsafe { core::LLVMGetNumContainedTypes(type_ref) } as usize;
+        if count == 0 {
+            return;
+        }
+        let mut subtypes: Vec<LLVMTypeRef> = Vec::with_capacity(count);
+        unsafe {
+            core::LLVMGetSubtypes(type_ref, subtypes.as_mut_ptr());
+            subtypes.set_len(count);
+        }
+        for subtype in subtypes {
+            Self::walk_inner(subtype, depth + 1, f, visited);
+        }
+    }
+
+    /// Recursively compares this type with `other` by structure rather than by pointer identity.
+    ///
+    /// # Details
+    ///
+    /// Matches each pair of types by `TypeKind`, then by kind-specific properties (array length,
+    /// vector size, pointer address space) and finally by recursively comparing each pair of
+    /// subtypes. Opaque pointers (`LLVMPointerTypeIsOpaque`) are treated as equal regardless of
+    /// any stored pointee, since an opaque pointer carries no pointee type to compare. A set of
+    /// already-compared pointer pairs guards against infinite recursion on self-referential
+    /// struct types; a pair found in the set is assumed equal, since it is already being compared
+    /// higher up the call stack.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The type to compare `self` against.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the two types are structurally equal, otherwise returns `false`.
+    #[must_use]
+    pub fn structurally_equal(&self, other: &Self) -> bool {
+        let mut visited = HashSet::new();
+        Self::structurally_equal_inner(self.0, other.0, &mut visited)
+    }
+
+    fn structurally_equal_inner(
+        lhs: LLVMTypeRef,
+        rhs: LLVMTypeRef,
+        visited: &mut HashSet<(LLVMTypeRef, LLVMTypeRef)>,
+    ) -> bool {
+        if lhs == rhs {
+            return true;
+        }
+        if !visited.insert((lhs, rhs)) {
+            return true;
+        }
+
+        let lhs_kind = unsafe { TypeKind::from(core::LLVMGetTypeKind(lhs)) };
+        let rhs_kind = unsafe { TypeKind::from(core::LLVMGetTypeKind(rhs)) };
+        if lhs_kind != rhs_kind {
+            return false;
+        }
+
+        match lhs_kind {
+            TypeKind::PointerTypeKind => {
+                let lhs_address_space = unsafe { core::LLVMGetPointerAddressSpace(lhs) };
+                let rhs_address_space = unsafe { core::LLVMGetPointerAddressSpace(rhs) };
+                if lhs_address_space != rhs_address_space {
+                    return false;
+                }
+                let lhs_opaque = unsafe { core::LLVMPointerTypeIsOpaque(lhs) != 0 };
+                let rhs_opaque = unsafe { core::LLVMPointerTypeIsOpaque(rhs) != 0 };
+                if lhs_opaque || rhs_opaque {
+                    return true;
+                }
+            }
+            TypeKind::ArrayTypeKind => {
+                let lhs_len = unsafe { core::LLVMGetArrayLength2(lhs) };
+                let rhs_len = unsafe { core::LLVMGetArrayLength2(rhs) };
+                if lhs_len != rhs_len {
+                    return false;
+                }
+            }
+            TypeKind::VectorTypeKind | TypeKind::ScalableVectorTypeKind => {
+                let lhs_size = unsafe { core::LLVMGetVectorSize(lhs) };
+                let rhs_size = unsafe { core::LLVMGetVectorSize(rhs) };
+                if lhs_size != rhs_size {
+                    return false;
+                }
+            }
+            _ => {}
+        }
+
+        let lhs_count = unsafe { core::LLVMGetNumContainedTypes(lhs) };
+        let rhs_count = unsafe { core::LLVMGetNumContainedTypes(rhs) };
+        if lhs_count != rhs_count {
+            return false;
+        }
+        if lhs_count == 0 {
+            return true;
+        }
+
+        let count = lhs_count as usize;
+        let mut lhs_subtypes: Vec<LLVMTypeRef> = Vec::with_capacity(count);
+        let mut rhs_subtypes: Vec<LLVMTypeRef> = Vec::with_capacity(count);
+        unsafe {
+            core::LLVMGetSubtypes(lhs, lhs_subtypes.as_mut_ptr());
+            lhs_subtypes.set_len(count);
+            core::LLVMGetSubtypes(rhs, rhs_subtypes.as_mut_ptr());
+            rhs_subtypes.set_len(count);
+        }
+
+        lhs_subtypes
+            .into_iter()
+            .zip(rhs_subtypes)
+            .all(|(lhs_subtype, rhs_subtype)| {
+                Self::structurally_equal_inner(lhs_subtype, rhs_subtype, visited)
+            })
+    }
+
+    /// Computes the size of this type in bits, without reference to any target data layout.
+    ///
+    /// # Details
+    ///
+    /// Walks the type structurally, the way gallivm's `lp_sizeof_llvm_type` does: integer types
+    /// report their bit width, floating-point types their fixed IEEE/extended width, array and
+    /// (non-scalable) vector types their element count times their element's size, and struct
+    /// types the sum of their elements' sizes (i.e. as if packed, since LLVM's type system alone
+    /// has no notion of padding). This is a cheap, target-free complement to the
+    /// `TargetData`-based `abi_size_of_type`/`store_size_of_type` family, handy for quick
+    /// introspection and assertions.
+    ///
+    /// Returns `None` for kinds with no target-free size: `VoidTypeKind`, `LabelTypeKind`,
+    /// `FunctionTypeKind`, `MetadataTypeKind`, `TokenTypeKind`, pointer types, scalable vector
+    /// types, opaque structs, and any struct containing a member with no target-free size.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(u64)` with the size in bits, or `None` if no target-free answer exists.
+    #[must_use]
+    pub fn size_in_bits(&self) -> Option<u64> {
+        Self::size_in_bits_inner(self.0)
+    }
+
+    fn size_in_bits_inner(type_ref: LLVMTypeRef) -> Option<u64> {
+        match unsafe { TypeKind::from(core::LLVMGetTypeKind(type_ref)) } {
+            TypeKind::IntegerTypeKind => {
+                Some(u64::from(unsafe { core::LLVMGetIntTypeWidth(type_ref) }))
+            }
+            TypeKind::HalfTypeKind | TypeKind::BFloatTypeKind => Some(16),
+            TypeKind::FloatTypeKind => Some(32),
+            TypeKind::DoubleTypeKind => Some(64),
+            TypeKind::X86_FP80TypeKind => Some(80),
+            TypeKind::FP128TypeKind | TypeKind::PPC_FP128TypeKind => Some(128),
+            TypeKind::ArrayTypeKind => {
+                let element_count = unsafe { core::LLVMGetArrayLength2(type_ref) };
+                let element_type = unsafe { core::LLVMGetElementType(type_ref) };
+                Self::size_in_bits_inner(element_type)
+                    .map(|element_bits| element_count * element_bits)
+            }
+            TypeKind::VectorTypeKind => {
+                let element_count = u64::from(unsafe { core::LLVMGetVectorSize(type_ref) });
+                let element_type = unsafe { core::LLVMGetElementType(type_ref) };
+                Self::size_in_bits_inner(element_type)
+                    .map(|element_bits| element_count * element_bits)
+            }
+            TypeKind::StructTypeKind => {
+                if unsafe { core::LLVMIsOpaqueStruct(type_ref) != 0 } {
+                    return None;
+                }
+                let count = unsafe { core::LLVMCountStructElementTypes(type_ref) };
+                (0..count).try_fold(0_u64, |total, index| {
+                    let element_type = unsafe { core::LLVMStructGetTypeAtIndex(type_ref, index) };
+                    Self::size_in_bits_inner(element_type).map(|element_bits| total + element_bits)
+                })
+            }
+            TypeKind::VoidTypeKind
+            | TypeKind::LabelTypeKind
+            | TypeKind::FunctionTypeKind
+            | TypeKind::MetadataTypeKind
+            | TypeKind::TokenTypeKind
+            | TypeKind::PointerTypeKind
+            | TypeKind::ScalableVectorTypeKind
+            | TypeKind::X86_MMXTypeKind
+            | TypeKind::X86_AMXTypeKind
+            | TypeKind::TargetExtTypeKind => None,
+        }
+    }
+
+    /// Produces an indented, multi-line structural description of this type, recursing into
+    /// element and field types.
+    ///
+    /// # Details
+    ///
+    /// Unlike `print_type_to_string`, which defers entirely to LLVM's own (flat) printer, this
+    /// walks the type tree the way gallivm's `lp_dump_llvmtype` does, printing the `TypeKind` of
+    /// `self` plus kind-specific details (array/vector length, pointer address space, struct
+    /// packedness) and then recursing into each contained subtype with increasing indentation. A
+    /// set of already-visited `LLVMTypeRef` pointers guards against infinite recursion on
+    /// self-referential/recursive struct types, printing `<recursive>` for a type already seen
+    /// higher in the tree instead of descending into it again.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `String` containing the indented structural description of the type.
+    #[must_use]
+    pub fn pretty_print(&self) -> String {
+        let mut output = String::new();
+        let mut visited = HashSet::new();
+        Self::pretty_print_inner(self.0, 0, &mut output, &mut visited);
+        output
+    }
+
+    fn pretty_print_inner(
+        type_ref: LLVMTypeRef,
+        depth: usize,
+        output: &mut String,
+        visited: &mut HashSet<LLVMTypeRef>,
+    ) {
+        let indent = "  ".repeat(depth);
+        let kind = unsafe { TypeKind::from(core::LLVMGetTypeKind(type_ref)) };
+
+        if !visited.insert(type_ref) {
+            output.push_str(&format!("{indent}<recursive {kind:?}>\n"));
+            return;
+        }
+
+        let detail = match kind {
+            TypeKind::ArrayTypeKind => {
+                format!(" (length = {})", unsafe {
+                    core::LLVMGetArrayLength2(type_ref)
+                })
+            }
+            TypeKind::VectorTypeKind | TypeKind::ScalableVectorTypeKind => {
+                format!(" (size = {})", unsafe { core::LLVMGetVectorSize(type_ref) })
+            }
+            TypeKind::PointerTypeKind => {
+                format!(" (address space = {})", unsafe {
+                    core::LLVMGetPointerAddressSpace(type_ref)
+                })
+            }
+            TypeKind::IntegerTypeKind => {
+                format!(" (width = {})", unsafe {
+                    core::LLVMGetIntTypeWidth(type_ref)
+                })
+            }
+            TypeKind::StructTypeKind if unsafe { core::LLVMIsPackedStruct(type_ref) != 0 } => {
+                " (packed)".to_string()
+            }
+            _ => String::new(),
+        };
+        output.push_str(&format!("{indent}{kind:?}{detail}\n"));
+
+        let count = unsafe { core::LLVMGetNumContainedTypes(type_ref) } as usize;
+        if count == 0 {
+            return;
+        }
+        let mut subtypes: Vec<LLVMTypeRef> = Vec::with_capacity(count);
+        unsafe {
+            core::LLVMGetSubtypes(type_ref, subtypes.as_mut_ptr());
+            subtypes.set_len(count);
+        }
+        for subtype in subtypes {
+            Self::pretty_print_inner(subtype, depth + 1, output, visited);
+        }
+    }
 }
 
-impl Deref for TypeRef {
+impl<'ctx> TypeRef<'ctx> {
+    /// Downcasts to an `IntTypeRef` if this type is an integer type.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(IntTypeRef)` if `get_type_kind()` is `TypeKind::IntegerTypeKind`, otherwise
+    /// `None`.
+    #[must_use]
+    pub fn as_int(&self) -> Option<IntTypeRef<'ctx>> {
+        (self.get_type_kind() == TypeKind::IntegerTypeKind).then(|| IntTypeRef::from(self.0))
+    }
+
+    /// Downcasts to a `FunctionTypeRef` if this type is a function type.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(FunctionTypeRef)` if `get_type_kind()` is `TypeKind::FunctionTypeKind`,
+    /// otherwise `None`.
+    #[must_use]
+    pub fn as_function(&self) -> Option<FunctionTypeRef<'ctx>> {
+        (self.get_type_kind() == TypeKind::FunctionTypeKind).then(|| FunctionTypeRef::from(self.0))
+    }
+
+    /// Downcasts to a `StructTypeRef` if this type is a structure type.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(StructTypeRef)` if `get_type_kind()` is `TypeKind::StructTypeKind`, otherwise
+    /// `None`.
+    #[must_use]
+    pub fn as_struct(&self) -> Option<StructTypeRef<'ctx>> {
+        (self.get_type_kind() == TypeKind::StructTypeKind).then(|| StructTypeRef::from(self.0))
+    }
+
+    /// Downcasts to a `SequentialTypeRef` if this type is an array type.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(SequentialTypeRef)` if `get_type_kind()` is `TypeKind::ArrayTypeKind`,
+    /// otherwise `None`.
+    #[must_use]
+    pub fn as_array(&self) -> Option<SequentialTypeRef<'ctx>> {
+        (self.get_type_kind() == TypeKind::ArrayTypeKind)
+            .then(|| SequentialTypeRef::from(self.0))
+    }
+
+    /// Downcasts to a `SequentialTypeRef` if this type is a (fixed or scalable) vector type.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(SequentialTypeRef)` if `get_type_kind()` is `TypeKind::VectorTypeKind` or
+    /// `TypeKind::ScalableVectorTypeKind`, otherwise `None`.
+    #[must_use]
+    pub fn as_vector(&self) -> Option<SequentialTypeRef<'ctx>> {
+        matches!(
+            self.get_type_kind(),
+            TypeKind::VectorTypeKind | TypeKind::ScalableVectorTypeKind
+        )
+        .then(|| SequentialTypeRef::from(self.0))
+    }
+
+    /// Downcasts to a `SequentialTypeRef` if this type is a pointer type.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(SequentialTypeRef)` if `get_type_kind()` is `TypeKind::PointerTypeKind`,
+    /// otherwise `None`.
+    #[must_use]
+    pub fn as_pointer(&self) -> Option<SequentialTypeRef<'ctx>> {
+        (self.get_type_kind() == TypeKind::PointerTypeKind)
+            .then(|| SequentialTypeRef::from(self.0))
+    }
+
+    /// Unconditionally reinterprets this type as the kind-specific wrapper `T`, without checking
+    /// `get_type_kind()`.
+    ///
+    /// # Details
+    ///
+    /// Each kind-specific type wrapper (`IntTypeRef`, `FunctionTypeRef`, `StructTypeRef`,
+    /// `SequentialTypeRef`, ...) implements `From<LLVMTypeRef>` as a thin, unchecked wrap around
+    /// the raw handle. This method is a convenience for callers who already know the kind of
+    /// `self` (e.g. from prior `get_type_kind()` matching or external knowledge) and want to
+    /// avoid the `Option`-returning `as_*` checks. Prefer `as_int`/`as_function`/`as_struct`/
+    /// `as_array`/`as_vector`/`as_pointer` when the kind isn't already known to be correct.
+    ///
+    /// # Returns
+    ///
+    /// Returns `T` wrapping the same underlying `LLVMTypeRef` as `self`.
+    #[must_use]
+    pub fn cast_unchecked<T: From<LLVMTypeRef>>(&self) -> T {
+        T::from(self.0)
+    }
+
+    /// Returns `true` if this type is the `void` type.
+    #[must_use]
+    pub fn is_void(&self) -> bool {
+        self.get_type_kind() == TypeKind::VoidTypeKind
+    }
+
+    /// Returns `true` if this type is the `label` type.
+    #[must_use]
+    pub fn is_label(&self) -> bool {
+        self.get_type_kind() == TypeKind::LabelTypeKind
+    }
+
+    /// Returns `true` if this type is the `x86_mmx` type.
+    #[must_use]
+    pub fn is_x86_mmx(&self) -> bool {
+        self.get_type_kind() == TypeKind::X86_MMXTypeKind
+    }
+
+    /// Returns `true` if this type is the `token` type.
+    #[must_use]
+    pub fn is_token(&self) -> bool {
+        self.get_type_kind() == TypeKind::TokenTypeKind
+    }
+
+    /// Returns `true` if this type is the `metadata` type.
+    #[must_use]
+    pub fn is_metadata(&self) -> bool {
+        self.get_type_kind() == TypeKind::MetadataTypeKind
+    }
+
+    /// Returns `true` if this type is a target extension type.
+    #[must_use]
+    pub fn is_target_ext(&self) -> bool {
+        self.get_type_kind() == TypeKind::TargetExtTypeKind
+    }
+
+    /// Recursively classifies this type into an owned, pattern-matchable [`LlvmType`].
+    ///
+    /// # Details
+    ///
+    /// Reads [`Self::get_type_kind`] and, for composite kinds, recurses into the contained
+    /// element/field/parameter types (via `LLVMGetElementType`, `LLVMStructGetTypeAtIndex`,
+    /// `LLVMGetParamTypes`, ...), producing a fully owned value with no remaining ties to the
+    /// LLVM context. A set of already-visited `LLVMTypeRef` pointers guards against infinite
+    /// recursion on self-referential struct types, classifying a type already seen higher in the
+    /// tree as [`LlvmType::Other`]`(`[`TypeKind::StructTypeKind`]`)` instead of descending into it
+    /// again.
+    ///
+    /// Opaque pointers (`LLVMPointerTypeIsOpaque`) carry no pointee type to classify; since `void`
+    /// is never itself a valid pointee in LLVM IR, [`LlvmType::Void`] is used as an unambiguous
+    /// placeholder for "opaque, no pointee".
+    ///
+    /// # Returns
+    ///
+    /// Returns the [`LlvmType`] mirroring this type's structure.
+    #[must_use]
+    pub fn classify(&self) -> LlvmType {
+        let mut visited = HashSet::new();
+        Self::classify_inner(self.0, &mut visited)
+    }
+
+    fn classify_inner(type_ref: LLVMTypeRef, visited: &mut HashSet<LLVMTypeRef>) -> LlvmType {
+        let kind = unsafe { TypeKind::from(core::LLVMGetTypeKind(type_ref)) };
+        match kind {
+            TypeKind::IntegerTypeKind => {
+                LlvmType::Int(unsafe { core::LLVMGetIntTypeWidth(type_ref) })
+            }
+            TypeKind::HalfTypeKind => LlvmType::Half,
+            TypeKind::BFloatTypeKind => LlvmType::BFloat,
+            TypeKind::FloatTypeKind => LlvmType::Float,
+            TypeKind::DoubleTypeKind => LlvmType::Double,
+            TypeKind::X86_FP80TypeKind => LlvmType::X86Fp80,
+            TypeKind::FP128TypeKind => LlvmType::Fp128,
+            TypeKind::PPC_FP128TypeKind => LlvmType::PpcFp128,
+            TypeKind::LabelTypeKind => LlvmType::Label,
+            TypeKind::VoidTypeKind => LlvmType::Void,
+            TypeKind::MetadataTypeKind => LlvmType::Metadata,
+            TypeKind::PointerTypeKind => {
+                let address_space = unsafe { core::LLVMGetPointerAddressSpace(type_ref) };
+                let pointee = if unsafe { core::LLVMPointerTypeIsOpaque(type_ref) != 0 } {
+                    LlvmType::Void
+                } else {
+                    let element = unsafe { core::LLVMGetElementType(type_ref) };
+                    Self::classify_inner(element, visited)
+                };
+                LlvmType::Pointer(Box::new(pointee), address_space)
+            }
+            TypeKind::ArrayTypeKind => {
+                let length = unsafe { core::LLVMGetArrayLength2(type_ref) };
+                let element = unsafe { core::LLVMGetElementType(type_ref) };
+                LlvmType::Array(length, Box::new(Self::classify_inner(element, visited)))
+            }
+            TypeKind::VectorTypeKind => {
+                let length = u64::from(unsafe { core::LLVMGetVectorSize(type_ref) });
+                let element = unsafe { core::LLVMGetElementType(type_ref) };
+                LlvmType::Vector(length, Box::new(Self::classify_inner(element, visited)))
+            }
+            TypeKind::StructTypeKind => {
+                if !visited.insert(type_ref) {
+                    return LlvmType::Other(TypeKind::StructTypeKind);
+                }
+                if unsafe { core::LLVMIsOpaqueStruct(type_ref) != 0 } {
+                    return LlvmType::Struct(Vec::new(), false);
+                }
+                let packed = unsafe { core::LLVMIsPackedStruct(type_ref) != 0 };
+                let count = unsafe { core::LLVMCountStructElementTypes(type_ref) };
+                let fields = (0..count)
+                    .map(|index| {
+                        let field = unsafe { core::LLVMStructGetTypeAtIndex(type_ref, index) };
+                        Self::classify_inner(field, visited)
+                    })
+                    .collect();
+                LlvmType::Struct(fields, packed)
+            }
+            TypeKind::FunctionTypeKind => {
+                let ret = unsafe { core::LLVMGetReturnType(type_ref) };
+                let param_count = unsafe { core::LLVMCountParamTypes(type_ref) } as usize;
+                let mut params: Vec<LLVMTypeRef> = Vec::with_capacity(param_count);
+                unsafe {
+                    core::LLVMGetParamTypes(type_ref, params.as_mut_ptr());
+                    params.set_len(param_count);
+                }
+                LlvmType::Function {
+                    ret: Box::new(Self::classify_inner(ret, visited)),
+                    params: params
+                        .into_iter()
+                        .map(|param| Self::classify_inner(param, visited))
+                        .collect(),
+                    vararg: unsafe { core::LLVMIsFunctionVarArg(type_ref) != 0 },
+                }
+            }
+            other => LlvmType::Other(other),
+        }
+    }
+}
+
+/// An owned, recursively-structured view of an LLVM type, produced by [`TypeRef::classify`].
+///
+/// Ports the idea of a closed algebraic type (as in the Haskell LLVM backend's `LlvmType`) into
+/// this crate: rather than carrying an opaque `LLVMTypeRef` and repeatedly querying it through the
+/// C API, `LlvmType` is an inspectable, pattern-matchable Rust value with no remaining ties to the
+/// type's originating context.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LlvmType {
+    /// An arbitrary-width integer type (`iN`), carrying its bit width.
+    Int(u32),
+    /// The 16-bit `half` floating-point type.
+    Half,
+    /// The 16-bit `bfloat` floating-point type.
+    BFloat,
+    /// The 32-bit `float` floating-point type.
+    Float,
+    /// The 64-bit `double` floating-point type.
+    Double,
+    /// The 80-bit x86 extended-precision floating-point type (`x86_fp80`).
+    X86Fp80,
+    /// The 128-bit `fp128` floating-point type.
+    Fp128,
+    /// The 128-bit `PowerPC` floating-point type (`ppc_fp128`).
+    PpcFp128,
+    /// A pointer type, carrying its pointee type and address space.
+    Pointer(Box<LlvmType>, u32),
+    /// A fixed-length array type, carrying its element count and element type.
+    Array(u64, Box<LlvmType>),
+    /// A fixed-length vector type, carrying its element count and element type.
+    Vector(u64, Box<LlvmType>),
+    /// A structure type, carrying its field types and whether it is packed.
+    Struct(Vec<LlvmType>, bool),
+    /// A function type.
+    Function {
+        /// The function's return type.
+        ret: Box<LlvmType>,
+        /// The function's parameter types.
+        params: Vec<LlvmType>,
+        /// Whether the function accepts a variable number of arguments.
+        vararg: bool,
+    },
+    /// A basic block label type.
+    Label,
+    /// The `void` type.
+    Void,
+    /// The metadata type.
+    Metadata,
+    /// Any type kind without a dedicated variant (`x86_mmx`, `x86_amx`, `token`, scalable vector,
+    /// target extension types), preserved via its [`TypeKind`] so no information is lost.
+    Other(TypeKind),
+}
+
+/// Renders the canonical LLVM textual form of the type (e.g. `i32`, `[4 x float]`, `<8 x i16>`,
+/// `{i8, double}`), computed purely from this value rather than by calling back into LLVM.
+impl std::fmt::Display for LlvmType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Int(width) => write!(f, "i{width}"),
+            Self::Half => write!(f, "half"),
+            Self::BFloat => write!(f, "bfloat"),
+            Self::Float => write!(f, "float"),
+            Self::Double => write!(f, "double"),
+            Self::X86Fp80 => write!(f, "x86_fp80"),
+            Self::Fp128 => write!(f, "fp128"),
+            Self::PpcFp128 => write!(f, "ppc_fp128"),
+            Self::Pointer(_, address_space) if *address_space == 0 => write!(f, "ptr"),
+            Self::Pointer(_, address_space) => write!(f, "ptr addrspace({address_space})"),
+            Self::Array(length, element) => write!(f, "[{length} x {element}]"),
+            Self::Vector(length, element) => write!(f, "<{length} x {element}>"),
+            Self::Struct(fields, packed) => {
+                let body = fields
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                if *packed {
+                    write!(f, "<{{{body}}}>")
+                } else {
+                    write!(f, "{{{body}}}")
+                }
+            }
+            Self::Function {
+                ret,
+                params,
+                vararg,
+            } => {
+                let mut param_strings: Vec<String> =
+                    params.iter().map(ToString::to_string).collect();
+                if *vararg {
+                    param_strings.push("...".to_string());
+                }
+                write!(f, "{ret} ({})", param_strings.join(", "))
+            }
+            Self::Label => write!(f, "label"),
+            Self::Void => write!(f, "void"),
+            Self::Metadata => write!(f, "metadata"),
+            Self::Other(kind) => write!(f, "{kind:?}"),
+        }
+    }
+}
+
+/// Factory constructors for aggregate and function types, mirroring the `new_array`/`new_vector`/
+/// `new_pointer`/`new_function` builders from the llvm-alt wrapper. These forward straight to the
+/// kind-specific constructors in the `types` submodules and return their kind-specific wrappers,
+/// giving `TypeRef` a single, discoverable place to build IR types rather than only reflect on
+/// them.
+impl<'ctx> TypeRef<'ctx> {
+    /// Create a fixed size array type that refers to a specific element type.
+    ///
+    /// Wraps `SequentialTypeRef::array_type2` (`LLVMArrayType2`).
+    #[must_use]
+    pub fn array(element_type: &Self, element_count: u64) -> SequentialTypeRef<'ctx> {
+        SequentialTypeRef::array_type2(element_type, element_count)
+    }
+
+    /// Create a vector type that contains a defined element type and a fixed number of elements.
+    ///
+    /// Wraps `SequentialTypeRef::vector_type` (`LLVMVectorType`).
+    #[must_use]
+    pub fn vector(element_type: &Self, element_count: u32) -> SequentialTypeRef<'ctx> {
+        SequentialTypeRef::vector_type(element_type, element_count)
+    }
+
+    /// Create a vector type that contains a defined element type and a scalable number of
+    /// elements.
+    ///
+    /// Wraps `SequentialTypeRef::scalable_vector_type` (`LLVMScalableVectorType`).
+    #[must_use]
+    pub fn scalable_vector(element_type: &Self, element_count: u32) -> SequentialTypeRef<'ctx> {
+        SequentialTypeRef::scalable_vector_type(element_type, element_count)
+    }
+
+    /// Create a function type consisting of a specified return type, parameter types and
+    /// whether the function is variadic.
+    ///
+    /// Wraps `FunctionTypeRef::function_type` (`LLVMFunctionType`).
+    #[must_use]
+    pub fn function(
+        return_type: &Self,
+        param_types: &[Self],
+        is_var_arg: bool,
+    ) -> FunctionTypeRef<'ctx> {
+        FunctionTypeRef::function_type(return_type, param_types, is_var_arg)
+    }
+}
+
+impl<'a> TypeRef<'a> {
+    /// Create an opaque pointer type in a context's given address space.
+    ///
+    /// Unlike `array`/`vector`/`scalable_vector`/`function`, which share `self`'s element-derived
+    /// `'ctx`, this constructor has no element type to borrow a lifetime from, so it carries its
+    /// own lifetime tied directly to `context`.
+    ///
+    /// Wraps `SequentialTypeRef::opaque_pointer_type_in_context` (`LLVMPointerTypeInContext`).
+    #[must_use]
+    pub fn pointer_in(context: &'a ContextRef, address_space: u32) -> SequentialTypeRef<'a> {
+        SequentialTypeRef::opaque_pointer_type_in_context(context, address_space)
+    }
+}
+
+impl<'ctx> Deref for TypeRef<'ctx> {
     type Target = LLVMTypeRef;
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
+
+/// Displays the type using its LLVM textual form (e.g. `i32`, `double`), as produced by
+/// `LLVMPrintTypeToString`.
+impl<'ctx> std::fmt::Display for TypeRef<'ctx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.print_type_to_string())
+    }
+}
+
+/// Debug representation showing the LLVM textual form of the type instead of the opaque pointer.
+impl<'ctx> std::fmt::Debug for TypeRef<'ctx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("TypeRef")
+            .field(&self.print_type_to_string())
+            .finish()
+    }
+}
+
+/// Types are compared by pointer identity: LLVM uniques types within a context, so two `TypeRef`
+/// handles to the same type always share one pointer.
+impl<'ctx> PartialEq for TypeRef<'ctx> {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.0, other.0)
+    }
+}
+
+impl<'ctx> Eq for TypeRef<'ctx> {}
+
+/// Hashes the underlying pointer address, consistent with the pointer-identity `PartialEq` impl.
+impl<'ctx> std::hash::Hash for TypeRef<'ctx> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
@@ -10,35 +10,56 @@
 //! - `TargetExtTypeRef`
 
 use crate::core::context::ContextRef;
-use crate::core::types::TypeRef;
+use crate::core::types::{TypeKind, TypeRef};
+use crate::error::Error;
 use crate::{CString, CUint, GetRef};
 use llvm_sys::core;
 use llvm_sys::prelude::LLVMTypeRef;
+use std::marker::PhantomData;
 
 /// These functions relate to `VoidTypeRef` of `LLVMTypeRef` instances.
-#[derive(Debug)]
-pub struct VoidTypeRef(LLVMTypeRef);
+///
+/// Carries the same `'ctx` lifetime as [`TypeRef`], tying a `VoidTypeRef` to the context it was
+/// created from (or to `'static` for the type obtained from the global context).
+pub struct VoidTypeRef<'ctx>(LLVMTypeRef, PhantomData<&'ctx ContextRef>);
 
-impl From<LLVMTypeRef> for VoidTypeRef {
+impl<'ctx> From<LLVMTypeRef> for VoidTypeRef<'ctx> {
     fn from(value: LLVMTypeRef) -> Self {
-        Self(value)
+        Self(value, PhantomData)
     }
 }
 
-impl GetRef for VoidTypeRef {
+impl<'ctx> GetRef for VoidTypeRef<'ctx> {
     type RawRef = LLVMTypeRef;
     fn get_ref(&self) -> Self::RawRef {
         self.0
     }
 }
 
-impl From<VoidTypeRef> for TypeRef {
-    fn from(value: VoidTypeRef) -> Self {
-        Self(value.0)
+impl<'ctx> From<VoidTypeRef<'ctx>> for TypeRef<'ctx> {
+    fn from(value: VoidTypeRef<'ctx>) -> Self {
+        Self::from(value.0)
     }
 }
 
-impl VoidTypeRef {
+/// Fallibly downcasts a `TypeRef` into a `VoidTypeRef`, checked via `LLVMGetTypeKind`.
+///
+/// The infallible `From<LLVMTypeRef>` above blindly wraps any type handle and remains available
+/// for internal use where the kind is already known; this conversion is the safe entry point
+/// for callers holding an erased `TypeRef`.
+impl<'ctx> TryFrom<TypeRef<'ctx>> for VoidTypeRef<'ctx> {
+    type Error = TypeRef<'ctx>;
+
+    fn try_from(value: TypeRef<'ctx>) -> Result<Self, Self::Error> {
+        if value.get_type_kind() == TypeKind::VoidTypeKind {
+            Ok(Self(value.get_ref(), PhantomData))
+        } else {
+            Err(value)
+        }
+    }
+}
+
+impl<'ctx> VoidTypeRef<'ctx> {
     /// Create a void type in a context.
     ///
     /// # Details
@@ -57,10 +78,41 @@ impl VoidTypeRef {
     ///
     /// Returns an instance of `Self` representing the `void` type in the specified context.
     #[must_use]
-    pub fn void_type_in_context(context: &ContextRef) -> Self {
-        unsafe { Self(core::LLVMVoidTypeInContext(context.get_ref())) }
+    pub fn void_type_in_context(context: &'ctx ContextRef) -> Self {
+        unsafe { Self(core::LLVMVoidTypeInContext(context.get_ref()), PhantomData) }
+    }
+
+    /// Returns a string representation of the type. Use
+    /// `LLVMDisposeMessage` to free the string.
+    ///
+    /// # Details
+    ///
+    /// Converts the LLVM `void` type to a human-readable string representation.
+    ///
+    /// This function wraps the `LLVMPrintTypeToString` function from the LLVM core library. It returns a `String`
+    /// containing a human-readable representation of the type represented by `self`. This is useful for debugging
+    /// or logging the type information in a readable format.
+    ///
+    /// If the conversion fails, the function returns an empty string.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `String` containing the string representation of the type.
+    #[must_use]
+    pub fn print_type_to_string(&self) -> String {
+        unsafe {
+            let c_str = core::LLVMPrintTypeToString(self.0);
+            if c_str.is_null() {
+                return String::new();
+            }
+            let rust_string = crate::CStr::new(c_str).to_string();
+            core::LLVMDisposeMessage(c_str);
+            rust_string
+        }
     }
+}
 
+impl VoidTypeRef<'static> {
     /// Create a void type in a global context.
     ///
     /// # Details
@@ -76,34 +128,70 @@ impl VoidTypeRef {
     /// Returns an instance of `Self` representing the `void` type in the global context.
     #[must_use]
     pub fn void_type() -> Self {
-        unsafe { Self(core::LLVMVoidType()) }
+        unsafe { Self(core::LLVMVoidType(), PhantomData) }
+    }
+}
+
+/// Displays the type using its LLVM textual form (`void`), as produced by
+/// `LLVMPrintTypeToString`.
+impl<'ctx> std::fmt::Display for VoidTypeRef<'ctx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.print_type_to_string())
+    }
+}
+
+/// Debug representation showing the LLVM textual form of the type instead of the opaque pointer.
+impl<'ctx> std::fmt::Debug for VoidTypeRef<'ctx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("VoidTypeRef")
+            .field(&self.print_type_to_string())
+            .finish()
     }
 }
 
 /// These functions relate to `LabelTypeRef` of `LLVMTypeRef` instances.
-#[derive(Debug)]
-pub struct LabelTypeRef(LLVMTypeRef);
+///
+/// Carries the same `'ctx` lifetime as [`TypeRef`], tying a `LabelTypeRef` to the context it was
+/// created from (or to `'static` for the type obtained from the global context).
+pub struct LabelTypeRef<'ctx>(LLVMTypeRef, PhantomData<&'ctx ContextRef>);
 
-impl From<LLVMTypeRef> for LabelTypeRef {
+impl<'ctx> From<LLVMTypeRef> for LabelTypeRef<'ctx> {
     fn from(value: LLVMTypeRef) -> Self {
-        Self(value)
+        Self(value, PhantomData)
     }
 }
 
-impl GetRef for LabelTypeRef {
+impl<'ctx> GetRef for LabelTypeRef<'ctx> {
     type RawRef = LLVMTypeRef;
     fn get_ref(&self) -> Self::RawRef {
         self.0
     }
 }
 
-impl From<LabelTypeRef> for TypeRef {
-    fn from(value: LabelTypeRef) -> Self {
-        Self(value.0)
+impl<'ctx> From<LabelTypeRef<'ctx>> for TypeRef<'ctx> {
+    fn from(value: LabelTypeRef<'ctx>) -> Self {
+        Self::from(value.0)
+    }
+}
+
+/// Fallibly downcasts a `TypeRef` into a `LabelTypeRef`, checked via `LLVMGetTypeKind`.
+///
+/// The infallible `From<LLVMTypeRef>` above blindly wraps any type handle and remains available
+/// for internal use where the kind is already known; this conversion is the safe entry point
+/// for callers holding an erased `TypeRef`.
+impl<'ctx> TryFrom<TypeRef<'ctx>> for LabelTypeRef<'ctx> {
+    type Error = TypeRef<'ctx>;
+
+    fn try_from(value: TypeRef<'ctx>) -> Result<Self, Self::Error> {
+        if value.get_type_kind() == TypeKind::LabelTypeKind {
+            Ok(Self(value.get_ref(), PhantomData))
+        } else {
+            Err(value)
+        }
     }
 }
 
-impl LabelTypeRef {
+impl<'ctx> LabelTypeRef<'ctx> {
     /// Create a label type in a context.
     ///
     /// # details
@@ -122,10 +210,46 @@ impl LabelTypeRef {
     ///
     /// Returns an instance of `Self` representing the `label` type in the specified context.
     #[must_use]
-    pub fn label_type_in_context(context: &ContextRef) -> Self {
-        unsafe { Self(core::LLVMLabelTypeInContext(context.get_ref())) }
+    pub fn label_type_in_context(context: &'ctx ContextRef) -> Self {
+        unsafe {
+            Self(
+                core::LLVMLabelTypeInContext(context.get_ref()),
+                PhantomData,
+            )
+        }
     }
 
+    /// Returns a string representation of the type. Use
+    /// `LLVMDisposeMessage` to free the string.
+    ///
+    /// # Details
+    ///
+    /// Converts the LLVM `label` type to a human-readable string representation.
+    ///
+    /// This function wraps the `LLVMPrintTypeToString` function from the LLVM core library. It returns a `String`
+    /// containing a human-readable representation of the type represented by `self`. This is useful for debugging
+    /// or logging the type information in a readable format.
+    ///
+    /// If the conversion fails, the function returns an empty string.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `String` containing the string representation of the type.
+    #[must_use]
+    pub fn print_type_to_string(&self) -> String {
+        unsafe {
+            let c_str = core::LLVMPrintTypeToString(self.0);
+            if c_str.is_null() {
+                return String::new();
+            }
+            let rust_string = crate::CStr::new(c_str).to_string();
+            core::LLVMDisposeMessage(c_str);
+            rust_string
+        }
+    }
+}
+
+impl LabelTypeRef<'static> {
     /// Create a label type in a global context.
     ///
     /// # Details
@@ -140,35 +264,71 @@ impl LabelTypeRef {
     ///
     /// Returns an instance of `TypeRef` representing the `label` type in the global context.
     #[must_use]
-    pub fn label_type() -> TypeRef {
-        unsafe { TypeRef(core::LLVMLabelType()) }
+    pub fn label_type() -> TypeRef<'static> {
+        unsafe { TypeRef::from(core::LLVMLabelType()) }
+    }
+}
+
+/// Displays the type using its LLVM textual form (`label`), as produced by
+/// `LLVMPrintTypeToString`.
+impl<'ctx> std::fmt::Display for LabelTypeRef<'ctx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.print_type_to_string())
+    }
+}
+
+/// Debug representation showing the LLVM textual form of the type instead of the opaque pointer.
+impl<'ctx> std::fmt::Debug for LabelTypeRef<'ctx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("LabelTypeRef")
+            .field(&self.print_type_to_string())
+            .finish()
     }
 }
 
 /// These functions relate to `X86MMXTypeRef` of `X86MMXTypeRef` instances.
-#[derive(Debug)]
-pub struct X86MMXTypeRef(LLVMTypeRef);
+///
+/// Carries the same `'ctx` lifetime as [`TypeRef`], tying an `X86MMXTypeRef` to the context it was
+/// created from (or to `'static` for the type obtained from the global context).
+pub struct X86MMXTypeRef<'ctx>(LLVMTypeRef, PhantomData<&'ctx ContextRef>);
 
-impl From<LLVMTypeRef> for X86MMXTypeRef {
+impl<'ctx> From<LLVMTypeRef> for X86MMXTypeRef<'ctx> {
     fn from(value: LLVMTypeRef) -> Self {
-        Self(value)
+        Self(value, PhantomData)
     }
 }
 
-impl GetRef for X86MMXTypeRef {
+impl<'ctx> GetRef for X86MMXTypeRef<'ctx> {
     type RawRef = LLVMTypeRef;
     fn get_ref(&self) -> Self::RawRef {
         self.0
     }
 }
 
-impl From<X86MMXTypeRef> for TypeRef {
-    fn from(value: X86MMXTypeRef) -> Self {
-        Self(value.0)
+impl<'ctx> From<X86MMXTypeRef<'ctx>> for TypeRef<'ctx> {
+    fn from(value: X86MMXTypeRef<'ctx>) -> Self {
+        Self::from(value.0)
+    }
+}
+
+/// Fallibly downcasts a `TypeRef` into an `X86MMXTypeRef`, checked via `LLVMGetTypeKind`.
+///
+/// The infallible `From<LLVMTypeRef>` above blindly wraps any type handle and remains available
+/// for internal use where the kind is already known; this conversion is the safe entry point
+/// for callers holding an erased `TypeRef`.
+impl<'ctx> TryFrom<TypeRef<'ctx>> for X86MMXTypeRef<'ctx> {
+    type Error = TypeRef<'ctx>;
+
+    fn try_from(value: TypeRef<'ctx>) -> Result<Self, Self::Error> {
+        if value.get_type_kind() == TypeKind::X86_MMXTypeKind {
+            Ok(Self(value.get_ref(), PhantomData))
+        } else {
+            Err(value)
+        }
     }
 }
 
-impl X86MMXTypeRef {
+impl<'ctx> X86MMXTypeRef<'ctx> {
     /// Create an X86 MMX type in a context.
     ///
     /// # Details
@@ -187,10 +347,41 @@ impl X86MMXTypeRef {
     ///
     /// Returns an instance of `TypeRef` representing the `x86_mmx` type in the specified context.
     #[must_use]
-    pub fn x86_mmx_type_in_context(context: &ContextRef) -> TypeRef {
-        unsafe { TypeRef(core::LLVMX86MMXTypeInContext(context.get_ref())) }
+    pub fn x86_mmx_type_in_context(context: &'ctx ContextRef) -> TypeRef<'ctx> {
+        unsafe { TypeRef::from(core::LLVMX86MMXTypeInContext(context.get_ref())) }
+    }
+
+    /// Returns a string representation of the type. Use
+    /// `LLVMDisposeMessage` to free the string.
+    ///
+    /// # Details
+    ///
+    /// Converts the LLVM `x86_mmx` type to a human-readable string representation.
+    ///
+    /// This function wraps the `LLVMPrintTypeToString` function from the LLVM core library. It returns a `String`
+    /// containing a human-readable representation of the type represented by `self`. This is useful for debugging
+    /// or logging the type information in a readable format.
+    ///
+    /// If the conversion fails, the function returns an empty string.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `String` containing the string representation of the type.
+    #[must_use]
+    pub fn print_type_to_string(&self) -> String {
+        unsafe {
+            let c_str = core::LLVMPrintTypeToString(self.0);
+            if c_str.is_null() {
+                return String::new();
+            }
+            let rust_string = crate::CStr::new(c_str).to_string();
+            core::LLVMDisposeMessage(c_str);
+            rust_string
+        }
     }
+}
 
+impl X86MMXTypeRef<'static> {
     /// Create a X86 MMX type in a global context.
     ///
     /// # Details
@@ -205,35 +396,71 @@ impl X86MMXTypeRef {
     ///
     /// Returns an instance of `TypeRef` representing the `x86_mmx` type in the global context.
     #[must_use]
-    pub fn x86_mmx_type() -> TypeRef {
-        unsafe { TypeRef(core::LLVMX86MMXType()) }
+    pub fn x86_mmx_type() -> TypeRef<'static> {
+        unsafe { TypeRef::from(core::LLVMX86MMXType()) }
+    }
+}
+
+/// Displays the type using its LLVM textual form (`x86_mmx`), as produced by
+/// `LLVMPrintTypeToString`.
+impl<'ctx> std::fmt::Display for X86MMXTypeRef<'ctx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.print_type_to_string())
+    }
+}
+
+/// Debug representation showing the LLVM textual form of the type instead of the opaque pointer.
+impl<'ctx> std::fmt::Debug for X86MMXTypeRef<'ctx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("X86MMXTypeRef")
+            .field(&self.print_type_to_string())
+            .finish()
     }
 }
 
 /// These functions relate to `X86AMXTypeRef` of `X86MMXTypeRef` instances.
-#[derive(Debug)]
-pub struct X86AMXTypeRef(LLVMTypeRef);
+///
+/// Carries the same `'ctx` lifetime as [`TypeRef`], tying an `X86AMXTypeRef` to the context it was
+/// created from (or to `'static` for the type obtained from the global context).
+pub struct X86AMXTypeRef<'ctx>(LLVMTypeRef, PhantomData<&'ctx ContextRef>);
 
-impl From<LLVMTypeRef> for X86AMXTypeRef {
+impl<'ctx> From<LLVMTypeRef> for X86AMXTypeRef<'ctx> {
     fn from(value: LLVMTypeRef) -> Self {
-        Self(value)
+        Self(value, PhantomData)
     }
 }
 
-impl GetRef for X86AMXTypeRef {
+impl<'ctx> GetRef for X86AMXTypeRef<'ctx> {
     type RawRef = LLVMTypeRef;
     fn get_ref(&self) -> Self::RawRef {
         self.0
     }
 }
 
-impl From<X86AMXTypeRef> for TypeRef {
-    fn from(value: X86AMXTypeRef) -> Self {
-        Self(value.0)
+impl<'ctx> From<X86AMXTypeRef<'ctx>> for TypeRef<'ctx> {
+    fn from(value: X86AMXTypeRef<'ctx>) -> Self {
+        Self::from(value.0)
     }
 }
 
-impl X86AMXTypeRef {
+/// Fallibly downcasts a `TypeRef` into an `X86AMXTypeRef`, checked via `LLVMGetTypeKind`.
+///
+/// The infallible `From<LLVMTypeRef>` above blindly wraps any type handle and remains available
+/// for internal use where the kind is already known; this conversion is the safe entry point
+/// for callers holding an erased `TypeRef`.
+impl<'ctx> TryFrom<TypeRef<'ctx>> for X86AMXTypeRef<'ctx> {
+    type Error = TypeRef<'ctx>;
+
+    fn try_from(value: TypeRef<'ctx>) -> Result<Self, Self::Error> {
+        if value.get_type_kind() == TypeKind::X86_AMXTypeKind {
+            Ok(Self(value.get_ref(), PhantomData))
+        } else {
+            Err(value)
+        }
+    }
+}
+
+impl<'ctx> X86AMXTypeRef<'ctx> {
     /// Create an X86 AMX type in a context.
     ///
     /// # Details
@@ -252,10 +479,41 @@ impl X86AMXTypeRef {
     ///
     /// Returns an instance of `TypeRef` representing the `x86_amx` type in the specified context.
     #[must_use]
-    pub fn x86_amx_type_in_context(context: &ContextRef) -> TypeRef {
-        unsafe { TypeRef(core::LLVMX86AMXTypeInContext(context.get_ref())) }
+    pub fn x86_amx_type_in_context(context: &'ctx ContextRef) -> TypeRef<'ctx> {
+        unsafe { TypeRef::from(core::LLVMX86AMXTypeInContext(context.get_ref())) }
+    }
+
+    /// Returns a string representation of the type. Use
+    /// `LLVMDisposeMessage` to free the string.
+    ///
+    /// # Details
+    ///
+    /// Converts the LLVM `x86_amx` type to a human-readable string representation.
+    ///
+    /// This function wraps the `LLVMPrintTypeToString` function from the LLVM core library. It returns a `String`
+    /// containing a human-readable representation of the type represented by `self`. This is useful for debugging
+    /// or logging the type information in a readable format.
+    ///
+    /// If the conversion fails, the function returns an empty string.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `String` containing the string representation of the type.
+    #[must_use]
+    pub fn print_type_to_string(&self) -> String {
+        unsafe {
+            let c_str = core::LLVMPrintTypeToString(self.0);
+            if c_str.is_null() {
+                return String::new();
+            }
+            let rust_string = crate::CStr::new(c_str).to_string();
+            core::LLVMDisposeMessage(c_str);
+            rust_string
+        }
     }
+}
 
+impl X86AMXTypeRef<'static> {
     /// Create a X86 AMX type in a global context.
     ///
     /// # Details
@@ -270,35 +528,71 @@ impl X86AMXTypeRef {
     ///
     /// Returns an instance of `TypeRef` representing the `x86_amx` type in the global context.
     #[must_use]
-    pub fn x86_amx_type() -> TypeRef {
-        unsafe { TypeRef(core::LLVMX86AMXType()) }
+    pub fn x86_amx_type() -> TypeRef<'static> {
+        unsafe { TypeRef::from(core::LLVMX86AMXType()) }
+    }
+}
+
+/// Displays the type using its LLVM textual form (`x86_amx`), as produced by
+/// `LLVMPrintTypeToString`.
+impl<'ctx> std::fmt::Display for X86AMXTypeRef<'ctx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.print_type_to_string())
+    }
+}
+
+/// Debug representation showing the LLVM textual form of the type instead of the opaque pointer.
+impl<'ctx> std::fmt::Debug for X86AMXTypeRef<'ctx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("X86AMXTypeRef")
+            .field(&self.print_type_to_string())
+            .finish()
     }
 }
 
 /// These functions relate to `TokenTypeRef` of `X86MMXTypeRef` instances.
-#[derive(Debug)]
-pub struct TokenTypeRef(LLVMTypeRef);
+///
+/// Carries the same `'ctx` lifetime as [`TypeRef`], tying a `TokenTypeRef` to the context it was
+/// created from.
+pub struct TokenTypeRef<'ctx>(LLVMTypeRef, PhantomData<&'ctx ContextRef>);
 
-impl From<LLVMTypeRef> for TokenTypeRef {
+impl<'ctx> From<LLVMTypeRef> for TokenTypeRef<'ctx> {
     fn from(value: LLVMTypeRef) -> Self {
-        Self(value)
+        Self(value, PhantomData)
     }
 }
 
-impl GetRef for TokenTypeRef {
+impl<'ctx> GetRef for TokenTypeRef<'ctx> {
     type RawRef = LLVMTypeRef;
     fn get_ref(&self) -> Self::RawRef {
         self.0
     }
 }
 
-impl From<TokenTypeRef> for TypeRef {
-    fn from(value: TokenTypeRef) -> Self {
-        Self(value.0)
+impl<'ctx> From<TokenTypeRef<'ctx>> for TypeRef<'ctx> {
+    fn from(value: TokenTypeRef<'ctx>) -> Self {
+        Self::from(value.0)
+    }
+}
+
+/// Fallibly downcasts a `TypeRef` into a `TokenTypeRef`, checked via `LLVMGetTypeKind`.
+///
+/// The infallible `From<LLVMTypeRef>` above blindly wraps any type handle and remains available
+/// for internal use where the kind is already known; this conversion is the safe entry point
+/// for callers holding an erased `TypeRef`.
+impl<'ctx> TryFrom<TypeRef<'ctx>> for TokenTypeRef<'ctx> {
+    type Error = TypeRef<'ctx>;
+
+    fn try_from(value: TypeRef<'ctx>) -> Result<Self, Self::Error> {
+        if value.get_type_kind() == TypeKind::TokenTypeKind {
+            Ok(Self(value.get_ref(), PhantomData))
+        } else {
+            Err(value)
+        }
     }
 }
 
-impl TokenTypeRef {
+impl<'ctx> TokenTypeRef<'ctx> {
     /// Create a token type in a context.
     ///
     /// # Details
@@ -317,35 +611,100 @@ impl TokenTypeRef {
     ///
     /// Returns an instance of `TypeRef` representing the `token` type in the specified context.
     #[must_use]
-    pub fn token_type_in_context(context: &ContextRef) -> TypeRef {
-        unsafe { TypeRef(core::LLVMTokenTypeInContext(context.get_ref())) }
+    pub fn token_type_in_context(context: &'ctx ContextRef) -> TypeRef<'ctx> {
+        unsafe { TypeRef::from(core::LLVMTokenTypeInContext(context.get_ref())) }
+    }
+
+    /// Returns a string representation of the type. Use
+    /// `LLVMDisposeMessage` to free the string.
+    ///
+    /// # Details
+    ///
+    /// Converts the LLVM `token` type to a human-readable string representation.
+    ///
+    /// This function wraps the `LLVMPrintTypeToString` function from the LLVM core library. It returns a `String`
+    /// containing a human-readable representation of the type represented by `self`. This is useful for debugging
+    /// or logging the type information in a readable format.
+    ///
+    /// If the conversion fails, the function returns an empty string.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `String` containing the string representation of the type.
+    #[must_use]
+    pub fn print_type_to_string(&self) -> String {
+        unsafe {
+            let c_str = core::LLVMPrintTypeToString(self.0);
+            if c_str.is_null() {
+                return String::new();
+            }
+            let rust_string = crate::CStr::new(c_str).to_string();
+            core::LLVMDisposeMessage(c_str);
+            rust_string
+        }
+    }
+}
+
+/// Displays the type using its LLVM textual form (`token`), as produced by
+/// `LLVMPrintTypeToString`.
+impl<'ctx> std::fmt::Display for TokenTypeRef<'ctx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.print_type_to_string())
+    }
+}
+
+/// Debug representation showing the LLVM textual form of the type instead of the opaque pointer.
+impl<'ctx> std::fmt::Debug for TokenTypeRef<'ctx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("TokenTypeRef")
+            .field(&self.print_type_to_string())
+            .finish()
     }
 }
 
 /// These functions relate to `MetadataTypeRef` of `X86MMXTypeRef` instances.
-#[derive(Debug)]
-pub struct MetadataTypeRef(LLVMTypeRef);
+///
+/// Carries the same `'ctx` lifetime as [`TypeRef`], tying a `MetadataTypeRef` to the context it
+/// was created from.
+pub struct MetadataTypeRef<'ctx>(LLVMTypeRef, PhantomData<&'ctx ContextRef>);
 
-impl From<LLVMTypeRef> for MetadataTypeRef {
+impl<'ctx> From<LLVMTypeRef> for MetadataTypeRef<'ctx> {
     fn from(value: LLVMTypeRef) -> Self {
-        Self(value)
+        Self(value, PhantomData)
     }
 }
 
-impl GetRef for MetadataTypeRef {
+impl<'ctx> GetRef for MetadataTypeRef<'ctx> {
     type RawRef = LLVMTypeRef;
     fn get_ref(&self) -> Self::RawRef {
         self.0
     }
 }
 
-impl From<MetadataTypeRef> for TypeRef {
-    fn from(value: MetadataTypeRef) -> Self {
-        Self(value.0)
+impl<'ctx> From<MetadataTypeRef<'ctx>> for TypeRef<'ctx> {
+    fn from(value: MetadataTypeRef<'ctx>) -> Self {
+        Self::from(value.0)
+    }
+}
+
+/// Fallibly downcasts a `TypeRef` into a `MetadataTypeRef`, checked via `LLVMGetTypeKind`.
+///
+/// The infallible `From<LLVMTypeRef>` above blindly wraps any type handle and remains available
+/// for internal use where the kind is already known; this conversion is the safe entry point
+/// for callers holding an erased `TypeRef`.
+impl<'ctx> TryFrom<TypeRef<'ctx>> for MetadataTypeRef<'ctx> {
+    type Error = TypeRef<'ctx>;
+
+    fn try_from(value: TypeRef<'ctx>) -> Result<Self, Self::Error> {
+        if value.get_type_kind() == TypeKind::MetadataTypeKind {
+            Ok(Self(value.get_ref(), PhantomData))
+        } else {
+            Err(value)
+        }
     }
 }
 
-impl MetadataTypeRef {
+impl<'ctx> MetadataTypeRef<'ctx> {
     /// Create a metadata type in a context.
     ///
     /// # Details
@@ -365,35 +724,100 @@ impl MetadataTypeRef {
     ///
     /// Returns an instance of `TypeRef` representing the `metadata` type in the specified context.
     #[must_use]
-    pub fn metadata_type_in_context(context: &ContextRef) -> TypeRef {
-        unsafe { TypeRef(core::LLVMMetadataTypeInContext(context.get_ref())) }
+    pub fn metadata_type_in_context(context: &'ctx ContextRef) -> TypeRef<'ctx> {
+        unsafe { TypeRef::from(core::LLVMMetadataTypeInContext(context.get_ref())) }
+    }
+
+    /// Returns a string representation of the type. Use
+    /// `LLVMDisposeMessage` to free the string.
+    ///
+    /// # Details
+    ///
+    /// Converts the LLVM `metadata` type to a human-readable string representation.
+    ///
+    /// This function wraps the `LLVMPrintTypeToString` function from the LLVM core library. It returns a `String`
+    /// containing a human-readable representation of the type represented by `self`. This is useful for debugging
+    /// or logging the type information in a readable format.
+    ///
+    /// If the conversion fails, the function returns an empty string.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `String` containing the string representation of the type.
+    #[must_use]
+    pub fn print_type_to_string(&self) -> String {
+        unsafe {
+            let c_str = core::LLVMPrintTypeToString(self.0);
+            if c_str.is_null() {
+                return String::new();
+            }
+            let rust_string = crate::CStr::new(c_str).to_string();
+            core::LLVMDisposeMessage(c_str);
+            rust_string
+        }
+    }
+}
+
+/// Displays the type using its LLVM textual form (`metadata`), as produced by
+/// `LLVMPrintTypeToString`.
+impl<'ctx> std::fmt::Display for MetadataTypeRef<'ctx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.print_type_to_string())
+    }
+}
+
+/// Debug representation showing the LLVM textual form of the type instead of the opaque pointer.
+impl<'ctx> std::fmt::Debug for MetadataTypeRef<'ctx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("MetadataTypeRef")
+            .field(&self.print_type_to_string())
+            .finish()
     }
 }
 
 /// These functions relate to `TargetExtTypeRef` of `X86MMXTypeRef` instances.
-#[derive(Debug)]
-pub struct TargetExtTypeRef(LLVMTypeRef);
+///
+/// Carries the same `'ctx` lifetime as [`TypeRef`], tying a `TargetExtTypeRef` to the context
+/// (and type/int parameters) it was created from.
+pub struct TargetExtTypeRef<'ctx>(LLVMTypeRef, PhantomData<&'ctx ContextRef>);
 
-impl From<LLVMTypeRef> for TargetExtTypeRef {
+impl<'ctx> From<LLVMTypeRef> for TargetExtTypeRef<'ctx> {
     fn from(value: LLVMTypeRef) -> Self {
-        Self(value)
+        Self(value, PhantomData)
     }
 }
 
-impl GetRef for TargetExtTypeRef {
+impl<'ctx> GetRef for TargetExtTypeRef<'ctx> {
     type RawRef = LLVMTypeRef;
     fn get_ref(&self) -> Self::RawRef {
         self.0
     }
 }
 
-impl From<TargetExtTypeRef> for TypeRef {
-    fn from(value: TargetExtTypeRef) -> Self {
-        Self(value.0)
+impl<'ctx> From<TargetExtTypeRef<'ctx>> for TypeRef<'ctx> {
+    fn from(value: TargetExtTypeRef<'ctx>) -> Self {
+        Self::from(value.0)
     }
 }
 
-impl TargetExtTypeRef {
+/// Fallibly downcasts a `TypeRef` into a `TargetExtTypeRef`, checked via `LLVMGetTypeKind`.
+///
+/// The infallible `From<LLVMTypeRef>` above blindly wraps any type handle and remains available
+/// for internal use where the kind is already known; this conversion is the safe entry point
+/// for callers holding an erased `TypeRef`.
+impl<'ctx> TryFrom<TypeRef<'ctx>> for TargetExtTypeRef<'ctx> {
+    type Error = TypeRef<'ctx>;
+
+    fn try_from(value: TypeRef<'ctx>) -> Result<Self, Self::Error> {
+        if value.get_type_kind() == TypeKind::TargetExtTypeKind {
+            Ok(Self(value.get_ref(), PhantomData))
+        } else {
+            Err(value)
+        }
+    }
+}
+
+impl<'ctx> TargetExtTypeRef<'ctx> {
     /// Create a target extension type in LLVM context.
     ///
     /// # Details
@@ -415,26 +839,78 @@ impl TargetExtTypeRef {
     /// # Returns
     ///
     /// Returns an instance of `Self` representing the target extension type in the specified context.
-    #[must_use]
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NulInString`] if `name` contains an interior NUL byte.
     pub fn target_ext_type_in_context(
-        context: &ContextRef,
+        context: &'ctx ContextRef,
         name: &str,
-        type_params: &[TypeRef],
+        type_params: &[TypeRef<'ctx>],
         int_params: &[u32],
-    ) -> Self {
+    ) -> Result<Self, Error> {
         let type_params_ptr = crate::to_mut_ptr!(type_params);
-        let int_params_ptr = crate::map_mut_ptr!(int_params, |v| *CUint::from(*v));
+        let int_params_ptr = crate::map_mut_ptr!(int_params, |v| *CUint::try_from(*v).expect("value does not fit in c_uint"));
 
-        let c_name = CString::from(name);
+        let c_name = CString::try_from(name)?;
         unsafe {
-            Self(core::LLVMTargetExtTypeInContext(
-                context.get_ref(),
-                c_name.as_ptr(),
-                type_params_ptr,
-                *CUint::from(type_params.len()),
-                int_params_ptr,
-                *CUint::from(int_params.len()),
+            Ok(Self(
+                core::LLVMTargetExtTypeInContext(
+                    context.get_ref(),
+                    c_name.as_ptr(),
+                    type_params_ptr,
+                    *CUint::try_from(type_params.len()).expect("value does not fit in c_uint"),
+                    int_params_ptr,
+                    *CUint::try_from(int_params.len()).expect("value does not fit in c_uint"),
+                ),
+                PhantomData,
             ))
         }
     }
+
+    /// Returns a string representation of the type. Use
+    /// `LLVMDisposeMessage` to free the string.
+    ///
+    /// # Details
+    ///
+    /// Converts the LLVM target extension type to a human-readable string representation.
+    ///
+    /// This function wraps the `LLVMPrintTypeToString` function from the LLVM core library. It returns a `String`
+    /// containing a human-readable representation of the type represented by `self`. This is useful for debugging
+    /// or logging the type information in a readable format.
+    ///
+    /// If the conversion fails, the function returns an empty string.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `String` containing the string representation of the type.
+    #[must_use]
+    pub fn print_type_to_string(&self) -> String {
+        unsafe {
+            let c_str = core::LLVMPrintTypeToString(self.0);
+            if c_str.is_null() {
+                return String::new();
+            }
+            let rust_string = crate::CStr::new(c_str).to_string();
+            core::LLVMDisposeMessage(c_str);
+            rust_string
+        }
+    }
+}
+
+/// Displays the type using its LLVM textual form (target extension type), as produced by
+/// `LLVMPrintTypeToString`.
+impl<'ctx> std::fmt::Display for TargetExtTypeRef<'ctx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.print_type_to_string())
+    }
+}
+
+/// Debug representation showing the LLVM textual form of the type instead of the opaque pointer.
+impl<'ctx> std::fmt::Debug for TargetExtTypeRef<'ctx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("TargetExtTypeRef")
+            .field(&self.print_type_to_string())
+            .finish()
+    }
 }
@@ -1,38 +1,61 @@
 //! Functions in this section operate on sequential types.
 
 use crate::core::context::ContextRef;
-use crate::core::types::TypeRef;
-use crate::{CUint, GetRef};
+use crate::core::types::{TypeKind, TypeRef};
+use crate::{CStr, CUint, GetRef};
 use llvm_sys::core;
 use llvm_sys::prelude::LLVMTypeRef;
+use std::marker::PhantomData;
 
 /// These functions relate to `SequentialTypeRef` of `LLVMTypeRef` instances.
 ///
 /// Sequential types represents "arrays" of types. This is a super class
 /// for array, vector, and pointer types.
-#[derive(Debug)]
-pub struct SequentialTypeRef(LLVMTypeRef);
+///
+/// Carries the same `'ctx` lifetime as [`TypeRef`], tying a `SequentialTypeRef` to the context it
+/// was created from.
+pub struct SequentialTypeRef<'ctx>(LLVMTypeRef, PhantomData<&'ctx ContextRef>);
 
-impl From<LLVMTypeRef> for SequentialTypeRef {
+impl<'ctx> From<LLVMTypeRef> for SequentialTypeRef<'ctx> {
     fn from(value: LLVMTypeRef) -> Self {
-        Self(value)
+        Self(value, PhantomData)
     }
 }
 
-impl GetRef for SequentialTypeRef {
+impl<'ctx> GetRef for SequentialTypeRef<'ctx> {
     type RawRef = LLVMTypeRef;
     fn get_ref(&self) -> Self::RawRef {
         self.0
     }
 }
 
-impl From<SequentialTypeRef> for TypeRef {
-    fn from(value: SequentialTypeRef) -> Self {
-        Self(value.0)
+impl<'ctx> From<SequentialTypeRef<'ctx>> for TypeRef<'ctx> {
+    fn from(value: SequentialTypeRef<'ctx>) -> Self {
+        Self::from(value.0)
+    }
+}
+
+/// Fallibly downcasts a `TypeRef` into a `SequentialTypeRef`, checked via `LLVMGetTypeKind`.
+///
+/// The infallible `From<LLVMTypeRef>` above blindly wraps any type handle and remains available
+/// for internal use where the kind is already known; this conversion is the safe entry point
+/// for callers holding an erased `TypeRef`. Array, pointer, vector and scalable vector types are
+/// all sequential types.
+impl<'ctx> TryFrom<TypeRef<'ctx>> for SequentialTypeRef<'ctx> {
+    type Error = TypeRef<'ctx>;
+
+    fn try_from(value: TypeRef<'ctx>) -> Result<Self, Self::Error> {
+        match value.get_type_kind() {
+            TypeKind::ArrayTypeKind
+            | TypeKind::PointerTypeKind
+            | TypeKind::VectorTypeKind
+            | TypeKind::ScalableVectorTypeKind => Ok(Self(value.get_ref(), PhantomData)),
+            _ => Err(value),
+        }
     }
 }
 
-impl SequentialTypeRef {
+impl<'ctx> SequentialTypeRef<'ctx> {
     /// Obtain the element type of array or vector type.
     ///
     /// # Details
@@ -48,8 +71,8 @@ impl SequentialTypeRef {
     ///
     /// Returns a `TypeRef` representing the element type of the composite type.
     #[must_use]
-    pub fn get_element_type(&self) -> TypeRef {
-        unsafe { TypeRef(core::LLVMGetElementType(self.0)) }
+    pub fn get_element_type(&self) -> TypeRef<'ctx> {
+        unsafe { TypeRef::from(core::LLVMGetElementType(self.0)) }
     }
 
     /// Returns type's subtypes
@@ -66,14 +89,14 @@ impl SequentialTypeRef {
     ///
     /// Returns a `Vec<TypeRef>` containing the subtypes of the composite type.
     #[must_use]
-    pub fn get_subtypes(&self) -> Vec<TypeRef> {
+    pub fn get_subtypes(&self) -> Vec<TypeRef<'ctx>> {
         let count = self.get_num_contained_types() as usize;
         let mut subtypes: Vec<LLVMTypeRef> = Vec::with_capacity(count);
         unsafe {
             core::LLVMGetSubtypes(self.0, subtypes.as_mut_ptr());
             subtypes.set_len(count);
         }
-        subtypes.into_iter().map(TypeRef).collect()
+        subtypes.into_iter().map(TypeRef::from).collect()
     }
 
     /// Return the number of types in the derived type.
@@ -114,8 +137,8 @@ impl SequentialTypeRef {
     ///
     /// Returns an instance of `Self` representing the array type with the specified element type and number of elements.
     #[must_use]
-    pub fn array_type2(element_type: &TypeRef, element_count: u64) -> Self {
-        unsafe { Self(core::LLVMArrayType2(element_type.0, element_count)) }
+    pub fn array_type2(element_type: &TypeRef<'ctx>, element_count: u64) -> Self {
+        unsafe { Self(core::LLVMArrayType2(element_type.0, element_count), PhantomData) }
     }
 
     /// Obtain the length of an array type.
@@ -158,8 +181,8 @@ impl SequentialTypeRef {
     ///
     /// Returns an instance of `Self` representing the pointer type with the specified element type and address space.
     #[must_use]
-    pub fn pointer_type(element_type: &TypeRef, address_space: u32) -> Self {
-        unsafe { Self(core::LLVMPointerType(element_type.0, address_space)) }
+    pub fn pointer_type(element_type: &TypeRef<'ctx>, address_space: u32) -> Self {
+        unsafe { Self(core::LLVMPointerType(element_type.0, address_space), PhantomData) }
     }
 
     /// Determine whether a pointer is opaque.
@@ -202,12 +225,12 @@ impl SequentialTypeRef {
     ///
     /// Returns an instance of `Self` representing the opaque pointer type in the specified context and address space.
     #[must_use]
-    pub fn opaque_pointer_type_in_context(context: &ContextRef, address_space: u32) -> Self {
+    pub fn opaque_pointer_type_in_context(context: &'ctx ContextRef, address_space: u32) -> Self {
         unsafe {
-            Self(core::LLVMPointerTypeInContext(
-                context.get_ref(),
-                address_space,
-            ))
+            Self(
+                core::LLVMPointerTypeInContext(context.get_ref(), address_space),
+                PhantomData,
+            )
         }
     }
 
@@ -251,12 +274,12 @@ impl SequentialTypeRef {
     ///
     /// Returns an instance of `Self` representing the vector type with the specified element type and number of elements.
     #[must_use]
-    pub fn vector_type(element_type: &TypeRef, element_count: u32) -> Self {
+    pub fn vector_type(element_type: &TypeRef<'ctx>, element_count: u32) -> Self {
         unsafe {
-            Self(core::LLVMVectorType(
-                element_type.0,
-                *CUint::from(element_count),
-            ))
+            Self(
+                core::LLVMVectorType(element_type.0, *CUint::try_from(element_count).expect("value does not fit in c_uint")),
+                PhantomData,
+            )
         }
     }
 
@@ -283,12 +306,12 @@ impl SequentialTypeRef {
     ///
     /// Returns an instance of `Self` representing the scalable vector type with the specified element type and minimum number of elements.
     #[must_use]
-    pub fn scalable_vector_type(element_type: &TypeRef, element_count: u32) -> Self {
+    pub fn scalable_vector_type(element_type: &TypeRef<'ctx>, element_count: u32) -> Self {
         unsafe {
-            Self(core::LLVMScalableVectorType(
-                element_type.0,
-                *CUint::from(element_count),
-            ))
+            Self(
+                core::LLVMScalableVectorType(element_type.0, *CUint::try_from(element_count).expect("value does not fit in c_uint")),
+                PhantomData,
+            )
         }
     }
 
@@ -310,4 +333,50 @@ impl SequentialTypeRef {
     pub fn get_vector_size(&self) -> u32 {
         unsafe { core::LLVMGetVectorSize(self.0) }
     }
+
+    /// Returns a string representation of the type. Use
+    /// `LLVMDisposeMessage` to free the string.
+    ///
+    /// # Details
+    ///
+    /// Converts the LLVM sequential type to a human-readable string representation.
+    ///
+    /// This function wraps the `LLVMPrintTypeToString` function from the LLVM core library. It returns a `String`
+    /// containing a human-readable representation of the type represented by `self`. This is useful for debugging
+    /// or logging the type information in a readable format.
+    ///
+    /// If the conversion fails, the function returns an empty string.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `String` containing the string representation of the type.
+    #[must_use]
+    pub fn print_type_to_string(&self) -> String {
+        unsafe {
+            let c_str = core::LLVMPrintTypeToString(self.0);
+            if c_str.is_null() {
+                return String::new();
+            }
+            let rust_string = CStr::new(c_str).to_string();
+            core::LLVMDisposeMessage(c_str);
+            rust_string
+        }
+    }
+}
+
+/// Displays the type using its LLVM textual form (e.g. `[4 x float]`, `ptr addrspace(1)`), as
+/// produced by `LLVMPrintTypeToString`.
+impl<'ctx> std::fmt::Display for SequentialTypeRef<'ctx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.print_type_to_string())
+    }
+}
+
+/// Debug representation showing the LLVM textual form of the type instead of the opaque pointer.
+impl<'ctx> std::fmt::Debug for SequentialTypeRef<'ctx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SequentialTypeRef")
+            .field(&self.print_type_to_string())
+            .finish()
+    }
 }
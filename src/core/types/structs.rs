@@ -5,31 +5,34 @@ use crate::core::types::TypeRef;
 use crate::{CInt, CStr, CString, CUint, GetRef};
 use llvm_sys::core;
 use llvm_sys::prelude::LLVMTypeRef;
+use std::marker::PhantomData;
 
 /// These functions relate to `TypeRef` of `LLVMTypeRef` instances.
-#[derive(Debug)]
-pub struct StructTypeRef(LLVMTypeRef);
+///
+/// Carries the same `'ctx` lifetime as [`TypeRef`], tying a `StructTypeRef` to the context it
+/// was created from.
+pub struct StructTypeRef<'ctx>(LLVMTypeRef, PhantomData<&'ctx ContextRef>);
 
-impl From<LLVMTypeRef> for StructTypeRef {
+impl<'ctx> From<LLVMTypeRef> for StructTypeRef<'ctx> {
     fn from(value: LLVMTypeRef) -> Self {
-        Self(value)
+        Self(value, PhantomData)
     }
 }
 
-impl GetRef for StructTypeRef {
+impl<'ctx> GetRef for StructTypeRef<'ctx> {
     type RawRef = LLVMTypeRef;
     fn get_ref(&self) -> Self::RawRef {
         self.0
     }
 }
 
-impl From<StructTypeRef> for TypeRef {
-    fn from(value: StructTypeRef) -> Self {
-        Self(value.0)
+impl<'ctx> From<StructTypeRef<'ctx>> for TypeRef<'ctx> {
+    fn from(value: StructTypeRef<'ctx>) -> Self {
+        Self::from(value.0)
     }
 }
 
-impl StructTypeRef {
+impl<'ctx> StructTypeRef<'ctx> {
     /// Create a new structure type in a context.
     ///
     /// A structure is specified by a list of inner elements/types and
@@ -55,8 +58,8 @@ impl StructTypeRef {
     /// Returns an instance of `Self` representing the structure type in the specified context.
     #[must_use]
     pub fn struct_type_in_context(
-        context: &ContextRef,
-        element_types: &[TypeRef],
+        context: &'ctx ContextRef,
+        element_types: &[TypeRef<'ctx>],
         packed: bool,
     ) -> Self {
         let mut element_types = element_types.iter().map(|v| v.0).collect::<Vec<_>>();
@@ -66,12 +69,15 @@ impl StructTypeRef {
             element_types.as_mut_ptr()
         };
         unsafe {
-            Self(core::LLVMStructTypeInContext(
-                context.get_ref(),
-                elements,
-                *CUint::from(element_types.len()),
-                *CInt::from(packed),
-            ))
+            Self(
+                core::LLVMStructTypeInContext(
+                    context.get_ref(),
+                    elements,
+                    *CUint::try_from(element_types.len()).expect("value does not fit in c_uint"),
+                    *CInt::from(packed),
+                ),
+                PhantomData,
+            )
         }
     }
 
@@ -86,6 +92,9 @@ impl StructTypeRef {
     /// each specified by a `TypeRef`. The structure can be optionally packed, meaning that its elements are laid out
     /// contiguously in memory without any padding.
     ///
+    /// Despite being routed through the global context, the returned type still shares the `'ctx` lifetime of
+    /// `element_types`, since LLVM resolves the owning context from the element types themselves.
+    ///
     /// # Parameters
     ///
     /// - `element_types`: A slice of `TypeRef` representing the types of the elements in the structure. Each element in this slice corresponds to a field in the structure.
@@ -95,7 +104,7 @@ impl StructTypeRef {
     ///
     /// Returns an instance of `Self` representing the structure type in the global context.
     #[must_use]
-    pub fn struct_type(element_types: &[TypeRef], packed: bool) -> Self {
+    pub fn struct_type(element_types: &[TypeRef<'ctx>], packed: bool) -> Self {
         let mut element_types = element_types.iter().map(|v| v.0).collect::<Vec<_>>();
         let elements = if element_types.is_empty() {
             std::ptr::null_mut()
@@ -103,11 +112,14 @@ impl StructTypeRef {
             element_types.as_mut_ptr()
         };
         unsafe {
-            Self(core::LLVMStructType(
-                elements,
-                *CUint::from(element_types.len()),
-                *CInt::from(packed),
-            ))
+            Self(
+                core::LLVMStructType(
+                    elements,
+                    *CUint::try_from(element_types.len()).expect("value does not fit in c_uint"),
+                    *CInt::from(packed),
+                ),
+                PhantomData,
+            )
         }
     }
 
@@ -131,16 +143,113 @@ impl StructTypeRef {
     ///
     /// Returns an instance of `Self` representing the named structure type in the specified context.
     #[must_use]
-    pub fn struct_create_named(context: &ContextRef, name: &str) -> Self {
-        let c_name = CString::from(name);
+    pub fn struct_create_named(context: &'ctx ContextRef, name: &str) -> Self {
+        let c_name = CString::try_from(name).expect("string contains an interior NUL byte");
         unsafe {
-            Self(core::LLVMStructCreateNamed(
-                context.get_ref(),
-                c_name.as_ptr(),
-            ))
+            Self(
+                core::LLVMStructCreateNamed(context.get_ref(), c_name.as_ptr()),
+                PhantomData,
+            )
         }
     }
 
+    /// Create a named structure type in a context and fill in its body in one step.
+    ///
+    /// # Details
+    ///
+    /// Combines `struct_create_named` and `struct_set_body` into a single call, mirroring LLVM's
+    /// `StructType::create(Context, Elements, Name, isPacked)` C++ overload. Splitting named
+    /// structure creation from setting its body is a common footgun: a caller who forgets the
+    /// `struct_set_body` call ends up with a type that is silently, accidentally opaque.
+    ///
+    /// # Parameters
+    ///
+    /// - `context`: A reference to the `ContextRef` in which the structure type will be created.
+    /// - `name`: A string slice (`&str`) representing the name of the structure type.
+    /// - `element_types`: A slice of `TypeRef` representing the types of the elements (fields) in the structure.
+    /// - `packed`: A boolean indicating whether the structure should be packed (`true`) or unpacked (`false`).
+    ///
+    /// # Returns
+    ///
+    /// Returns an instance of `Self` representing the named structure type, with its body already set.
+    #[must_use]
+    pub fn struct_create_named_with_body(
+        context: &'ctx ContextRef,
+        name: &str,
+        element_types: &[TypeRef<'ctx>],
+        packed: bool,
+    ) -> Self {
+        let named = Self::struct_create_named(context, name);
+        named.struct_set_body(element_types, packed);
+        named
+    }
+
+    /// Create an anonymous-but-identified structure type in a context and fill in its body in one
+    /// step.
+    ///
+    /// # Details
+    ///
+    /// Like `struct_create_named_with_body`, but creates the structure without a name. Unlike
+    /// `struct_type_in_context`, the result is still an identified struct (it has its own identity
+    /// distinct from other structurally-equal structs), it just isn't registered under a name in
+    /// the context's symbol table.
+    ///
+    /// # Parameters
+    ///
+    /// - `context`: A reference to the `ContextRef` in which the structure type will be created.
+    /// - `element_types`: A slice of `TypeRef` representing the types of the elements (fields) in the structure.
+    /// - `packed`: A boolean indicating whether the structure should be packed (`true`) or unpacked (`false`).
+    ///
+    /// # Returns
+    ///
+    /// Returns an instance of `Self` representing the identified structure type, with its body already set.
+    #[must_use]
+    pub fn struct_create_identified_with_body(
+        context: &'ctx ContextRef,
+        element_types: &[TypeRef<'ctx>],
+        packed: bool,
+    ) -> Self {
+        Self::struct_create_named_with_body(context, "", element_types, packed)
+    }
+
+    /// Build a recursive named structure type, e.g. a linked-list node that points back to
+    /// itself.
+    ///
+    /// # Details
+    ///
+    /// Only an identified structure can be recursive or left temporarily opaque, so this
+    /// creates the structure via `struct_create_named` first, hands the still-opaque `Self` to
+    /// `build_element_types` so the caller can reference it (through a pointer or an array of
+    /// itself) while assembling the field types, then finalizes the body with `struct_set_body`.
+    /// This is the only safe way to build a self-referential structure: constructing the element
+    /// types before the structure exists is impossible, since there would be nothing yet to
+    /// reference.
+    ///
+    /// # Parameters
+    ///
+    /// - `context`: A reference to the `ContextRef` in which the structure type will be created.
+    /// - `name`: A string slice (`&str`) representing the name of the structure type.
+    /// - `packed`: A boolean indicating whether the structure should be packed (`true`) or unpacked (`false`).
+    /// - `build_element_types`: A closure given the opaque, not-yet-finalized `Self`, which returns the field types to set as its body.
+    ///
+    /// # Returns
+    ///
+    /// Returns an instance of `Self` representing the named structure type, with its body already set.
+    pub fn struct_create_recursive<F>(
+        context: &'ctx ContextRef,
+        name: &str,
+        packed: bool,
+        build_element_types: F,
+    ) -> Self
+    where
+        F: FnOnce(&Self) -> Vec<TypeRef<'ctx>>,
+    {
+        let opaque = Self::struct_create_named(context, name);
+        let element_types = build_element_types(&opaque);
+        opaque.struct_set_body(&element_types, packed);
+        opaque
+    }
+
     /// Obtain the name of a structure.
     ///
     /// # Details
@@ -182,9 +291,19 @@ impl StructTypeRef {
     ///
     /// # Parameters
     ///
-    /// - `element_types`: A slice of `Self` representing the types of the elements (fields) in the structure. Each element in this slice corresponds to a field in the structure.
+    /// - `element_types`: A slice of `TypeRef` representing the types of the elements (fields) in the structure. Each element in this slice corresponds to a field in the structure.
     /// - `packed`: A boolean indicating whether the structure should be packed (`true`) or unpacked (`false`). A packed structure has its fields tightly packed without padding.
-    pub fn struct_set_body(&self, element_types: &[Self], packed: bool) {
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if `self` is a literal structure (`is_literal_struct()`): LLVM
+    /// forbids setting the body of a literal struct (it is uniqued by its contents and fixed at
+    /// creation), so this would otherwise fail silently or behave unpredictably.
+    pub fn struct_set_body(&self, element_types: &[TypeRef<'ctx>], packed: bool) {
+        debug_assert!(
+            !self.is_literal_struct(),
+            "cannot set the body of a literal structure type"
+        );
         let mut element_types = element_types.iter().map(|v| v.0).collect::<Vec<_>>();
         let elements = if element_types.is_empty() {
             std::ptr::null_mut()
@@ -195,7 +314,7 @@ impl StructTypeRef {
             core::LLVMStructSetBody(
                 self.0,
                 elements,
-                *CUint::from(element_types.len()),
+                *CUint::try_from(element_types.len()).expect("value does not fit in c_uint"),
                 *CInt::from(packed),
             );
         }
@@ -248,14 +367,14 @@ impl StructTypeRef {
     /// # Returns
     ///
     /// Returns a `Vec<TypeRef>` representing the types of the elements (fields) in the structure type.
-    pub fn get_struct_element_types(&self) -> Vec<TypeRef> {
+    pub fn get_struct_element_types(&self) -> Vec<TypeRef<'ctx>> {
         let count = self.count_struct_element_types() as usize;
         let mut raw_element_types: Vec<LLVMTypeRef> = Vec::with_capacity(count);
         unsafe {
             core::LLVMGetStructElementTypes(self.0, raw_element_types.as_mut_ptr());
             raw_element_types.set_len(count);
         }
-        raw_element_types.into_iter().map(TypeRef).collect()
+        raw_element_types.into_iter().map(TypeRef::from).collect()
     }
 
     /// Get the type of the element at a given index in the structure.
@@ -280,8 +399,50 @@ impl StructTypeRef {
     ///
     /// This function may panic if the index is out of bounds for the structure, depending on how the underlying LLVM function handles it.
     #[must_use]
-    pub fn struct_get_type_at_index(&self, index: u32) -> TypeRef {
-        unsafe { TypeRef(core::LLVMStructGetTypeAtIndex(self.0, *CUint::from(index))) }
+    pub fn struct_get_type_at_index(&self, index: u32) -> TypeRef<'ctx> {
+        unsafe { TypeRef::from(core::LLVMStructGetTypeAtIndex(self.0, *CUint::try_from(index).expect("value does not fit in c_uint"))) }
+    }
+
+    /// Get the type of the element at a given index in the structure, without panicking on an
+    /// out-of-bounds index.
+    ///
+    /// # Details
+    ///
+    /// Checks `index` against `count_struct_element_types` before calling
+    /// `LLVMStructGetTypeAtIndex`, so an out-of-range index returns `None` instead of relying on
+    /// whatever the underlying LLVM function does with it.
+    ///
+    /// # Parameters
+    ///
+    /// - `index`: The index of the element (field) within the structure. The index is zero-based, meaning `0` refers to the first field.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(TypeRef)` representing the type of the element at `index`, or `None` if
+    /// `index` is out of bounds.
+    #[must_use]
+    pub fn try_get_type_at_index(&self, index: u32) -> Option<TypeRef<'ctx>> {
+        if index >= self.count_struct_element_types() {
+            return None;
+        }
+        Some(self.struct_get_type_at_index(index))
+    }
+
+    /// Returns an iterator over the types of the structure's elements (fields), without
+    /// materializing a `Vec` up front.
+    ///
+    /// # Details
+    ///
+    /// Lazily yields each field's `TypeRef` in order by calling `struct_get_type_at_index` for
+    /// indices `0..count_struct_element_types`. Prefer this over `get_struct_element_types` when
+    /// you only need to inspect or iterate the fields rather than collect them all.
+    ///
+    /// # Returns
+    ///
+    /// Returns an iterator yielding the `TypeRef` of each element in the structure, in order.
+    pub fn element_types_iter(&self) -> impl Iterator<Item = TypeRef<'ctx>> + '_ {
+        (0..self.count_struct_element_types())
+            .map(move |index| self.struct_get_type_at_index(index))
     }
 
     /// Determine whether a structure is packed.
@@ -338,4 +499,71 @@ impl StructTypeRef {
     pub fn is_literal_struct(&self) -> bool {
         unsafe { core::LLVMIsLiteralStruct(self.0) != 0 }
     }
+
+    /// Determine whether a structure is identified rather than literal.
+    ///
+    /// # Details
+    ///
+    /// An identified structure is a top-level, possibly-named structure type created via
+    /// `struct_create_named`: unlike a literal structure, which is uniqued purely by its contents
+    /// and fixed at creation, an identified structure has an identity of its own, so it can be
+    /// left opaque and/or reference itself (directly through a pointer, or through an array of
+    /// itself).
+    ///
+    /// This is simply the negation of `is_literal_struct`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the structure is identified, otherwise returns `false`.
+    #[must_use]
+    pub fn is_identified(&self) -> bool {
+        !self.is_literal_struct()
+    }
+
+    /// Returns a string representation of the type. Use
+    /// `LLVMDisposeMessage` to free the string.
+    ///
+    /// # Details
+    ///
+    /// Converts the LLVM structure type to a human-readable string representation, e.g.
+    /// `%MyStruct = type { i32, i8* }` for a named struct or `{ i32, i8* }` for a literal one.
+    ///
+    /// This function wraps the `LLVMPrintTypeToString` function from the LLVM core library. It returns a `String`
+    /// containing a human-readable representation of the type represented by `self`. This is useful for debugging
+    /// or logging the type information in a readable format.
+    ///
+    /// If the conversion fails, the function returns an empty string.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `String` containing the string representation of the type.
+    #[must_use]
+    pub fn print_type_to_string(&self) -> String {
+        unsafe {
+            let c_str = core::LLVMPrintTypeToString(self.0);
+            if c_str.is_null() {
+                return String::new();
+            }
+            let rust_string = CStr::new(c_str).to_string();
+            core::LLVMDisposeMessage(c_str);
+            rust_string
+        }
+    }
+}
+
+/// Displays the type using its LLVM textual form (e.g. `%MyStruct = type { i32, i8* }`), as
+/// produced by `LLVMPrintTypeToString`.
+impl<'ctx> std::fmt::Display for StructTypeRef<'ctx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.print_type_to_string())
+    }
+}
+
+/// Debug representation showing the LLVM textual form of the type instead of the opaque pointer.
+impl<'ctx> std::fmt::Debug for StructTypeRef<'ctx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("StructTypeRef")
+            .field(&self.print_type_to_string())
+            .finish()
+    }
 }
@@ -47,17 +47,17 @@ impl ValueRef {
     /// Get function parameter by index
     #[must_use]
     pub fn get_func_param(func_value: &Rc<Self>, index: usize) -> Self {
-        unsafe { Self(core::LLVMGetParam(***func_value, *CUint::from(index))) }
+        unsafe { Self(core::LLVMGetParam(***func_value, *CUint::try_from(index).expect("value does not fit in c_uint"))) }
     }
 
     /// Set the string name of a value. By default, in LLVM values monotonic increased
     pub fn set_value_name2(&self, name: &str) {
         unsafe {
-            let c_name = CString::from(name);
+            let c_name = CString::try_from(name).expect("string contains an interior NUL byte");
             core::LLVMSetValueName2(
                 **self,
                 c_name.as_ptr(),
-                *SizeT::from(c_name.to_bytes().len()),
+                *SizeT::try_from(c_name.to_bytes().len()).expect("value does not fit in size_t"),
             );
         }
     }
@@ -66,7 +66,7 @@ impl ValueRef {
     #[must_use]
     pub fn get_inline_asm_asm_string(&self) -> Option<String> {
         unsafe {
-            let mut length = SizeT::from(0_usize);
+            let mut length = SizeT::try_from(0_usize).expect("value does not fit in size_t");
             let c_str = core::LLVMGetInlineAsmAsmString(self.0, &mut *length);
             if c_str.is_null() {
                 None
@@ -80,7 +80,7 @@ impl ValueRef {
     #[must_use]
     pub fn get_inline_asm_constraint_string(&self) -> Option<String> {
         unsafe {
-            let mut length = SizeT::from(0_usize);
+            let mut length = SizeT::try_from(0_usize).expect("value does not fit in size_t");
             let c_str = core::LLVMGetInlineAsmConstraintString(self.0, &mut *length);
             if c_str.is_null() {
                 None
@@ -101,7 +101,7 @@ impl ValueRef {
     ///
     /// This is the same type that was passed into `LLVMGetInlineAsm` originally.
     #[must_use]
-    pub fn get_inline_asm_function_type(&self) -> TypeRef {
+    pub fn get_inline_asm_function_type(&self) -> TypeRef<'_> {
         TypeRef::from(unsafe { core::LLVMGetInlineAsmFunctionType(self.0) })
     }
 
@@ -128,7 +128,7 @@ impl ValueRef {
     #[must_use]
     pub fn get_debug_loc_directory(&self) -> Option<String> {
         unsafe {
-            let mut length = CUint::from(0_usize);
+            let mut length = CUint::try_from(0_usize).expect("value does not fit in c_uint");
             let c_str = core::LLVMGetDebugLocDirectory(self.0, &mut *length);
             if c_str.is_null() {
                 None
@@ -143,7 +143,7 @@ impl ValueRef {
     #[must_use]
     pub fn get_debug_loc_filename(&self) -> Option<String> {
         unsafe {
-            let mut length = CUint::from(0_usize);
+            let mut length = CUint::try_from(0_usize).expect("value does not fit in c_uint");
             let c_str = core::LLVMGetDebugLocFilename(self.0, &mut *length);
             if c_str.is_null() {
                 None
@@ -2,7 +2,13 @@
 
 use super::ValueRef;
 use crate::core::context::ContextRef;
+use crate::core::types::float::FloatTypeRef;
+use crate::core::types::int::IntTypeRef;
+use crate::core::types::structs::StructTypeRef;
 use crate::core::types::TypeRef;
+use crate::core::values::general::{get_value_kind, type_of};
+use crate::core::values::ValueKind;
+use crate::error::Error;
 use crate::{CInt, CStr, CString, CUint, GetRef, SizeT};
 use llvm_sys::core;
 
@@ -31,12 +37,12 @@ pub fn const_string_in_context2(
     string: &str,
     dont_null_terminate: bool,
 ) -> ValueRef {
-    let c_string = CString::from(string);
+    let c_string = CString::try_from(string).expect("string contains an interior NUL byte");
     unsafe {
         ValueRef(core::LLVMConstStringInContext2(
             context.get_ref(),
             c_string.as_ptr(),
-            *SizeT::from(string.len()),
+            *SizeT::try_from(string.len()).expect("value does not fit in size_t"),
             *CInt::from(dont_null_terminate),
         ))
     }
@@ -66,16 +72,134 @@ pub fn const_string_in_context2(
 /// Returns an instance of `ValueRef`, which encapsulates the constant string value created in the global context.
 #[must_use]
 pub fn const_string(string: &str, dont_null_terminate: bool) -> ValueRef {
-    let c_string = CString::from(string);
+    let c_string = CString::try_from(string).expect("string contains an interior NUL byte");
     unsafe {
         ValueRef(core::LLVMConstString(
             c_string.as_ptr(),
-            *CUint::from(string.len()),
+            *CUint::try_from(string.len()).expect("value does not fit in c_uint"),
             *CInt::from(dont_null_terminate),
         ))
     }
 }
 
+/// Create a `ConstantDataSequential` from raw bytes in a specified LLVM context.
+///
+/// # Details
+///
+/// Creates a constant string value from an arbitrary byte slice in a specified LLVM context.
+///
+/// This function wraps the `LLVMConstStringInContext2` function from the LLVM core library, the
+/// same underlying call as `const_string_in_context2`. Unlike that function, it takes `&[u8]`
+/// rather than `&str`, so callers can build constant "string" data (e.g. embedded binary blobs,
+/// or text whose encoding is not known to be UTF-8) without Rust's `&str` validity requirement
+/// getting in the way.
+///
+/// # Parameters
+///
+/// - `context`: A reference to the LLVM context (`ContextRef`) in which the constant should be created.
+/// - `bytes`: The raw bytes that make up the content of the constant. These are copied as-is, with no encoding assumptions.
+/// - `dont_null_terminate`: A boolean value indicating whether the constant should not be null-terminated. If `true`, no null terminator is appended; if `false`, one is.
+///
+/// # Returns
+///
+/// Returns an instance of `ValueRef`, which encapsulates the constant value created in the specified context.
+#[must_use]
+pub fn const_bytes(context: &ContextRef, bytes: &[u8], dont_null_terminate: bool) -> ValueRef {
+    unsafe {
+        ValueRef(core::LLVMConstStringInContext2(
+            context.get_ref(),
+            bytes.as_ptr().cast::<std::os::raw::c_char>(),
+            *SizeT::try_from(bytes.len()).expect("value does not fit in size_t"),
+            *CInt::from(dont_null_terminate),
+        ))
+    }
+}
+
+/// Create a `ConstantDataArray` of `i8` elements directly from raw bytes.
+///
+/// A thin, non-null-terminating wrapper around [`const_bytes`] — the same
+/// `LLVMConstStringInContext2` call LLVM itself uses to build a `ConstantDataArray` from a
+/// `StringRef` of raw data — so large blobs can be embedded without building an intermediate
+/// `Vec<ValueRef>` of per-byte constants. Pair with [`get_as_string`] or
+/// [`get_element_as_constant`] to read the data back out.
+#[must_use]
+pub fn const_data_array_from_bytes(context: &ContextRef, bytes: &[u8]) -> ValueRef {
+    const_bytes(context, bytes, true)
+}
+
+/// Create a constant array of `i16` elements from raw `u16` values.
+///
+/// # Details
+///
+/// LLVM's C API does not expose a bulk constructor for `ConstantDataArray` beyond the `i8`
+/// string form used by [`const_data_array_from_bytes`]; this builds each element as a scalar
+/// `ConstantInt` via [`scalar::const_int`](super::scalar::const_int) and assembles them with
+/// [`try_const_array2`]. LLVM still canonicalizes the result to a `ConstantDataArray`, since
+/// every element is a simple constant integer of the same type.
+///
+/// # Errors
+///
+/// Returns [`Error::IntCast`] if `values.len()` does not fit in a `u64`.
+pub fn const_data_array_from_u16(context: &ContextRef, values: &[u16]) -> Result<ValueRef, Error> {
+    let element_type = IntTypeRef::int16_type_in_context(context).into();
+    let elements = values
+        .iter()
+        .map(|&v| super::scalar::const_int(&element_type, u64::from(v), false))
+        .collect::<Vec<_>>();
+    try_const_array2(&element_type, &elements)
+}
+
+/// Create a constant array of `i32` elements from raw `u32` values.
+///
+/// See [`const_data_array_from_u16`] for the approach: elements are built one at a time via
+/// [`scalar::const_int`](super::scalar::const_int) and assembled with [`try_const_array2`].
+///
+/// # Errors
+///
+/// Returns [`Error::IntCast`] if `values.len()` does not fit in a `u64`.
+pub fn const_data_array_from_u32(context: &ContextRef, values: &[u32]) -> Result<ValueRef, Error> {
+    let element_type = IntTypeRef::int32_type_in_context(context).into();
+    let elements = values
+        .iter()
+        .map(|&v| super::scalar::const_int(&element_type, u64::from(v), false))
+        .collect::<Vec<_>>();
+    try_const_array2(&element_type, &elements)
+}
+
+/// Create a constant array of `float` elements from raw `f32` values.
+///
+/// See [`const_data_array_from_u16`] for the approach: elements are built one at a time via
+/// [`scalar::const_real`](super::scalar::const_real) and assembled with [`try_const_array2`].
+///
+/// # Errors
+///
+/// Returns [`Error::IntCast`] if `values.len()` does not fit in a `u64`.
+pub fn const_data_array_from_f32(context: &ContextRef, values: &[f32]) -> Result<ValueRef, Error> {
+    let element_type = FloatTypeRef::float_type_in_context(context).into();
+    let elements = values
+        .iter()
+        .map(|&v| super::scalar::const_real(&element_type, f64::from(v)))
+        .collect::<Vec<_>>();
+    try_const_array2(&element_type, &elements)
+}
+
+/// Create a constant array of `double` elements from raw `f64` values.
+///
+/// See [`const_data_array_from_u16`] for the approach: elements are built one at a time via
+/// [`scalar::const_real`](super::scalar::const_real) and assembled with [`try_const_array2`].
+///
+/// # Errors
+///
+/// Returns [`Error::IntCast`] if `values.len()` does not fit in a `u64`.
+pub fn const_data_array_from_f64(context: &ContextRef, values: &[f64]) -> Result<ValueRef, Error> {
+    let element_type = FloatTypeRef::double_type_in_context(context).into();
+    let elements = values
+        .iter()
+        .map(|&v| super::scalar::const_real(&element_type, v))
+        .collect::<Vec<_>>();
+    try_const_array2(&element_type, &elements)
+}
+
 /// Returns true if the specified constant is an array of `i8`.
 ///
 /// # Details
@@ -122,6 +246,35 @@ pub fn get_as_string(val: &ValueRef) -> Option<String> {
     }
 }
 
+/// Get the element of a `ConstantDataSequential` at the given index, as a constant.
+///
+/// # Details
+///
+/// Retrieves a specific element from a `ConstantDataArray`/`ConstantDataVector`.
+///
+/// This function wraps the `LLVMGetElementAsConstant` function from the LLVM core library.
+/// Unlike [`get_aggregate_element`], which walks a general `ConstantAggregate`, this is
+/// specific to the dense data sequential produced by e.g. [`const_string`] or
+/// [`const_bytes`], and always resolves an in-range index to a constant `i8`/float element.
+///
+/// # Parameters
+///
+/// - `val`: The constant data sequential (`ConstantDataArray` or `ConstantDataVector`) to read from.
+/// - `idx`: The index of the element to retrieve.
+///
+/// # Returns
+///
+/// Returns a [`ValueRef`] for the constant element at `idx`.
+#[must_use]
+pub fn get_element_as_constant(val: &ValueRef, idx: u32) -> ValueRef {
+    unsafe {
+        ValueRef(core::LLVMGetElementAsConstant(
+            val.get_ref(),
+            *CUint::try_from(idx).expect("value does not fit in c_uint"),
+        ))
+    }
+}
+
 /// Create an anonymous `ConstantStruct` with the specified values.
 ///
 /// # Details
@@ -158,12 +311,43 @@ pub fn const_struct_in_context(
         ValueRef(core::LLVMConstStructInContext(
             context.get_ref(),
             constant_vals_ptr,
-            *CUint::from(constant_vals.len()),
+            *CUint::try_from(constant_vals.len()).expect("value does not fit in c_uint"),
             *CInt::from(packed),
         ))
     }
 }
 
+/// Fallible, overflow-checked form of [`const_struct_in_context`].
+///
+/// `LLVMConstStructInContext` takes the field count as a C `unsigned` (32 bits); the
+/// infallible form above silently truncates a longer slice via `CUint::from`. This validates
+/// `constant_vals.len()` fits in a `c_uint` before making the FFI call.
+///
+/// # Errors
+///
+/// Returns [`Error::IntCast`] if `constant_vals.len()` does not fit in a `c_uint`.
+pub fn try_const_struct_in_context(
+    context: &ContextRef,
+    constant_vals: &[ValueRef],
+    packed: bool,
+) -> Result<ValueRef, Error> {
+    let len = CUint::try_from(constant_vals.len())?;
+    let mut constant_vals = constant_vals.iter().map(|v| v.0).collect::<Vec<_>>();
+    let constant_vals_ptr = if constant_vals.is_empty() {
+        std::ptr::null_mut()
+    } else {
+        constant_vals.as_mut_ptr()
+    };
+    Ok(unsafe {
+        ValueRef(core::LLVMConstStructInContext(
+            context.get_ref(),
+            constant_vals_ptr,
+            *len,
+            *CInt::from(packed),
+        ))
+    })
+}
+
 /// Create a `ConstantStruct` in the global `Context`.
 ///
 /// This is the same as `constStruct_in_context` except it operates on the
@@ -197,12 +381,37 @@ pub fn const_struct(constant_vals: &[ValueRef], packed: bool) -> ValueRef {
     unsafe {
         ValueRef(core::LLVMConstStruct(
             constant_vals_ptr,
-            *CUint::from(constant_vals.len()),
+            *CUint::try_from(constant_vals.len()).expect("value does not fit in c_uint"),
             *CInt::from(packed),
         ))
     }
 }
 
+/// Fallible, overflow-checked form of [`const_struct`].
+///
+/// See [`try_const_struct_in_context`]: this validates `constant_vals.len()` fits in a
+/// `c_uint` before making the FFI call, instead of silently truncating it.
+///
+/// # Errors
+///
+/// Returns [`Error::IntCast`] if `constant_vals.len()` does not fit in a `c_uint`.
+pub fn try_const_struct(constant_vals: &[ValueRef], packed: bool) -> Result<ValueRef, Error> {
+    let len = CUint::try_from(constant_vals.len())?;
+    let mut constant_vals = constant_vals.iter().map(|v| v.0).collect::<Vec<_>>();
+    let constant_vals_ptr = if constant_vals.is_empty() {
+        std::ptr::null_mut()
+    } else {
+        constant_vals.as_mut_ptr()
+    };
+    Ok(unsafe {
+        ValueRef(core::LLVMConstStruct(
+            constant_vals_ptr,
+            *len,
+            *CInt::from(packed),
+        ))
+    })
+}
+
 /// Create a `ConstantArray` from values.
 ///
 /// # Details
@@ -221,8 +430,15 @@ pub fn const_struct(constant_vals: &[ValueRef], packed: bool) -> ValueRef {
 /// # Returns
 ///
 /// Returns an instance of [`ValueRef`], which encapsulates the constant array value created with the specified element type and elements.
+///
+/// # Note
+///
+/// `constant_vals.len()` is converted to the `u64` count `LLVMConstArray2` expects via a
+/// saturating cast: a slice whose length somehow does not fit in a `u64` is silently
+/// truncated to `u64::MAX` rather than rejected. Prefer [`try_const_array2`], which reports
+/// that case as an error instead of building a wrong-sized constant.
 #[must_use]
-pub fn const_array2(element_type: &TypeRef, constant_vals: &[ValueRef]) -> ValueRef {
+pub fn const_array2(element_type: &TypeRef<'_>, constant_vals: &[ValueRef]) -> ValueRef {
     let mut constant_vals = constant_vals.iter().map(|v| v.0).collect::<Vec<_>>();
     let constant_vals_ptr = if constant_vals.is_empty() {
         std::ptr::null_mut()
@@ -238,6 +454,37 @@ pub fn const_array2(element_type: &TypeRef, constant_vals: &[ValueRef]) -> Value
     }
 }
 
+/// Fallible, overflow-checked form of [`const_array2`].
+///
+/// Validates that `constant_vals.len()` fits in the `u64` element count
+/// `LLVMConstArray2` expects, instead of saturating it to `u64::MAX`.
+///
+/// # Errors
+///
+/// Returns [`Error::IntCast`] if `constant_vals.len()` does not fit in a `u64`.
+pub fn try_const_array2(
+    element_type: &TypeRef<'_>,
+    constant_vals: &[ValueRef],
+) -> Result<ValueRef, Error> {
+    let len = u64::try_from(constant_vals.len()).map_err(|_| Error::IntCast {
+        value: constant_vals.len().to_string(),
+        target: "u64",
+    })?;
+    let mut constant_vals = constant_vals.iter().map(|v| v.0).collect::<Vec<_>>();
+    let constant_vals_ptr = if constant_vals.is_empty() {
+        std::ptr::null_mut()
+    } else {
+        constant_vals.as_mut_ptr()
+    };
+    Ok(unsafe {
+        ValueRef(core::LLVMConstArray2(
+            element_type.get_ref(),
+            constant_vals_ptr,
+            len,
+        ))
+    })
+}
+
 /// Create a non-anonymous `ConstantStruct` from values.
 ///
 /// # Details
@@ -257,7 +504,7 @@ pub fn const_array2(element_type: &TypeRef, constant_vals: &[ValueRef]) -> Value
 ///
 /// Returns an instance of [`ValueRef`], which encapsulates the constant named struct value created with the specified fields.
 #[must_use]
-pub fn const_named_struct(struct_type: &TypeRef, constant_vals: &[ValueRef]) -> ValueRef {
+pub fn const_named_struct(struct_type: &TypeRef<'_>, constant_vals: &[ValueRef]) -> ValueRef {
     let mut constant_vals = constant_vals.iter().map(|v| v.0).collect::<Vec<_>>();
     let constant_vals_ptr = if constant_vals.is_empty() {
         std::ptr::null_mut()
@@ -268,11 +515,39 @@ pub fn const_named_struct(struct_type: &TypeRef, constant_vals: &[ValueRef]) ->
         ValueRef(core::LLVMConstNamedStruct(
             struct_type.get_ref(),
             constant_vals_ptr,
-            *CUint::from(constant_vals.len()),
+            *CUint::try_from(constant_vals.len()).expect("value does not fit in c_uint"),
         ))
     }
 }
 
+/// Fallible, overflow-checked form of [`const_named_struct`].
+///
+/// See [`try_const_struct_in_context`]: this validates `constant_vals.len()` fits in a
+/// `c_uint` before making the FFI call, instead of silently truncating it.
+///
+/// # Errors
+///
+/// Returns [`Error::IntCast`] if `constant_vals.len()` does not fit in a `c_uint`.
+pub fn try_const_named_struct(
+    struct_type: &TypeRef<'_>,
+    constant_vals: &[ValueRef],
+) -> Result<ValueRef, Error> {
+    let len = CUint::try_from(constant_vals.len())?;
+    let mut constant_vals = constant_vals.iter().map(|v| v.0).collect::<Vec<_>>();
+    let constant_vals_ptr = if constant_vals.is_empty() {
+        std::ptr::null_mut()
+    } else {
+        constant_vals.as_mut_ptr()
+    };
+    Ok(unsafe {
+        ValueRef(core::LLVMConstNamedStruct(
+            struct_type.get_ref(),
+            constant_vals_ptr,
+            *len,
+        ))
+    })
+}
+
 /// Get element of a constant aggregate `(struct, array or vector)` at the
 /// specified index. Returns `None` if the index is out of range, or it's not
 /// possible to determine the element (e.g., because the constant is a
@@ -297,7 +572,7 @@ pub fn const_named_struct(struct_type: &TypeRef, constant_vals: &[ValueRef]) ->
 /// - `None` if the index is out of bounds or the element cannot be retrieved.
 #[must_use]
 pub fn get_aggregate_element(val: &ValueRef, idx: u32) -> Option<ValueRef> {
-    let element = unsafe { core::LLVMGetAggregateElement(val.get_ref(), *CUint::from(idx)) };
+    let element = unsafe { core::LLVMGetAggregateElement(val.get_ref(), *CUint::try_from(idx).expect("value does not fit in c_uint")) };
     if element.is_null() {
         None
     } else {
@@ -333,7 +608,159 @@ pub fn const_vector(scalar_constant_vals: &[ValueRef]) -> ValueRef {
     unsafe {
         ValueRef(core::LLVMConstVector(
             scalar_constant_vals_ptr,
-            *CUint::from(scalar_constant_vals.len()),
+            *CUint::try_from(scalar_constant_vals.len()).expect("value does not fit in c_uint"),
         ))
     }
 }
+
+/// Fallible, overflow-checked form of [`const_vector`].
+///
+/// See [`try_const_struct_in_context`]: this validates `scalar_constant_vals.len()` fits in
+/// a `c_uint` before making the FFI call, instead of silently truncating it.
+///
+/// # Errors
+///
+/// Returns [`Error::IntCast`] if `scalar_constant_vals.len()` does not fit in a `c_uint`.
+pub fn try_const_vector(scalar_constant_vals: &[ValueRef]) -> Result<ValueRef, Error> {
+    let len = CUint::try_from(scalar_constant_vals.len())?;
+    let mut scalar_constant_vals = scalar_constant_vals.iter().map(|v| v.0).collect::<Vec<_>>();
+    let scalar_constant_vals_ptr = if scalar_constant_vals.is_empty() {
+        std::ptr::null_mut()
+    } else {
+        scalar_constant_vals.as_mut_ptr()
+    };
+    Ok(unsafe { ValueRef(core::LLVMConstVector(scalar_constant_vals_ptr, *len)) })
+}
+
+/// A constant composed of other constants: an array, struct, or vector.
+///
+/// Mirrors LLVM's `ConstantAggregate`, the common base of `ConstantArray`, `ConstantStruct`,
+/// and `ConstantVector`. Wraps a [`ValueRef`] already known to be one of these kinds and
+/// gives uniform element access over it, so callers can walk a constant aggregate — including
+/// one nested inside another, e.g. a constant struct whose fields are themselves constant
+/// arrays — without switching on the aggregate's specific kind.
+pub struct ConstantAggregate(ValueRef);
+
+impl From<ConstantAggregate> for ValueRef {
+    fn from(value: ConstantAggregate) -> Self {
+        value.0
+    }
+}
+
+impl GetRef for ConstantAggregate {
+    type RawRef = <ValueRef as GetRef>::RawRef;
+    fn get_ref(&self) -> Self::RawRef {
+        self.0.get_ref()
+    }
+}
+
+/// Fallibly downcasts a `ValueRef` into a `ConstantAggregate`, checked via `LLVMGetValueKind`.
+///
+/// Array, struct, and vector constants are all `ConstantAggregate`s; everything else
+/// (including the related but distinct `ConstantDataArray`/`ConstantDataVector` kinds
+/// produced by [`const_string`] and [`const_bytes`]) is rejected.
+impl TryFrom<ValueRef> for ConstantAggregate {
+    type Error = ValueRef;
+
+    fn try_from(value: ValueRef) -> Result<Self, Self::Error> {
+        match get_value_kind(&value) {
+            ValueKind::ConstantArray | ValueKind::ConstantStruct | ValueKind::ConstantVector => {
+                Ok(Self(value))
+            }
+            _ => Err(value),
+        }
+    }
+}
+
+impl ConstantAggregate {
+    /// The number of elements in this aggregate.
+    ///
+    /// LLVM stores an aggregate constant's elements as its operands, so this is a thin,
+    /// `u32`-returning wrapper around [`ValueRef::num_operands`].
+    #[must_use]
+    pub fn num_elements(&self) -> u32 {
+        self.0.num_operands()
+    }
+
+    /// Get the element at `idx`.
+    ///
+    /// Returns `None` if `idx` is out of range, or if the element cannot be determined (e.g.
+    /// because the constant is a constant expression). A thin wrapper around
+    /// [`get_aggregate_element`].
+    #[must_use]
+    pub fn element(&self, idx: u32) -> Option<ValueRef> {
+        get_aggregate_element(&self.0, idx)
+    }
+
+    /// Iterate over this aggregate's elements in order.
+    #[must_use]
+    pub fn elements(&self) -> ConstantAggregateElements<'_> {
+        ConstantAggregateElements {
+            aggregate: self,
+            index: 0,
+            len: self.num_elements(),
+        }
+    }
+}
+
+/// An iterator over the elements of a [`ConstantAggregate`], returned by
+/// [`ConstantAggregate::elements`].
+///
+/// Lazily calls [`get_aggregate_element`] for each index in turn rather than eagerly
+/// collecting, so callers that only need the first few elements (or want to bail out early
+/// on a nested aggregate) do no more work than necessary.
+pub struct ConstantAggregateElements<'a> {
+    aggregate: &'a ConstantAggregate,
+    index: u32,
+    len: u32,
+}
+
+impl<'a> Iterator for ConstantAggregateElements<'a> {
+    type Item = ValueRef;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+        let element = self.aggregate.element(self.index);
+        self.index += 1;
+        element
+    }
+}
+
+/// Derive the anonymous `StructType` for a would-be `ConstantStruct` built from
+/// `constant_vals`, mirroring LLVM's `ConstantStruct::getTypeForElements`.
+///
+/// This reads each value's type via [`type_of`] and hands the resulting list to
+/// [`StructTypeRef::struct_type_in_context`], so callers that just called
+/// [`const_struct_in_context`] (or are about to) can recover the matching struct type
+/// without reconstructing the layout by hand.
+#[must_use]
+pub fn struct_type_for_elements<'ctx>(
+    context: &'ctx ContextRef,
+    constant_vals: &[ValueRef],
+    packed: bool,
+) -> TypeRef<'ctx> {
+    let element_types = constant_vals
+        .iter()
+        .map(|v| TypeRef::from(type_of(v).get_ref()))
+        .collect::<Vec<_>>();
+    StructTypeRef::struct_type_in_context(context, &element_types, packed).into()
+}
+
+/// Create an anonymous `ConstantStruct` together with its `StructType`.
+///
+/// A convenience wrapper pairing [`const_struct_in_context`] with
+/// [`struct_type_for_elements`], so callers who need both the constant and its type (e.g. to
+/// declare the global it initializes, or to build a `GEP` into it) do not have to call the
+/// two separately.
+#[must_use]
+pub fn const_struct_with_type<'ctx>(
+    context: &'ctx ContextRef,
+    constant_vals: &[ValueRef],
+    packed: bool,
+) -> (ValueRef, TypeRef<'ctx>) {
+    let struct_type = struct_type_for_elements(context, constant_vals, packed);
+    let value = const_struct_in_context(context, constant_vals, packed);
+    (value, struct_type)
+}
@@ -23,10 +23,14 @@
 //!
 //! These functions wrap the corresponding LLVM core library functions, providing a safe and idiomatic Rust interface for interacting with LLVM constants.
 
+use super::scalar::{const_int, const_int_get_sext_value, const_int_get_zext_value};
 use super::ValueRef;
 use crate::basic_block::BasicBlockRef;
 use crate::core::types::TypeRef;
+use crate::core::values::general::{get_value_kind, type_of};
+use crate::core::values::ValueKind;
 use crate::core::{IntPredicate, Opcode, RealPredicate};
+use crate::error::Error;
 use crate::{CUint, GetRef};
 use llvm_sys::core;
 
@@ -51,6 +55,46 @@ impl ValueRef {
         unsafe { Opcode::from(core::LLVMGetConstOpcode(self.0)) }
     }
 
+    /// Obtain the number of operands of a constant expression (or any other `LLVM User` value).
+    ///
+    /// # Details
+    ///
+    /// `get_const_opcode` only reveals *what* operation a constant expression performs; this is
+    /// the companion that reveals *what it operates on*. A thin, `u32`-returning wrapper around
+    /// `uses::get_num_operands`, so callers can size a loop over `operand` to walk the
+    /// expression tree, e.g. to re-emit it as runtime instructions on an LLVM version that no
+    /// longer supports its constexpr kind, or to pretty-print a constant initializer.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `u32` representing the number of operands `self` has.
+    #[must_use]
+    pub fn num_operands(&self) -> u32 {
+        crate::core::values::uses::get_num_operands(self) as u32
+    }
+
+    /// Obtain the operand of a constant expression (or any other `LLVM User` value) at a given
+    /// index.
+    ///
+    /// # Details
+    ///
+    /// A thin wrapper around `uses::get_operand`, kept alongside `get_const_opcode` and
+    /// `num_operands` so a caller can recursively walk a folded constant expression's operand
+    /// edges, which are otherwise opaque.
+    ///
+    /// # Parameters
+    ///
+    /// - `index`: The index of the operand to retrieve, in `0..self.num_operands()`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(ValueRef)` with the operand at `index`, or `None` if `index` is out of
+    /// bounds.
+    #[must_use]
+    pub fn operand(&self, index: u32) -> Option<Self> {
+        crate::core::values::uses::get_operand(self, index)
+    }
+
     /// Obtain the alignment of the specified type.
     ///
     /// # Details
@@ -70,7 +114,7 @@ impl ValueRef {
     ///
     /// Returns a new constant integer value representing the alignment of the specified type in bytes.
     #[must_use]
-    pub fn align_of(ty: &TypeRef) -> Self {
+    pub fn align_of(ty: &TypeRef<'_>) -> Self {
         unsafe { Self(core::LLVMAlignOf(ty.get_ref())) }
     }
 
@@ -92,7 +136,7 @@ impl ValueRef {
     ///
     /// Returns a new constant integer value representing the size of the specified type in bytes.
     #[must_use]
-    pub fn size_of(ty: &TypeRef) -> Self {
+    pub fn size_of(ty: &TypeRef<'_>) -> Self {
         unsafe { Self(core::LLVMSizeOf(ty.get_ref())) }
     }
 
@@ -135,6 +179,48 @@ impl ValueRef {
         unsafe { Self(core::LLVMConstNSWNeg(self.0)) }
     }
 
+    /// Opt-in fallback for `const_nsw_neg` on LLVM versions where the `nsw neg`
+    /// constant-expression kind has been removed.
+    ///
+    /// # Details
+    ///
+    /// Recent LLVM releases have begun deleting constant-expression kinds such as `nsw neg`
+    /// outright, so `LLVMConstNSWNeg` can return a null `LLVMValueRef` instead of a folded
+    /// constant. This calls `const_nsw_neg` first and, only if it came back null, folds the
+    /// negation directly in Rust: for the common case where `self` is a plain `ConstantInt` of
+    /// a width up to 64 bits, it reads its sign-extended value, returns
+    /// `ValueRef::get_poison` if it is the minimum representable value for that width (negating
+    /// it would signed-overflow, matching `nsw`'s poison-on-overflow semantics), and otherwise
+    /// rebuilds a fresh constant with `const_int` from the negated, width-masked value. Any
+    /// other operand (not a plain integer constant, or wider than 64 bits) falls back to the
+    /// (possibly null) native result unchanged, since there is no builder/insert point threaded
+    /// through this free function to emit an equivalent instruction. Callers that only target
+    /// current LLVM can keep calling `const_nsw_neg` directly.
+    #[must_use]
+    pub fn const_nsw_neg_or_fold(&self) -> Self {
+        let native = self.const_nsw_neg();
+        if !native.0.is_null() {
+            return native;
+        }
+        let Some(width) = int_const_width(self) else {
+            return native;
+        };
+        if width > 64 {
+            return native;
+        }
+        let ty = type_of(self);
+        let value = const_int_get_sext_value(self);
+        let min = if width == 64 {
+            i64::MIN
+        } else {
+            -(1_i64 << (width - 1))
+        };
+        if value == min {
+            return Self::get_poison(&ty);
+        }
+        const_int(&ty, mask_to_width((-value) as u64, width), true)
+    }
+
     /// Create a `NUW` negation operation on a constant value.
     ///
     /// # Details
@@ -155,6 +241,36 @@ impl ValueRef {
         unsafe { Self(core::LLVMConstNUWNeg(self.0)) }
     }
 
+    /// Opt-in fallback for `const_nuw_neg` on LLVM versions where the `nuw neg`
+    /// constant-expression kind has been removed.
+    ///
+    /// # Details
+    ///
+    /// See `const_nsw_neg_or_fold` for the rationale. For the common case where `self` is a
+    /// plain `ConstantInt` of a width up to 64 bits, this reads its zero-extended value and
+    /// returns `ValueRef::get_poison` unless it is exactly zero, since unsigned negation of any
+    /// other value unsigned-overflows; zero negates to itself. Any other operand falls back to
+    /// the (possibly null) native result unchanged, for the same reasons as
+    /// `const_nsw_neg_or_fold`.
+    #[must_use]
+    pub fn const_nuw_neg_or_fold(&self) -> Self {
+        let native = self.const_nuw_neg();
+        if !native.0.is_null() {
+            return native;
+        }
+        let Some(width) = int_const_width(self) else {
+            return native;
+        };
+        if width > 64 {
+            return native;
+        }
+        let ty = type_of(self);
+        if const_int_get_zext_value(self) != 0 {
+            return Self::get_poison(&ty);
+        }
+        const_int(&ty, 0, false)
+    }
+
     /// Create a logical NOT operation on a constant value.
     ///
     /// # Details
@@ -498,6 +614,45 @@ impl ValueRef {
         unsafe { Self(core::LLVMConstShl(lhs.0, rhs.0)) }
     }
 
+    /// Opt-in fallback for `const_shl` on LLVM versions where the `shl` constant-expression
+    /// kind has been removed.
+    ///
+    /// # Details
+    ///
+    /// Recent LLVM releases have begun deleting constant-expression kinds such as `shl`
+    /// outright, so `LLVMConstShl` can return a null `LLVMValueRef` instead of a folded
+    /// constant. This calls `const_shl` first and, only if it came back null, folds the shift
+    /// directly in Rust: for the common case where `lhs` and `rhs` are both plain
+    /// `ConstantInt`s of a width up to 64 bits, it reads their zero-extended values, returns
+    /// `ValueRef::get_poison` if the shift amount is greater than or equal to the type's width
+    /// (matching `shl`'s existing poison-on-overflow semantics), and otherwise shifts `lhs` by
+    /// that amount, masks the result to the type's width, and rebuilds a fresh constant with
+    /// `const_int`. Operands that are not plain integer constants, or whose width exceeds 64
+    /// bits, fall back to the (possibly null) native result unchanged, since there is no
+    /// builder/insert point threaded through this free function to emit an equivalent
+    /// instruction. Callers that only target current LLVM can keep calling `const_shl` directly.
+    #[must_use]
+    pub fn const_shl_or_fold(lhs: &Self, rhs: &Self) -> Self {
+        let native = Self::const_shl(lhs, rhs);
+        if !native.0.is_null() {
+            return native;
+        }
+        let Some(width) = int_const_width(lhs) else {
+            return native;
+        };
+        if width > 64 {
+            return native;
+        }
+        let ty = type_of(lhs);
+        let shift = const_int_get_zext_value(rhs);
+        if shift >= u64::from(width) {
+            return Self::get_poison(&ty);
+        }
+        let value = const_int_get_zext_value(lhs);
+        let result = mask_to_width(value.wrapping_shl(shift as u32), width);
+        const_int(&ty, result, false)
+    }
+
     /// Create a GEP (`GetElementPtr`) operation on a constant value.
     ///
     /// # Details
@@ -520,7 +675,7 @@ impl ValueRef {
     /// Returns an instance of `ValueRef`, which encapsulates the result of the `GEP` calculation. The result is a constant
     /// value determined at compile time, representing the address of the sub-element within the aggregate data structure.
     #[must_use]
-    pub fn const_gep2(ty: &TypeRef, constant_val: &Self, constant_indices: &[Self]) -> Self {
+    pub fn const_gep2(ty: &TypeRef<'_>, constant_val: &Self, constant_indices: &[Self]) -> Self {
         let mut constant_indices = constant_indices.iter().map(|v| v.0).collect::<Vec<_>>();
         let constant_indices_ptr = if constant_indices.is_empty() {
             std::ptr::null_mut()
@@ -532,11 +687,43 @@ impl ValueRef {
                 ty.get_ref(),
                 constant_val.0,
                 constant_indices_ptr,
-                *CUint::from(constant_indices.len()),
+                *CUint::try_from(constant_indices.len()).expect("value does not fit in c_uint"),
             ))
         }
     }
 
+    /// Create a GEP (`GetElementPtr`) operation on a constant value, validating vector GEP lane
+    /// counts before building it.
+    ///
+    /// # Details
+    ///
+    /// `const_gep2` passes `constant_val` and `constant_indices` straight through to
+    /// `LLVMConstGEP2`, which also supports "vector GEP": a `constant_val` that is a vector of
+    /// pointers, combined with one or more vector-typed indices, computes a per-lane address
+    /// and returns a vector of pointers. LLVM's verifier requires every vector operand of the
+    /// GEP (the base pointer vector and any vector-typed index) to share the same element
+    /// count; this function checks that up front, rather than letting a lane-count mismatch
+    /// surface later as a verifier failure, mirroring LLVM's own rule instead of silently
+    /// assuming a scalar base and scalar indices.
+    ///
+    /// # Parameters
+    ///
+    /// See `const_gep2`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GepVectorWidthMismatch`] if `constant_val` is a vector of pointers and
+    /// any vector-typed index in `constant_indices` has a different element count, or if two
+    /// vector-typed indices disagree with each other.
+    pub fn const_gep2_checked(
+        ty: &TypeRef<'_>,
+        constant_val: &Self,
+        constant_indices: &[Self],
+    ) -> Result<Self, Error> {
+        check_gep_vector_widths(constant_val, constant_indices)?;
+        Ok(Self::const_gep2(ty, constant_val, constant_indices))
+    }
+
     /// Create an in-bounds GEP (`GetElementPtr`) operation on a constant value.
     ///
     /// # Details
@@ -561,7 +748,7 @@ impl ValueRef {
     /// with the guarantee that the address is within the bounds of the object.
     #[must_use]
     pub fn const_in_bounds_gep2(
-        ty: &TypeRef,
+        ty: &TypeRef<'_>,
         constant_val: &Self,
         constant_indices: &[Self],
     ) -> Self {
@@ -576,11 +763,33 @@ impl ValueRef {
                 ty.get_ref(),
                 constant_val.0,
                 constant_indices_ptr,
-                *CUint::from(constant_indices.len()),
+                *CUint::try_from(constant_indices.len()).expect("value does not fit in c_uint"),
             ))
         }
     }
 
+    /// Create an in-bounds GEP (`GetElementPtr`) operation on a constant value, validating
+    /// vector GEP lane counts before building it.
+    ///
+    /// # Details
+    ///
+    /// See `const_gep2_checked`; this is the in-bounds counterpart, delegating to
+    /// `const_in_bounds_gep2` once the lane-count check passes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GepVectorWidthMismatch`] if `constant_val` is a vector of pointers and
+    /// any vector-typed index in `constant_indices` has a different element count, or if two
+    /// vector-typed indices disagree with each other.
+    pub fn const_in_bounds_gep2_checked(
+        ty: &TypeRef<'_>,
+        constant_val: &Self,
+        constant_indices: &[Self],
+    ) -> Result<Self, Error> {
+        check_gep_vector_widths(constant_val, constant_indices)?;
+        Ok(Self::const_in_bounds_gep2(ty, constant_val, constant_indices))
+    }
+
     /// Truncate a constant value to the specified type.
     ///
     /// # Details
@@ -601,7 +810,7 @@ impl ValueRef {
     /// Returns an instance of `ValueRef`, which encapsulates the result of the truncation. The result is a constant value
     /// determined at compile time, representing the truncated integer value.
     #[must_use]
-    pub fn const_trunc(&self, to_type: &TypeRef) -> Self {
+    pub fn const_trunc(&self, to_type: &TypeRef<'_>) -> Self {
         unsafe { Self(core::LLVMConstTrunc(self.0, to_type.get_ref())) }
     }
 
@@ -625,7 +834,7 @@ impl ValueRef {
     /// Returns an instance of `ValueRef`, which encapsulates the result of the pointer-to-integer conversion. The result
     /// is a constant value determined at compile time, representing the integer interpretation of the pointer value.
     #[must_use]
-    pub fn const_ptr_to_int(&self, to_type: &TypeRef) -> Self {
+    pub fn const_ptr_to_int(&self, to_type: &TypeRef<'_>) -> Self {
         unsafe { Self(core::LLVMConstPtrToInt(self.0, to_type.get_ref())) }
     }
 
@@ -649,7 +858,7 @@ impl ValueRef {
     /// Returns an instance of `ValueRef`, which encapsulates the result of the integer-to-pointer conversion. The result
     /// is a constant value determined at compile time, representing the pointer interpretation of the integer value.
     #[must_use]
-    pub fn const_int_to_ptr(&self, to_type: &TypeRef) -> Self {
+    pub fn const_int_to_ptr(&self, to_type: &TypeRef<'_>) -> Self {
         unsafe { Self(core::LLVMConstIntToPtr(self.0, to_type.get_ref())) }
     }
 
@@ -673,7 +882,7 @@ impl ValueRef {
     /// Returns an instance of `ValueRef`, which encapsulates the result of the bitcast. The result is a constant value
     /// determined at compile time, representing the value reinterpreted as the target type.
     #[must_use]
-    pub fn const_bit_cast(&self, to_type: &TypeRef) -> Self {
+    pub fn const_bit_cast(&self, to_type: &TypeRef<'_>) -> Self {
         unsafe { Self(core::LLVMConstBitCast(self.0, to_type.get_ref())) }
     }
 
@@ -697,7 +906,7 @@ impl ValueRef {
     /// Returns an instance of `ValueRef`, which encapsulates the result of the address space cast. The result is a constant
     /// value determined at compile time, representing the pointer value in the new address space.
     #[must_use]
-    pub fn const_addr_space_cast(&self, to_type: &TypeRef) -> Self {
+    pub fn const_addr_space_cast(&self, to_type: &TypeRef<'_>) -> Self {
         unsafe { Self(core::LLVMConstAddrSpaceCast(self.0, to_type.get_ref())) }
     }
 
@@ -723,7 +932,7 @@ impl ValueRef {
     /// Returns an instance of `ValueRef`, which encapsulates the result of the truncation or bitcast. The result is a constant
     /// value determined at compile time, representing the value either truncated to a smaller type or reinterpreted as the target type.
     #[must_use]
-    pub fn const_trunc_or_bit_cast(&self, to_type: &TypeRef) -> Self {
+    pub fn const_trunc_or_bit_cast(&self, to_type: &TypeRef<'_>) -> Self {
         unsafe { Self(core::LLVMConstTruncOrBitCast(self.0, to_type.get_ref())) }
     }
 
@@ -748,7 +957,7 @@ impl ValueRef {
     /// Returns an instance of `ValueRef`, which encapsulates the result of the pointer cast. The result is a constant value
     /// determined at compile time, representing the pointer value reinterpreted as the new type.
     #[must_use]
-    pub fn const_pointer_cast(&self, to_type: &TypeRef) -> Self {
+    pub fn const_pointer_cast(&self, to_type: &TypeRef<'_>) -> Self {
         unsafe { Self(core::LLVMConstPointerCast(self.0, to_type.get_ref())) }
     }
 
@@ -852,4 +1061,279 @@ impl ValueRef {
     pub fn block_address(function: &Self, basic_block: &BasicBlockRef) -> Self {
         unsafe { Self(core::LLVMBlockAddress(function.0, basic_block.get_ref())) }
     }
+
+    /// Collapse a redundant constant-expression chain rooted at `self` into its simplified
+    /// equivalent, mirroring the peephole folds LLVM's `InstCombine` normally applies to the
+    /// equivalent runtime instructions.
+    ///
+    /// # Details
+    ///
+    /// Constant expressions assembled one `const_*` call at a time (e.g. a `const_bit_cast` of
+    /// another `const_bit_cast`) are not folded automatically the way instructions are when a
+    /// `Builder` is available. This inspects `self`'s constant-expression opcode (via
+    /// `get_const_opcode`) and its operands (via `operand`) and rewrites four specific redundant
+    /// patterns:
+    ///
+    /// - `bitcast(bitcast(x, T1), T2)` folds to a single `bitcast(x, T2)`, or to `x` itself if
+    ///   `x`'s type and `T2` are structurally equal.
+    /// - `bitcast(gep(p, [0, 0, ...]), T)`, where every `gep` index is the constant integer `0`,
+    ///   folds to `bitcast(p, T)`, since a zero-index GEP adds no offset.
+    /// - `inttoptr(ptrtoint(p))` folds to `p` when `p`'s type and `self`'s type are structurally
+    ///   equal, and `ptrtoint(inttoptr(i))` folds to `i` when their types are structurally
+    ///   equal (which, for integer types, means their bit widths match).
+    /// - A `trunc` or `bitcast` whose source and destination types are already structurally
+    ///   equal folds to its operand unchanged.
+    ///
+    /// Each rule only fires when the operand types make it value-preserving; otherwise, or if
+    /// `self` is not a constant expression, or does not match one of these shapes, `self` is
+    /// returned unchanged.
+    ///
+    /// # Returns
+    ///
+    /// Returns the simplified `ValueRef`, or an equivalent copy of `self` if none of the rules
+    /// apply.
+    #[must_use]
+    pub fn simplify(&self) -> Self {
+        let Some(opcode) = const_expr_opcode(self) else {
+            return Self(self.0);
+        };
+        let self_ty = type_of(self);
+
+        match opcode {
+            Opcode::BitCast => {
+                let Some(operand0) = self.operand(0) else {
+                    return Self(self.0);
+                };
+                if const_expr_opcode(&operand0) == Some(Opcode::BitCast) {
+                    if let Some(inner) = operand0.operand(0) {
+                        return if type_of(&inner).structurally_equal(&self_ty) {
+                            inner
+                        } else {
+                            inner.const_bit_cast(&self_ty)
+                        };
+                    }
+                }
+                if const_expr_opcode(&operand0) == Some(Opcode::GetElementPtr)
+                    && is_all_zero_index_gep(&operand0)
+                {
+                    if let Some(base) = operand0.operand(0) {
+                        return base.const_bit_cast(&self_ty);
+                    }
+                }
+                if type_of(&operand0).structurally_equal(&self_ty) {
+                    return operand0;
+                }
+                Self(self.0)
+            }
+            Opcode::Trunc => {
+                let Some(operand0) = self.operand(0) else {
+                    return Self(self.0);
+                };
+                if type_of(&operand0).structurally_equal(&self_ty) {
+                    operand0
+                } else {
+                    Self(self.0)
+                }
+            }
+            Opcode::IntToPtr => {
+                let Some(operand0) = self.operand(0) else {
+                    return Self(self.0);
+                };
+                if const_expr_opcode(&operand0) == Some(Opcode::PtrToInt) {
+                    if let Some(p) = operand0.operand(0) {
+                        if type_of(&p).structurally_equal(&self_ty) {
+                            return p;
+                        }
+                    }
+                }
+                Self(self.0)
+            }
+            Opcode::PtrToInt => {
+                let Some(operand0) = self.operand(0) else {
+                    return Self(self.0);
+                };
+                if const_expr_opcode(&operand0) == Some(Opcode::IntToPtr) {
+                    if let Some(i) = operand0.operand(0) {
+                        if type_of(&i).structurally_equal(&self_ty) {
+                            return i;
+                        }
+                    }
+                }
+                Self(self.0)
+            }
+            _ => Self(self.0),
+        }
+    }
+}
+
+/// The bit width of `val`'s type if it is a plain integer constant, used by the `_or_fold`
+/// constant-expression fallbacks above to know how to mask/extend their computed result.
+/// Returns `None` for any other kind of value, which those fallbacks treat as "defer to the
+/// native constexpr result", since this crate has no builder plumbed through these free
+/// constant-expression constructors to emit an instruction instead.
+fn int_const_width(val: &ValueRef) -> Option<u32> {
+    type_of(val).as_int().map(|ty| ty.get_int_type_width())
+}
+
+/// Masks `v` down to its low `width` bits, leaving it unchanged once `width` reaches 64.
+fn mask_to_width(v: u64, width: u32) -> u64 {
+    if width >= 64 {
+        v
+    } else {
+        v & ((1_u64 << width) - 1)
+    }
+}
+
+/// The vector element count of `ty` if it is a (fixed or scalable) vector type, `None` for any
+/// scalar type.
+fn vector_width(ty: &TypeRef<'_>) -> Option<u32> {
+    ty.as_vector().map(|vector_ty| vector_ty.get_vector_size())
+}
+
+/// Checks that `constant_val` and `constant_indices` agree on a single vector lane count for a
+/// `const_gep2_checked`/`const_in_bounds_gep2_checked` call: the base pointer vector (if any)
+/// and every vector-typed index must all have the same element count, mirroring LLVM's
+/// verifier rule for vector GEP.
+fn check_gep_vector_widths(
+    constant_val: &ValueRef,
+    constant_indices: &[ValueRef],
+) -> Result<(), Error> {
+    let mut width = vector_width(&type_of(constant_val));
+    for index in constant_indices {
+        let Some(index_width) = vector_width(&type_of(index)) else {
+            continue;
+        };
+        match width {
+            None => width = Some(index_width),
+            Some(expected) if expected != index_width => {
+                return Err(Error::GepVectorWidthMismatch {
+                    expected,
+                    found: index_width,
+                });
+            }
+            Some(_) => {}
+        }
+    }
+    Ok(())
+}
+
+/// The constant-expression opcode of `val`, or `None` if `val` is not a `ConstantExpr` at all
+/// (e.g. a plain `ConstantInt`, an instruction, or any other value kind). Used by `simplify` to
+/// decide whether it is safe to trust `get_const_opcode`'s result, which is only meaningful for
+/// constant expressions.
+fn const_expr_opcode(val: &ValueRef) -> Option<Opcode> {
+    (get_value_kind(val) == ValueKind::ConstantExpr).then(|| val.get_const_opcode())
+}
+
+/// Whether `gep` is a `GetElementPtr` constant expression all of whose indices (every operand
+/// after the base pointer at operand `0`) are the constant integer `0`, the shape `simplify`
+/// looks for to fold `bitcast(gep(p, [0, 0, ...]), T)` down to `bitcast(p, T)`.
+fn is_all_zero_index_gep(gep: &ValueRef) -> bool {
+    let num_operands = gep.num_operands();
+    if num_operands < 2 {
+        return false;
+    }
+    (1..num_operands).all(|index| {
+        gep.operand(index).is_some_and(|idx| {
+            get_value_kind(&idx) == ValueKind::ConstantInt && const_int_get_zext_value(&idx) == 0
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::int::IntTypeRef;
+    use crate::core::types::sequential::SequentialTypeRef;
+
+    fn ptr_to<'ctx>(element_type: &TypeRef<'ctx>) -> TypeRef<'ctx> {
+        TypeRef::from(SequentialTypeRef::pointer_type(element_type, 0))
+    }
+
+    #[test]
+    fn simplify_nested_bitcast_folds_to_original_value_when_types_match() {
+        let i8_ty = TypeRef::from(IntTypeRef::int8_type());
+        let i32_ty = TypeRef::from(IntTypeRef::int32_type());
+        let ptr_i8 = ptr_to(&i8_ty);
+        let ptr_i32 = ptr_to(&i32_ty);
+
+        let base = ValueRef::const_pointer_null(&ptr_i8);
+        let outer = base.const_bit_cast(&ptr_i32).const_bit_cast(&ptr_i8);
+
+        assert_eq!(outer.simplify().get_ref(), base.get_ref());
+    }
+
+    #[test]
+    fn simplify_nested_bitcast_folds_to_single_bitcast_when_types_differ() {
+        let i8_ty = TypeRef::from(IntTypeRef::int8_type());
+        let i32_ty = TypeRef::from(IntTypeRef::int32_type());
+        let i64_ty = TypeRef::from(IntTypeRef::int64_type());
+        let ptr_i8 = ptr_to(&i8_ty);
+        let ptr_i32 = ptr_to(&i32_ty);
+        let ptr_i64 = ptr_to(&i64_ty);
+
+        let base = ValueRef::const_pointer_null(&ptr_i8);
+        let outer = base.const_bit_cast(&ptr_i32).const_bit_cast(&ptr_i64);
+        let simplified = outer.simplify();
+
+        assert_eq!(simplified.get_const_opcode(), Opcode::BitCast);
+        assert_eq!(
+            simplified.operand(0).expect("bitcast has an operand").get_ref(),
+            base.get_ref()
+        );
+    }
+
+    #[test]
+    fn simplify_zero_index_gep_under_bitcast_skips_straight_to_base() {
+        let i8_ty = TypeRef::from(IntTypeRef::int8_type());
+        let i32_ty = TypeRef::from(IntTypeRef::int32_type());
+        let ptr_i8 = ptr_to(&i8_ty);
+        let array_ty = TypeRef::from(SequentialTypeRef::array_type2(&i32_ty, 4));
+        let ptr_array = ptr_to(&array_ty);
+
+        let base = ValueRef::const_pointer_null(&ptr_array);
+        let zero_a = const_int(&i32_ty, 0, false);
+        let zero_b = const_int(&i32_ty, 0, false);
+        let gep = ValueRef::const_gep2(&array_ty, &base, &[zero_a, zero_b]);
+        let outer = gep.const_bit_cast(&ptr_i8);
+        let simplified = outer.simplify();
+
+        assert_eq!(simplified.get_const_opcode(), Opcode::BitCast);
+        assert_eq!(
+            simplified.operand(0).expect("bitcast has an operand").get_ref(),
+            base.get_ref()
+        );
+    }
+
+    #[test]
+    fn simplify_inttoptr_of_ptrtoint_folds_to_original_pointer() {
+        let i8_ty = TypeRef::from(IntTypeRef::int8_type());
+        let i64_ty = TypeRef::from(IntTypeRef::int64_type());
+        let ptr_i8 = ptr_to(&i8_ty);
+
+        let base = ValueRef::const_pointer_null(&ptr_i8);
+        let roundtrip = base.const_ptr_to_int(&i64_ty).const_int_to_ptr(&ptr_i8);
+
+        assert_eq!(roundtrip.simplify().get_ref(), base.get_ref());
+    }
+
+    #[test]
+    fn simplify_ptrtoint_of_inttoptr_folds_to_original_integer() {
+        let i8_ty = TypeRef::from(IntTypeRef::int8_type());
+        let i64_ty = TypeRef::from(IntTypeRef::int64_type());
+        let ptr_i8 = ptr_to(&i8_ty);
+
+        let original = const_int(&i64_ty, 42, false);
+        let roundtrip = original.const_int_to_ptr(&ptr_i8).const_ptr_to_int(&i64_ty);
+
+        assert_eq!(roundtrip.simplify().get_ref(), original.get_ref());
+    }
+
+    #[test]
+    fn simplify_is_a_no_op_for_a_value_that_is_not_a_constant_expression() {
+        let i32_ty = TypeRef::from(IntTypeRef::int32_type());
+        let plain = const_int(&i32_ty, 7, false);
+
+        assert_eq!(plain.simplify().get_ref(), plain.get_ref());
+    }
 }
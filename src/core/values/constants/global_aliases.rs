@@ -1,8 +1,9 @@
+use super::global_variables::ThreadLocalMode;
 use super::ValueRef;
 use crate::core::module::ModuleRef;
 use crate::core::types::TypeRef;
 use crate::core::AddressSpace;
-use crate::{CString, GetRef};
+use crate::{CInt, CString, GetRef};
 use llvm_sys::core;
 
 /// Adds a `GlobalAlias` to the module.
@@ -25,12 +26,12 @@ use llvm_sys::core;
 #[must_use]
 pub fn add_alias2(
     module: &ModuleRef,
-    value_ty: &TypeRef,
+    value_ty: &TypeRef<'_>,
     addr_space: &AddressSpace,
     aliasee: &ValueRef,
     name: &str,
 ) -> ValueRef {
-    let c_string = CString::from(name);
+    let c_string = CString::try_from(name).expect("string contains an interior NUL byte");
     unsafe {
         let alias = core::LLVMAddAlias2(
             module.get_ref(),
@@ -58,7 +59,7 @@ pub fn add_alias2(
 /// no such alias is found within the module.
 #[must_use]
 pub fn get_named_global_alias(module: &ModuleRef, name: &str) -> Option<ValueRef> {
-    let c_string = CString::from(name);
+    let c_string = CString::try_from(name).expect("string contains an interior NUL byte");
     unsafe {
         let alias = core::LLVMGetNamedGlobalAlias(
             module.get_ref(),
@@ -172,3 +173,60 @@ pub fn alias_set_aliasee(val: &ValueRef, new_aliasee: &ValueRef) {
         core::LLVMAliasSetAliasee(val.0, new_aliasee.0);
     }
 }
+
+/// Determines if the alias is thread-local.
+///
+/// This function wraps the `LLVMIsThreadLocal` function from the LLVM core library. It checks whether
+/// the alias represented by `ValueRef` is marked as thread-local. `LLVMIsThreadLocal` operates on any
+/// `GlobalValue`, so it applies to aliases exactly as it does to global variables (see
+/// `global_variables::is_thread_local`).
+///
+/// # Returns
+///
+/// Returns `true` if the alias is thread-local, otherwise returns `false`.
+#[must_use]
+pub fn is_thread_local(val: &ValueRef) -> bool {
+    unsafe { core::LLVMIsThreadLocal(val.get_ref()) != 0 }
+}
+
+/// Sets whether the alias is thread-local.
+///
+/// This function wraps the `LLVMSetThreadLocal` function from the LLVM core library. It marks the alias
+/// represented by `ValueRef` as either thread-local or not, based on the provided boolean value.
+///
+/// # Parameters
+///
+/// - `is_thread_local`: A boolean value. If `true`, the alias is marked as thread-local. If `false`, it is not thread-local.
+pub fn set_thread_local(val: &ValueRef, is_thread_local: bool) {
+    unsafe {
+        core::LLVMSetThreadLocal(val.get_ref(), *CInt::from(is_thread_local));
+    }
+}
+
+/// Retrieves the thread-local storage (TLS) mode of the alias.
+///
+/// This function wraps the `LLVMGetThreadLocalMode` function from the LLVM core library. It returns the
+/// thread-local mode of the alias represented by `ValueRef`. This lets callers emit e.g. a `localexec` TLS
+/// alias of a thread-local global, matching the aliasee's own TLS model.
+///
+/// # Returns
+///
+/// Returns a [`ThreadLocalMode`] enum value representing the thread-local mode of the alias.
+#[must_use]
+pub fn get_thread_local_mode(val: &ValueRef) -> ThreadLocalMode {
+    unsafe { core::LLVMGetThreadLocalMode(val.get_ref()).into() }
+}
+
+/// Sets the thread-local storage (TLS) mode for the alias.
+///
+/// This function wraps the `LLVMSetThreadLocalMode` function from the LLVM core library. It configures
+/// the thread-local mode for the alias represented by `ValueRef`.
+///
+/// # Parameters
+///
+/// - `mode`: A [`ThreadLocalMode`] enum value representing the desired thread-local mode.
+pub fn set_thread_local_mode(val: &ValueRef, mode: ThreadLocalMode) {
+    unsafe {
+        core::LLVMSetThreadLocalMode(val.get_ref(), mode.into());
+    }
+}
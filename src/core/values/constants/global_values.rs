@@ -1,28 +1,237 @@
+//! Functions in this group model `ValueRef` instances that correspond
+//! to `GlobalValue`, covering linkage, visibility, DLL storage class,
+//! unnamed-addr, section/alignment, and comdat accessors shared by
+//! globals, functions, and aliases.
+
 use super::ValueRef;
 use crate::core::module::{MetadataRef, ModuleRef};
 use crate::core::types::TypeRef;
+use crate::core::values::general::get_value_name;
 use crate::core::{DLLStorageClass, Linkage, UnnamedAddr, Visibility};
 use crate::{CStr, CString, CUint, GetRef};
+use llvm_sys::comdat::{
+    LLVMGetComdat, LLVMGetComdatSelectionKind, LLVMGetOrInsertComdat, LLVMSetComdat,
+    LLVMSetComdatSelectionKind,
+};
 use llvm_sys::core;
-use llvm_sys::prelude::LLVMValueMetadataEntry;
+use llvm_sys::prelude::{LLVMComdatRef, LLVMValueMetadataEntry};
+use llvm_sys::LLVMComdatSelectionKind;
+use std::collections::BTreeMap;
+use std::ops::BitOr;
 
-/// Wrapper for `LLVMValueMetadataEntry`
-#[derive(Debug)]
-pub struct ValueMetadataEntry(LLVMValueMetadataEntry);
+/// Wrapper for `LLVMComdatRef`.
+///
+/// A `Comdat` groups global objects so the linker folds or discards them together, rather than
+/// individually, when resolving multiple definitions across translation units (COFF "selectany"
+/// sections and ELF group sections are both modeled this way). A `Comdat` is owned by the module
+/// it was obtained from; see [`get_or_insert_comdat`].
+#[derive(Debug, Clone, Copy)]
+pub struct Comdat(LLVMComdatRef);
 
-impl From<LLVMValueMetadataEntry> for ValueMetadataEntry {
-    fn from(value: LLVMValueMetadataEntry) -> Self {
+impl From<LLVMComdatRef> for Comdat {
+    fn from(value: LLVMComdatRef) -> Self {
         Self(value)
     }
 }
 
-impl GetRef for ValueMetadataEntry {
-    type RawRef = LLVMValueMetadataEntry;
+impl GetRef for Comdat {
+    type RawRef = LLVMComdatRef;
     fn get_ref(&self) -> Self::RawRef {
         self.0
     }
 }
 
+/// Conflict-resolution rule used to decide which of several identically-named COMDAT groups the
+/// linker keeps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ComdatSelectionKind {
+    /// The linker may choose any COMDAT with this name.
+    Any,
+    /// The data referenced by the COMDAT must be the same across all definitions.
+    ExactMatch,
+    /// The linker chooses the largest COMDAT with this name.
+    Largest,
+    /// No deduplication is performed; every definition is kept.
+    NoDeduplicate,
+    /// The data referenced by the COMDAT must be the same size across all definitions.
+    SameSize,
+}
+
+impl From<LLVMComdatSelectionKind> for ComdatSelectionKind {
+    fn from(kind: LLVMComdatSelectionKind) -> Self {
+        match kind {
+            LLVMComdatSelectionKind::LLVMAnyComdatSelectionKind => Self::Any,
+            LLVMComdatSelectionKind::LLVMExactMatchComdatSelectionKind => Self::ExactMatch,
+            LLVMComdatSelectionKind::LLVMLargestComdatSelectionKind => Self::Largest,
+            LLVMComdatSelectionKind::LLVMNoDeduplicateComdatSelectionKind => Self::NoDeduplicate,
+            LLVMComdatSelectionKind::LLVMSameSizeComdatSelectionKind => Self::SameSize,
+        }
+    }
+}
+
+impl From<ComdatSelectionKind> for LLVMComdatSelectionKind {
+    fn from(kind: ComdatSelectionKind) -> Self {
+        match kind {
+            ComdatSelectionKind::Any => Self::LLVMAnyComdatSelectionKind,
+            ComdatSelectionKind::ExactMatch => Self::LLVMExactMatchComdatSelectionKind,
+            ComdatSelectionKind::Largest => Self::LLVMLargestComdatSelectionKind,
+            ComdatSelectionKind::NoDeduplicate => Self::LLVMNoDeduplicateComdatSelectionKind,
+            ComdatSelectionKind::SameSize => Self::LLVMSameSizeComdatSelectionKind,
+        }
+    }
+}
+
+/// Returns the Comdat in the module with the specified name, creating it if it doesn't already
+/// exist.
+///
+/// ## Details
+///
+/// This function wraps the `LLVMGetOrInsertComdat` function from the LLVM core library.
+///
+/// # Parameters
+///
+/// - `module`: The `ModuleRef` to look up or create the Comdat in.
+/// - `name`: The name of the Comdat group.
+///
+/// # Returns
+///
+/// Returns the `Comdat` registered under `name` in `module`.
+#[must_use]
+pub fn get_or_insert_comdat(module: &ModuleRef, name: &str) -> Comdat {
+    let c_name = CString::try_from(name).expect("string contains an interior NUL byte");
+    unsafe { Comdat::from(LLVMGetOrInsertComdat(module.get_ref(), c_name.as_ptr())) }
+}
+
+/// Get the Comdat assigned to the given global object.
+///
+/// ## Details
+///
+/// This function wraps the `LLVMGetComdat` function from the LLVM core library.
+///
+/// # Returns
+///
+/// Returns `Some(Comdat)` if the global value has a Comdat assigned, otherwise `None`.
+#[must_use]
+pub fn get_comdat(val: &ValueRef) -> Option<Comdat> {
+    let comdat = unsafe { LLVMGetComdat(val.get_ref()) };
+    if comdat.is_null() {
+        None
+    } else {
+        Some(Comdat::from(comdat))
+    }
+}
+
+/// Assign the Comdat to the given global object.
+///
+/// ## Details
+///
+/// This function wraps the `LLVMSetComdat` function from the LLVM core library.
+///
+/// # Parameters
+///
+/// - `comdat`: The `Comdat` to assign to the global value.
+pub fn set_comdat(val: &ValueRef, comdat: &Comdat) {
+    unsafe {
+        LLVMSetComdat(val.get_ref(), comdat.get_ref());
+    }
+}
+
+/// Get the conflict-resolution selection kind for the Comdat.
+///
+/// ## Details
+///
+/// This function wraps the `LLVMGetComdatSelectionKind` function from the LLVM core library.
+///
+/// # Returns
+///
+/// Returns the `ComdatSelectionKind` for `comdat`.
+#[must_use]
+pub fn get_comdat_selection_kind(comdat: &Comdat) -> ComdatSelectionKind {
+    unsafe { ComdatSelectionKind::from(LLVMGetComdatSelectionKind(comdat.get_ref())) }
+}
+
+/// Set the conflict-resolution selection kind for the Comdat.
+///
+/// ## Details
+///
+/// This function wraps the `LLVMSetComdatSelectionKind` function from the LLVM core library.
+///
+/// # Parameters
+///
+/// - `kind`: The `ComdatSelectionKind` to assign to `comdat`.
+pub fn set_comdat_selection_kind(comdat: &Comdat, kind: ComdatSelectionKind) {
+    unsafe {
+        LLVMSetComdatSelectionKind(comdat.get_ref(), kind.into());
+    }
+}
+
+/// Get the directory component of the source location attached to this value's debug info.
+///
+/// ## Details
+///
+/// This function wraps the `LLVMGetDebugLocDirectory` function from the LLVM core library. It
+/// applies to any value carrying a debug location: an instruction's `!dbg` attachment, a global
+/// variable's `DebugInfo`, or a function's subprogram.
+///
+/// # Returns
+///
+/// Returns `Some(String)` with the directory of the attached source location, or `None` if `val`
+/// has no debug location.
+#[must_use]
+pub fn get_debug_loc_directory(val: &ValueRef) -> Option<String> {
+    let mut length: u32 = 0;
+    unsafe {
+        let c_str = core::LLVMGetDebugLocDirectory(val.get_ref(), &mut length);
+        if c_str.is_null() {
+            None
+        } else {
+            Some(CStr::new(c_str).to_string())
+        }
+    }
+}
+
+/// Get the filename component of the source location attached to this value's debug info.
+///
+/// ## Details
+///
+/// This function wraps the `LLVMGetDebugLocFilename` function from the LLVM core library. It
+/// applies to any value carrying a debug location: an instruction's `!dbg` attachment, a global
+/// variable's `DebugInfo`, or a function's subprogram.
+///
+/// # Returns
+///
+/// Returns `Some(String)` with the filename of the attached source location, or `None` if `val`
+/// has no debug location.
+#[must_use]
+pub fn get_debug_loc_filename(val: &ValueRef) -> Option<String> {
+    let mut length: u32 = 0;
+    unsafe {
+        let c_str = core::LLVMGetDebugLocFilename(val.get_ref(), &mut length);
+        if c_str.is_null() {
+            None
+        } else {
+            Some(CStr::new(c_str).to_string())
+        }
+    }
+}
+
+/// Get the line number of the source location attached to this value's debug info.
+///
+/// ## Details
+///
+/// This function wraps the `LLVMGetDebugLocLine` function from the LLVM core library. It applies
+/// to any value carrying a debug location: an instruction's `!dbg` attachment, a global
+/// variable's `DebugInfo`, or a function's subprogram.
+///
+/// # Returns
+///
+/// Returns the line number of the attached source location, or `0` if `val` has no debug
+/// location.
+#[must_use]
+pub fn get_debug_loc_line(val: &ValueRef) -> u32 {
+    unsafe { core::LLVMGetDebugLocLine(val.get_ref()) }
+}
+
 /// Get the module that contains the global value.
 ///
 /// ## Details
@@ -64,20 +273,16 @@ pub fn is_declaration(val: &ValueRef) -> bool {
 ///
 /// ## Details
 ///
-/// Sets the linkage type for a global value.
+/// Retrieves the linkage type for a global value.
 ///
-/// This function wraps the `LLVMSetLinkage` function from the LLVM core library. It configures the linkage type
+/// This function wraps the `LLVMGetLinkage` function from the LLVM core library. It returns the linkage type
 /// for the global value represented by `ValueRef`. The linkage type determines how the symbol is treated during the
 /// linking process, particularly in relation to how it can be combined with other symbols and whether it is visible
 /// outside of the module.
 ///
-/// # Parameters
+/// # Returns
 ///
-/// - `linkage`: A `Linkage` enum value that specifies the linkage type for the global value. Common linkage types include:
-///   - `ExternalLinkage`: The symbol is visible to other modules and can be linked against.
-///   - `InternalLinkage`: The symbol is only visible within the current module.
-///   - `PrivateLinkage`: The symbol is local to the file and not exposed to other modules.
-///   - `LinkOnceODRLinkage`: Ensures that the symbol is defined only once across all modules, complying with the One Definition Rule (ODR).
+/// Returns a `Linkage` enum value representing the linkage type of the global value.
 #[must_use]
 pub fn get_linkage(val: &ValueRef) -> Linkage {
     unsafe { crate::core::Linkage::from(core::LLVMGetLinkage(val.get_ref())) }
@@ -87,7 +292,20 @@ pub fn get_linkage(val: &ValueRef) -> Linkage {
 ///
 /// ## Details
 ///
+/// Sets the linkage type for a global value.
+///
+/// This function wraps the `LLVMSetLinkage` function from the LLVM core library. It configures the linkage type
+/// for the global value represented by `ValueRef`. The linkage type determines how the symbol is treated during the
+/// linking process, particularly in relation to how it can be combined with other symbols and whether it is visible
+/// outside of the module.
+///
+/// # Parameters
 ///
+/// - `linkage`: A `Linkage` enum value that specifies the linkage type for the global value. Common linkage types include:
+///   - `ExternalLinkage`: The symbol is visible to other modules and can be linked against.
+///   - `InternalLinkage`: The symbol is only visible within the current module.
+///   - `PrivateLinkage`: The symbol is local to the file and not exposed to other modules.
+///   - `LinkOnceODRLinkage`: Ensures that the symbol is defined only once across all modules, complying with the One Definition Rule (ODR).
 pub fn set_linkage(val: &ValueRef, linkage: Linkage) {
     unsafe { core::LLVMSetLinkage(val.get_ref(), linkage.into()) }
 }
@@ -133,7 +351,7 @@ pub fn get_section(val: &ValueRef) -> Option<String> {
 ///
 /// - `section`: A string slice (`&str`) representing the name of the section where the global value should be placed.
 pub fn set_section(val: &ValueRef, section: &str) {
-    let c_section = CString::from(section);
+    let c_section = CString::try_from(section).expect("string contains an interior NUL byte");
     unsafe {
         core::LLVMSetSection(val.get_ref(), c_section.as_ptr());
     }
@@ -284,7 +502,7 @@ pub fn set_unnamed_address(val: &ValueRef, unnamed_addr: UnnamedAddr) {
 ///
 /// Returns a `TypeRef` representing the type of the global value.
 #[must_use]
-pub fn get_value_type(val: &ValueRef) -> TypeRef {
+pub fn get_value_type(val: &ValueRef) -> TypeRef<'_> {
     unsafe { TypeRef::from(core::LLVMGlobalGetValueType(val.get_ref())) }
 }
 
@@ -323,7 +541,7 @@ pub fn get_alignment(val: &ValueRef) -> u32 {
 /// - `bytes`: A `u32` value representing the desired alignment in bytes. This value must be a power of two.
 pub fn set_alignment(val: &ValueRef, bytes: u32) {
     unsafe {
-        core::LLVMSetAlignment(val.get_ref(), *CUint::from(bytes));
+        core::LLVMSetAlignment(val.get_ref(), *CUint::try_from(bytes).expect("value does not fit in c_uint"));
     }
 }
 
@@ -364,7 +582,7 @@ pub fn global_set_metadata(val: &ValueRef, kind: u32, md: &MetadataRef) {
 /// - `kind`: A `u32` representing the kind of metadata to be erased. The kind ID specifies the category or type of the metadata.
 pub fn global_erase_metadata(val: &ValueRef, kind: u32) {
     unsafe {
-        core::LLVMGlobalEraseMetadata(val.get_ref(), *CUint::from(kind));
+        core::LLVMGlobalEraseMetadata(val.get_ref(), *CUint::try_from(kind).expect("value does not fit in c_uint"));
     }
 }
 
@@ -383,124 +601,456 @@ pub fn global_clear_metadata(val: &ValueRef) {
     }
 }
 
-/// Destroys value metadata entries.
+/// Owning handle to the array of `LLVMValueMetadataEntry` returned by
+/// [`global_copy_all_metadata`].
 ///
-/// ## Panics
-/// This function is purely informative and panics with a message about the call
-/// being unavailable. Since there are no cases in which it can be called in
-/// safe code. For raw access, if there is such a need, must be called
-/// `LLVMDisposeValueMetadataEntries` directly.
-pub fn dispose_value_metadata_entries(_entries: &[ValueMetadataEntry]) {
-    unreachable!("LLVMDisposeValueMetadataEntries is unsafe adn restricted to operated to operate directly for safe code");
+/// ## Details
+///
+/// `LLVMGlobalCopyAllMetadata` hands back a heap array that must eventually be freed with
+/// `LLVMDisposeValueMetadataEntries`; the raw `LLVMValueMetadataEntriesGetKind`/
+/// `LLVMValueMetadataEntriesGetMetadata` accessors then index back into that same array. Keeping
+/// the pointer and entry count alive for as long as those accessors are used, and disposing of it
+/// exactly once, is the caller's responsibility in the C API. `ValueMetadataEntries` owns the
+/// array for its whole lifetime and frees it in `Drop`, so indexing past the end is the only way
+/// left to misuse it, and that is checked.
+#[derive(Debug)]
+pub struct ValueMetadataEntries {
+    entries: *mut LLVMValueMetadataEntry,
+    num_entries: usize,
+}
+
+impl Drop for ValueMetadataEntries {
+    fn drop(&mut self) {
+        if !self.entries.is_null() {
+            unsafe {
+                core::LLVMDisposeValueMetadataEntries(self.entries);
+            }
+        }
+    }
+}
+
+impl ValueMetadataEntries {
+    /// Returns the number of metadata entries.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.num_entries
+    }
+
+    /// Returns `true` if there are no metadata entries.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.num_entries == 0
+    }
+
+    /// Returns the metadata kind ID of the entry at `index`.
+    ///
+    /// ## Details
+    ///
+    /// This function wraps the `LLVMValueMetadataEntriesGetKind` function from the LLVM core
+    /// library.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= self.len()`.
+    #[must_use]
+    pub fn kind(&self, index: usize) -> u32 {
+        assert!(
+            index < self.num_entries,
+            "metadata entry index out of bounds"
+        );
+        unsafe { core::LLVMValueMetadataEntriesGetKind(self.entries, *CUint::try_from(index as u32).expect("value does not fit in c_uint")) }
+    }
+
+    /// Returns the underlying metadata node of the entry at `index`.
+    ///
+    /// ## Details
+    ///
+    /// This function wraps the `LLVMValueMetadataEntriesGetMetadata` function from the LLVM core
+    /// library.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= self.len()`.
+    #[must_use]
+    pub fn metadata(&self, index: usize) -> MetadataRef {
+        assert!(
+            index < self.num_entries,
+            "metadata entry index out of bounds"
+        );
+        unsafe {
+            MetadataRef::from(core::LLVMValueMetadataEntriesGetMetadata(
+                self.entries,
+                *CUint::try_from(index as u32).expect("value does not fit in c_uint"),
+            ))
+        }
+    }
+
+    /// Returns an iterator over `(kind, metadata)` pairs for every entry, in entry order.
+    #[must_use]
+    pub fn iter(&self) -> ValueMetadataEntriesIter<'_> {
+        ValueMetadataEntriesIter {
+            entries: self,
+            index: 0,
+        }
+    }
+
+    /// Groups every attached metadata node by its kind ID.
+    ///
+    /// ## Details
+    ///
+    /// Since LLVM allows multiple metadata attachments of the same kind on a single value, a
+    /// plain `kind -> metadata` map would silently drop all but one attachment per kind; this
+    /// collects every attachment into a `Vec` per kind instead.
+    #[must_use]
+    pub fn by_kind(&self) -> BTreeMap<u32, Vec<MetadataRef>> {
+        let mut map = BTreeMap::new();
+        for (kind, metadata) in self.iter() {
+            map.entry(kind).or_default().push(metadata);
+        }
+        map
+    }
 }
 
-/// Retrieves an array of metadata entries representing the metadata attached to  this value.
+/// Iterator over the `(kind, metadata)` pairs of a [`ValueMetadataEntries`].
+#[derive(Debug)]
+pub struct ValueMetadataEntriesIter<'a> {
+    entries: &'a ValueMetadataEntries,
+    index: usize,
+}
+
+impl Iterator for ValueMetadataEntriesIter<'_> {
+    type Item = (u32, MetadataRef);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.entries.len() {
+            return None;
+        }
+        let item = (
+            self.entries.kind(self.index),
+            self.entries.metadata(self.index),
+        );
+        self.index += 1;
+        Some(item)
+    }
+}
+
+/// Retrieves the metadata entries representing the metadata attached to this value.
 ///
 /// ## Details
 ///
-/// Copies all metadata attached to a global value and returns it as a vector of `ValueMetadataEntry`.
-///
-/// This function wraps the `LLVMGlobalCopyAllMetadata` function from the LLVM core library. It retrieves all metadata
-/// entries associated with the global value represented by `ValueRef` and returns them as a vector of `ValueMetadataEntry`.
-/// Metadata in LLVM is used to attach additional information to various constructs, such as functions or global variables,
-/// which can be useful for debugging, optimization, or other purposes.
-///
-/// After copying the metadata entries, the function ensures that any allocated memory for the metadata entries is correctly
-/// freed by calling ``LLVMDisposeValueMetadataEntries``.
+/// This function wraps the `LLVMGlobalCopyAllMetadata` function from the LLVM core library. It
+/// retrieves all metadata entries associated with the global value represented by `ValueRef` and
+/// returns them as an owning [`ValueMetadataEntries`], which disposes of the underlying LLVM
+/// array when dropped.
 ///
 /// # Returns
 ///
-/// Returns a `Vec<ValueMetadataEntry>` containing all metadata entries attached to the global value. If no metadata is
-/// attached, an empty vector is returned.
+/// Returns a `ValueMetadataEntries` containing all metadata entries attached to the global value.
+/// If no metadata is attached, it is empty.
 #[must_use]
-pub fn global_copy_all_metadata(val: &ValueRef) -> Vec<ValueMetadataEntry> {
+pub fn global_copy_all_metadata(val: &ValueRef) -> ValueMetadataEntries {
     let mut num_entries: usize = 0;
     let entries_ptr = unsafe { core::LLVMGlobalCopyAllMetadata(val.get_ref(), &mut num_entries) };
-
-    if entries_ptr.is_null() {
-        return Vec::new();
-    }
-    let entries_slice = unsafe { std::slice::from_raw_parts(entries_ptr, num_entries) };
-
-    let entries = entries_slice
-        .iter()
-        .map(|&entry| ValueMetadataEntry::from(entry))
-        .collect::<Vec<_>>();
-
-    // Free allocated memory
-    unsafe {
-        core::LLVMDisposeValueMetadataEntries(entries_ptr);
+    ValueMetadataEntries {
+        entries: entries_ptr,
+        num_entries: if entries_ptr.is_null() {
+            0
+        } else {
+            num_entries
+        },
     }
-
-    entries
 }
 
-/// Returns the kind of a value metadata entry at a specific index.
+/// Computes the symbol name used to derive a global value's ThinLTO
+/// [`GUID`](get_guid), matching `llvm::GlobalValue::getGlobalIdentifier()`.
 ///
 /// ## Details
 ///
-/// Retrieves the metadata kind ID for a specific entry in a list of value metadata entries.
-///
-/// This function wraps the `LLVMValueMetadataEntriesGetKind` function from the LLVM core library. It retrieves
-/// the kind ID of the metadata entry at the specified index within the provided vector of `ValueMetadataEntry`.
-/// Metadata kinds in LLVM are used to categorize the type of metadata, allowing different kinds of information
-/// to be attached to values.
-///
-/// # Parameters
-///
-/// - `value_metadata_entries`: A vector of `ValueMetadataEntry` from which the metadata kind ID will be retrieved.
-/// - `index`: The index of the metadata entry within the vector for which the kind ID is requested.
+/// For externally-visible linkage the identifier is just the value's name. Local linkage
+/// (`InternalLinkage`/`PrivateLinkage`) can legally reuse the same name across translation units,
+/// so in that case the identifier is made module-unique by prefixing the parent module's source
+/// file name, exactly as `llvm::GlobalValue::getGlobalIdentifier()` does.
 ///
 /// # Returns
 ///
-/// Returns a `u32` representing the metadata kind ID for the specified entry.
-///
-/// # Panics
-///
-/// The function may panic if the provided index is out of bounds for the vector, depending on how the underlying
-/// LLVM function handles invalid indices.
+/// Returns the computed identifier string, or the empty string if `val` has no name.
 #[must_use]
-pub fn value_metadata_entries_get_kind(
-    value_metadata_entries: &[ValueMetadataEntry],
-    index: u32,
-) -> u32 {
-    let entries_ptr = crate::to_mut_ptr!(value_metadata_entries);
-    unsafe { core::LLVMValueMetadataEntriesGetKind(entries_ptr, *CUint::from(index)) }
+pub fn get_global_identifier(val: &ValueRef) -> String {
+    let name = get_value_name(val).unwrap_or_default();
+    let is_local_linkage = matches!(
+        get_linkage(val),
+        Linkage::InternalLinkage | Linkage::PrivateLinkage
+    );
+    if !is_local_linkage {
+        return name;
+    }
+    match get_global_parent(val).get_source_file_name() {
+        Some(file_name) if !file_name.is_empty() => format!("{file_name}:{name}"),
+        _ => name,
+    }
 }
 
-/// Returns the underlying metadata node of a value metadata entry at a specific index.
+/// Computes the 64-bit ThinLTO global unique identifier (GUID) for a global value.
 ///
 /// ## Details
 ///
-/// Retrieves the metadata reference for a specific entry in a list of value metadata entries.
-///
-/// This function wraps the `LLVMValueMetadataEntriesGetMetadata` function from the LLVM core library. It retrieves
-/// the `MetadataRef` associated with the metadata entry at the specified index within the provided vector of `ValueMetadataEntry`.
-/// This allows you to access the metadata attached to a global value or other LLVM constructs.
-///
-/// # Parameters
-///
-/// - `value_metadata_entries`: A vector of `ValueMetadataEntry` from which the metadata reference will be retrieved.
-/// - `index`: The index of the metadata entry within the vector for which the metadata reference is requested.
+/// Matches `llvm::GlobalValue::getGUID()`: the GUID is the low 64 bits (the first 8 bytes,
+/// interpreted little-endian) of the MD5 digest of [`get_global_identifier`]'s result. This lets
+/// IR globals be matched against ThinLTO summaries and call-graph profiles, which reference
+/// symbols by this same GUID.
 ///
 /// # Returns
 ///
-/// Returns a `MetadataRef` representing the metadata associated with the specified entry.
-///
-/// # Panics
-///
-/// The function may panic if the provided index is out of bounds for the vector, depending on how the underlying
-/// LLVM function handles invalid indices.
+/// Returns the GUID of `val`.
 #[must_use]
-pub fn value_metadata_entries_get_metadata(
-    value_metadata_entries: &[ValueMetadataEntry],
-    index: u32,
-) -> MetadataRef {
-    let entries_ptr = crate::to_mut_ptr!(value_metadata_entries);
-    unsafe {
-        MetadataRef::from(core::LLVMValueMetadataEntriesGetMetadata(
-            entries_ptr,
-            *CUint::from(index),
-        ))
+pub fn get_guid(val: &ValueRef) -> u64 {
+    let identifier = get_global_identifier(val);
+    let digest = md5::digest(identifier.as_bytes());
+    u64::from_le_bytes(digest[..8].try_into().unwrap_or_default())
+}
+
+/// A small, self-contained MD5 (RFC 1321) implementation.
+///
+/// `ValueRef::get_guid` is the only user of this: it needs the raw 16-byte MD5 digest of a
+/// symbol identifier and nothing else, so rather than taking on a dependency for one hash this
+/// vendors the straightforward reference algorithm.
+mod md5 {
+    const S: [u32; 64] = [
+        7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 5, 9, 14, 20, 5, 9, 14, 20, 5,
+        9, 14, 20, 5, 9, 14, 20, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 6, 10,
+        15, 21, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21,
+    ];
+
+    const K: [u32; 64] = [
+        0xd76a_a478,
+        0xe8c7_b756,
+        0x2420_70db,
+        0xc1bd_ceee,
+        0xf57c_0faf,
+        0x4787_c62a,
+        0xa830_4613,
+        0xfd46_9501,
+        0x6980_98d8,
+        0x8b44_f7af,
+        0xffff_5bb1,
+        0x895c_d7be,
+        0x6b90_1122,
+        0xfd98_7193,
+        0xa679_438e,
+        0x49b4_0821,
+        0xf61e_2562,
+        0xc040_b340,
+        0x265e_5a51,
+        0xe9b6_c7aa,
+        0xd62f_105d,
+        0x0244_1453,
+        0xd8a1_e681,
+        0xe7d3_fbc8,
+        0x21e1_cde6,
+        0xc337_07d6,
+        0xf4d5_0d87,
+        0x455a_14ed,
+        0xa9e3_e905,
+        0xfcef_a3f8,
+        0x676f_02d9,
+        0x8d2a_4c8a,
+        0xfffa_3942,
+        0x8771_f681,
+        0x6d9d_6122,
+        0xfde5_380c,
+        0xa4be_ea44,
+        0x4bde_cfa9,
+        0xf6bb_4b60,
+        0xbebf_bc70,
+        0x289b_7ec6,
+        0xeaa1_27fa,
+        0xd4ef_3085,
+        0x0488_1d05,
+        0xd9d4_d039,
+        0xe6db_99e5,
+        0x1fa2_7cf8,
+        0xc4ac_5665,
+        0xf429_2244,
+        0x432a_ff97,
+        0xab94_23a7,
+        0xfc93_a039,
+        0x655b_59c3,
+        0x8f0c_cc92,
+        0xffef_f47d,
+        0x8584_5dd1,
+        0x6fa8_7e4f,
+        0xfe2c_e6e0,
+        0xa301_4314,
+        0x4e08_11a1,
+        0xf753_7e82,
+        0xbd3a_f235,
+        0x2ad7_d2bb,
+        0xeb86_d391,
+    ];
+
+    /// Computes the 16-byte MD5 digest of `input`.
+    #[must_use]
+    pub fn digest(input: &[u8]) -> [u8; 16] {
+        let mut a0: u32 = 0x6745_2301;
+        let mut b0: u32 = 0xefcd_ab89;
+        let mut c0: u32 = 0x98ba_dcfe;
+        let mut d0: u32 = 0x1032_5476;
+
+        let mut message = input.to_vec();
+        let bit_len = (message.len() as u64).wrapping_mul(8);
+        message.push(0x80);
+        while message.len() % 64 != 56 {
+            message.push(0);
+        }
+        message.extend_from_slice(&bit_len.to_le_bytes());
+
+        for chunk in message.chunks_exact(64) {
+            let mut m = [0u32; 16];
+            for (i, word) in chunk.chunks_exact(4).enumerate() {
+                m[i] = u32::from_le_bytes([word[0], word[1], word[2], word[3]]);
+            }
+
+            let (mut a, mut b, mut c, mut d) = (a0, b0, c0, d0);
+            for i in 0..64 {
+                let (f, g) = match i {
+                    0..=15 => ((b & c) | (!b & d), i),
+                    16..=31 => ((d & b) | (!d & c), (5 * i + 1) % 16),
+                    32..=47 => (b ^ c ^ d, (3 * i + 5) % 16),
+                    _ => (c ^ (b | !d), (7 * i) % 16),
+                };
+                let f = f.wrapping_add(a).wrapping_add(K[i]).wrapping_add(m[g]);
+                a = d;
+                d = c;
+                c = b;
+                b = b.wrapping_add(f.rotate_left(S[i]));
+            }
+
+            a0 = a0.wrapping_add(a);
+            b0 = b0.wrapping_add(b);
+            c0 = c0.wrapping_add(c);
+            d0 = d0.wrapping_add(d);
+        }
+
+        let mut out = [0u8; 16];
+        out[0..4].copy_from_slice(&a0.to_le_bytes());
+        out[4..8].copy_from_slice(&b0.to_le_bytes());
+        out[8..12].copy_from_slice(&c0.to_le_bytes());
+        out[12..16].copy_from_slice(&d0.to_le_bytes());
+        out
+    }
+}
+
+/// Which conventionally-named section a piece of `SanitizerBinaryMetadata` belongs in.
+///
+/// Mirrors the two sections LLVM's `SanitizerBinaryMetadata` pass emits descriptors into.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SanitizerBinaryMetadataSection {
+    /// `__sanitizer_metadata_covered`: marks a function as instrumented for coverage.
+    Covered,
+    /// `__sanitizer_metadata_atomics`: marks a function as containing atomic operations.
+    Atomics,
+}
+
+impl SanitizerBinaryMetadataSection {
+    /// The conventional section name this descriptor kind is placed in.
+    #[must_use]
+    pub const fn section_name(self) -> &'static str {
+        match self {
+            Self::Covered => "__sanitizer_metadata_covered",
+            Self::Atomics => "__sanitizer_metadata_atomics",
+        }
+    }
+}
+
+/// Feature bits packed into a `SanitizerBinaryMetadata` descriptor's version word, alongside
+/// [`SANITIZER_BINARY_METADATA_VERSION_BASE`](version_word).
+///
+/// - `COVERED`: the descriptor marks coverage instrumentation.
+/// - `ATOMICS`: the descriptor marks the presence of atomic operations.
+/// - `UAR`: the descriptor marks use-after-return instrumentation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SanitizerBinaryMetadataFeatures(u32);
+
+impl SanitizerBinaryMetadataFeatures {
+    /// No feature bits set.
+    pub const NONE: Self = Self(0);
+    /// Coverage instrumentation is present.
+    pub const COVERED: Self = Self(1 << 0);
+    /// Atomic operations are present.
+    pub const ATOMICS: Self = Self(1 << 1);
+    /// Use-after-return instrumentation is present.
+    pub const UAR: Self = Self(1 << 2);
+
+    /// Returns the raw bitmask.
+    #[must_use]
+    pub const fn bits(self) -> u32 {
+        self.0
     }
+
+    /// Returns `true` if every bit set in `other` is also set in `self`.
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for SanitizerBinaryMetadataFeatures {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// The base version number `version_word` encodes into the low 16 bits of every descriptor,
+/// incremented whenever the descriptor layout changes in an incompatible way.
+const SANITIZER_BINARY_METADATA_VERSION_BASE: u32 = 2;
+
+/// The bit position feature flags are shifted to, above the 16-bit version number.
+const SANITIZER_BINARY_METADATA_VERSION_SHIFT: u32 = 16;
+
+/// Packs `SANITIZER_BINARY_METADATA_VERSION_BASE` in the low 16 bits with `features` shifted into
+/// the bits above it, matching the version/feature word layout
+/// `llvm::SanitizerBinaryMetadata` emits.
+#[must_use]
+pub const fn version_word(features: SanitizerBinaryMetadataFeatures) -> u32 {
+    SANITIZER_BINARY_METADATA_VERSION_BASE
+        | (features.bits() << SANITIZER_BINARY_METADATA_VERSION_SHIFT)
+}
+
+/// Declares `val` as a `SanitizerBinaryMetadata` descriptor for `section`.
+///
+/// ## Details
+///
+/// Attaches a metadata node carrying [`version_word(features)`](version_word) to `val`, and
+/// places `val` in the conventionally-named section for `section`
+/// ([`SanitizerBinaryMetadataSection::section_name`]) with `PrivateLinkage` and
+/// `LocalUnnamedAddr`, matching how `llvm::SanitizerBinaryMetadata` emits its per-function
+/// descriptors: private to the module and with no externally-observable address, so the linker
+/// is free to fold or discard them like any other instrumentation bookkeeping.
+///
+/// Built on top of the `global_set_metadata`/`set_section`/`set_linkage`/`set_unnamed_address`
+/// primitives already in this module; downstream tools parse the sections without needing to
+/// hand-roll the version/feature encoding themselves.
+pub fn mark_sanitizer_binary_metadata(
+    val: &ValueRef,
+    section: SanitizerBinaryMetadataSection,
+    features: SanitizerBinaryMetadataFeatures,
+) {
+    set_section(val, section.section_name());
+    set_linkage(val, Linkage::PrivateLinkage);
+    set_unnamed_address(val, UnnamedAddr::LocalUnnamedAddr);
+
+    let context = get_global_parent(val).get_module_context();
+    let kind_id = context
+        .get_md_kind_id_in_context("sanitizer_binary_metadata.version")
+        .0;
+    let word = unsafe {
+        core::LLVMConstInt(
+            core::LLVMInt32TypeInContext(context.get_ref()),
+            u64::from(version_word(features)),
+            0,
+        )
+    };
+    let md = MetadataRef::from(unsafe { core::LLVMValueAsMetadata(word) });
+    global_set_metadata(val, kind_id, &md);
 }
@@ -1,6 +1,13 @@
+//! Functions in this group model `ValueRef` instances that correspond
+//! to `GlobalVariable`, covering creation, lookup, iteration, the
+//! initializer, and the thread-local-storage accessors below.
+
 use super::{TypeRef, ValueRef};
 use crate::core::module::ModuleRef;
+use crate::core::values::general::is_constant;
+use crate::core::values::GlobalVariableValue;
 use crate::core::AddressSpace;
+use crate::error::Error;
 use crate::{CInt, CString, GetRef};
 use llvm_sys::{core, LLVMThreadLocalMode};
 
@@ -51,6 +58,77 @@ impl From<ThreadLocalMode> for LLVMThreadLocalMode {
     }
 }
 
+/// Recommends a TLS model for a thread-local variable, given what is known about where it is
+/// defined and used.
+///
+/// ## Details
+///
+/// `GeneralDynamicTLSModel` is always correct but is also the slowest model, since it makes no
+/// assumptions about how the variable may be accessed. As the TLS-model extension (LLVM PR9788)
+/// notes, a frontend that knows more about a variable's usage can pick a cheaper model:
+///
+/// - `LocalExecTLSModel` when the variable is defined in the final executable and only ever
+///   accessed from the module that defines it.
+/// - `InitialExecTLSModel` when `may_be_dlopened` is `false`, i.e. the module containing the
+///   variable is known to be loaded at program startup rather than via `dlopen`.
+/// - `LocalDynamicTLSModel` when the variable is only accessed within the module that defines it
+///   (typically a shared library), but that module may itself be `dlopen`ed.
+/// - `GeneralDynamicTLSModel` otherwise, when none of the above is known to hold.
+///
+/// # Parameters
+///
+/// - `defined_in_executable`: `true` if the variable is defined in the final executable, as
+///   opposed to a shared library.
+/// - `used_only_in_this_module`: `true` if the variable is never accessed from outside the
+///   module that defines it.
+/// - `may_be_dlopened`: `true` if the module containing the variable might be loaded via
+///   `dlopen` rather than being present at program startup.
+///
+/// # Returns
+///
+/// Returns the cheapest `ThreadLocalMode` that the given information justifies.
+#[must_use]
+pub const fn recommended_thread_local_mode(
+    defined_in_executable: bool,
+    used_only_in_this_module: bool,
+    may_be_dlopened: bool,
+) -> ThreadLocalMode {
+    if defined_in_executable && used_only_in_this_module {
+        ThreadLocalMode::LocalExecTLSModel
+    } else if !may_be_dlopened {
+        ThreadLocalMode::InitialExecTLSModel
+    } else if used_only_in_this_module {
+        ThreadLocalMode::LocalDynamicTLSModel
+    } else {
+        ThreadLocalMode::GeneralDynamicTLSModel
+    }
+}
+
+/// Computes the recommended TLS model via [`recommended_thread_local_mode`] and applies it to
+/// `val` via [`set_thread_local_mode`].
+///
+/// # Parameters
+///
+/// - `defined_in_executable`: `true` if the variable is defined in the final executable, as
+///   opposed to a shared library.
+/// - `used_only_in_this_module`: `true` if the variable is never accessed from outside the
+///   module that defines it.
+/// - `may_be_dlopened`: `true` if the module containing the variable might be loaded via
+///   `dlopen` rather than being present at program startup.
+pub fn apply_recommended_thread_local_mode(
+    val: &ValueRef,
+    defined_in_executable: bool,
+    used_only_in_this_module: bool,
+    may_be_dlopened: bool,
+) {
+    let mode = recommended_thread_local_mode(
+        defined_in_executable,
+        used_only_in_this_module,
+        may_be_dlopened,
+    );
+    set_thread_local_mode(val, mode);
+}
+
 /// Adds a new global variable of the specified type to the module.
 ///
 /// This function wraps the `LLVMAddGlobal` function from the LLVM core library. It creates a new global variable
@@ -64,17 +142,18 @@ impl From<ThreadLocalMode> for LLVMThreadLocalMode {
 ///
 /// # Returns
 ///
-/// Returns a `ValueRef` representing the newly added global variable.
+/// Returns a `GlobalVariableValue` representing the newly added global variable.
 #[must_use]
-pub fn add_global(m: &ModuleRef, ty: &TypeRef, name: &str) -> ValueRef {
-    let c_name = CString::from(name);
-    unsafe {
+pub fn add_global(m: &ModuleRef, ty: &TypeRef<'_>, name: &str) -> GlobalVariableValue {
+    let c_name = CString::try_from(name).expect("string contains an interior NUL byte");
+    let global = unsafe {
         ValueRef(core::LLVMAddGlobal(
             m.get_ref(),
             ty.get_ref(),
             c_name.as_ptr(),
         ))
-    }
+    };
+    GlobalVariableValue(global)
 }
 
 /// Adds a new global variable of the specified type to the module in a specific address space.
@@ -92,23 +171,25 @@ pub fn add_global(m: &ModuleRef, ty: &TypeRef, name: &str) -> ValueRef {
 ///
 /// # Returns
 ///
-/// Returns a `ValueRef` representing the newly added global variable in the specified address space.
+/// Returns a `GlobalVariableValue` representing the newly added global variable in the specified
+/// address space.
 #[must_use]
 pub fn add_global_in_address_space(
     m: &ModuleRef,
-    ty: &TypeRef,
+    ty: &TypeRef<'_>,
     name: &str,
     address_space: &AddressSpace,
-) -> ValueRef {
-    let c_name = CString::from(name);
-    unsafe {
+) -> GlobalVariableValue {
+    let c_name = CString::try_from(name).expect("string contains an interior NUL byte");
+    let global = unsafe {
         ValueRef(core::LLVMAddGlobalInAddressSpace(
             m.get_ref(),
             ty.get_ref(),
             c_name.as_ptr(),
             ***address_space,
         ))
-    }
+    };
+    GlobalVariableValue(global)
 }
 
 /// Retrieves a global variable by its name from the module.
@@ -128,7 +209,7 @@ pub fn add_global_in_address_space(
 /// - `None` if no global variable with the specified name exists in the module.
 #[must_use]
 pub fn get_named_global(m: &ModuleRef, name: &str) -> Option<ValueRef> {
-    let c_name = CString::from(name);
+    let c_name = CString::try_from(name).expect("string contains an interior NUL byte");
     let global = unsafe { core::LLVMGetNamedGlobal(m.get_ref(), c_name.as_ptr()) };
     if global.is_null() {
         None
@@ -404,3 +485,124 @@ pub fn set_externally_initialized(val: &ValueRef, is_ext_init: bool) {
         core::LLVMSetExternallyInitialized(val.get_ref(), *CInt::from(is_ext_init));
     }
 }
+
+impl GlobalVariableValue {
+    /// Get the initializer for this global variable.
+    ///
+    /// See [`get_initializer`].
+    #[must_use]
+    pub fn get_initializer(&self) -> Option<ValueRef> {
+        get_initializer(&self.0)
+    }
+
+    /// Sets the initializer for this global variable.
+    ///
+    /// ## Details
+    ///
+    /// LLVM requires a global variable's initializer to be a constant. Unlike the free
+    /// [`set_initializer`] function, this checks that via `LLVMIsConstant` first, so that passing
+    /// e.g. an instruction result fails with an [`Error`] instead of handing LLVM a value it
+    /// will reject.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotAConstant`] if `constant_val` is not an LLVM constant.
+    pub fn set_initializer(&self, constant_val: &ValueRef) -> Result<(), Error> {
+        if !is_constant(constant_val) {
+            return Err(Error::NotAConstant {
+                context: "global variable initializer",
+            });
+        }
+        set_initializer(&self.0, constant_val);
+        Ok(())
+    }
+}
+
+/// Builder for a module-level global variable.
+///
+/// ## Details
+///
+/// Creating a fully-configured global variable today means calling [`add_global`] or
+/// [`add_global_in_address_space`] and then a separate setter for every attribute
+/// (`thread_local_mode`, `global_constant`, `initializer`). `GlobalVariableBuilder` collects those
+/// attributes first and creates the global only once, chaining `.thread_local(mode)`,
+/// `.constant(true)`, `.initializer(c)`, and `.address_space(a)` before [`build`](Self::build)
+/// materializes it into the module.
+#[derive(Debug)]
+pub struct GlobalVariableBuilder<'ctx> {
+    ty: TypeRef<'ctx>,
+    name: String,
+    address_space: Option<AddressSpace>,
+    thread_local_mode: Option<ThreadLocalMode>,
+    is_constant: bool,
+    initializer: Option<ValueRef>,
+}
+
+impl<'ctx> GlobalVariableBuilder<'ctx> {
+    /// Starts building a global variable of type `ty` named `name`.
+    #[must_use]
+    pub fn new(ty: TypeRef<'ctx>, name: &str) -> Self {
+        Self {
+            ty,
+            name: name.to_string(),
+            address_space: None,
+            thread_local_mode: None,
+            is_constant: false,
+            initializer: None,
+        }
+    }
+
+    /// Places the global variable in `address_space` instead of the module's default address
+    /// space.
+    #[must_use]
+    pub fn address_space(mut self, address_space: AddressSpace) -> Self {
+        self.address_space = Some(address_space);
+        self
+    }
+
+    /// Sets the thread-local mode the global variable is created with.
+    #[must_use]
+    pub fn thread_local(mut self, mode: ThreadLocalMode) -> Self {
+        self.thread_local_mode = Some(mode);
+        self
+    }
+
+    /// Marks the global variable as a global constant, or not.
+    #[must_use]
+    pub fn constant(mut self, is_constant: bool) -> Self {
+        self.is_constant = is_constant;
+        self
+    }
+
+    /// Sets the initializer the global variable is created with.
+    #[must_use]
+    pub fn initializer(mut self, value: ValueRef) -> Self {
+        self.initializer = Some(value);
+        self
+    }
+
+    /// Creates the global variable in `module` with every attribute set so far.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotAConstant`] if an [`initializer`](Self::initializer) was given a value
+    /// that is not an LLVM constant; see [`GlobalVariableValue::set_initializer`].
+    pub fn build(self, module: &ModuleRef) -> Result<GlobalVariableValue, Error> {
+        let global = match &self.address_space {
+            Some(address_space) => {
+                add_global_in_address_space(module, &self.ty, &self.name, address_space)
+            }
+            None => add_global(module, &self.ty, &self.name),
+        };
+        if let Some(mode) = self.thread_local_mode {
+            set_thread_local_mode(&global, mode);
+        }
+        if self.is_constant {
+            set_global_constant(&global, true);
+        }
+        if let Some(initializer) = &self.initializer {
+            global.set_initializer(initializer)?;
+        }
+        Ok(global)
+    }
+}
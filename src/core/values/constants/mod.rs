@@ -3,6 +3,9 @@
 
 pub mod composite;
 pub mod expressions;
+pub mod global_aliases;
+pub mod global_values;
+pub mod global_variables;
 pub mod scalar;
 
 use super::ValueRef;
@@ -30,7 +33,7 @@ impl ValueRef {
     ///
     /// Returns an instance of `ValueRef`, which encapsulates the constant null value for the specified type.
     #[must_use]
-    pub fn const_null(ty: &TypeRef) -> Self {
+    pub fn const_null(ty: &TypeRef<'_>) -> Self {
         unsafe { Self(core::LLVMConstNull(ty.get_ref())) }
     }
 
@@ -55,7 +58,7 @@ impl ValueRef {
     ///
     /// Returns an instance of `ValueRef`, which encapsulates the constant all-ones value for the specified type.
     #[must_use]
-    pub fn const_all_ones(ty: &TypeRef) -> Self {
+    pub fn const_all_ones(ty: &TypeRef<'_>) -> Self {
         unsafe { Self(core::LLVMConstAllOnes(ty.get_ref())) }
     }
 
@@ -77,7 +80,7 @@ impl ValueRef {
     ///
     /// Returns an instance of `ValueRef`, which encapsulates the constant undefined value for the specified type.
     #[must_use]
-    pub fn get_undef(ty: &TypeRef) -> Self {
+    pub fn get_undef(ty: &TypeRef<'_>) -> Self {
         unsafe { Self(core::LLVMGetUndef(ty.get_ref())) }
     }
 
@@ -100,7 +103,7 @@ impl ValueRef {
     ///
     /// Returns an instance of `ValueRef`, which encapsulates the constant poison value for the specified type.
     #[must_use]
-    pub fn get_poison(ty: &TypeRef) -> Self {
+    pub fn get_poison(ty: &TypeRef<'_>) -> Self {
         unsafe { Self(core::LLVMGetPoison(ty.get_ref())) }
     }
 
@@ -141,7 +144,7 @@ impl ValueRef {
     ///
     /// Returns an instance of `ValueRef`, which encapsulates the constant null pointer value for the specified type.
     #[must_use]
-    pub fn const_pointer_null(ty: &TypeRef) -> Self {
+    pub fn const_pointer_null(ty: &TypeRef<'_>) -> Self {
         unsafe { Self(core::LLVMConstPointerNull(ty.get_ref())) }
     }
 }
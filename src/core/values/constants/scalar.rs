@@ -3,6 +3,9 @@
 
 use super::ValueRef;
 use crate::core::types::TypeRef;
+use crate::core::values::general::{get_value_kind, print_value_to_string, type_of};
+use crate::core::values::ValueKind;
+use crate::error::{ConstParseError, Error};
 use crate::{CDouble, CInt, CString, CUint, GetRef};
 use llvm_sys::core;
 
@@ -27,7 +30,7 @@ use llvm_sys::core;
 ///
 /// Returns an instance of `ValueRef`, which encapsulates the constant integer value determined at compile time.
 #[must_use]
-pub fn const_int(ty: &TypeRef, n: u64, sign_extend: bool) -> ValueRef {
+pub fn const_int(ty: &TypeRef<'_>, n: u64, sign_extend: bool) -> ValueRef {
     unsafe {
         ValueRef(core::LLVMConstInt(
             ty.get_ref(),
@@ -56,11 +59,11 @@ pub fn const_int(ty: &TypeRef, n: u64, sign_extend: bool) -> ValueRef {
 ///
 /// Returns an instance of `ValueRef`, which encapsulates the constant integer value with arbitrary precision, as determined at compile time.
 #[must_use]
-pub fn const_int_of_arbitrary_precision(ty: &TypeRef, words: &[u64]) -> ValueRef {
+pub fn const_int_of_arbitrary_precision(ty: &TypeRef<'_>, words: &[u64]) -> ValueRef {
     unsafe {
         ValueRef(core::LLVMConstIntOfArbitraryPrecision(
             ty.get_ref(),
-            *CUint::from(words.len()),
+            *CUint::try_from(words.len()).expect("value does not fit in c_uint"),
             words.as_ptr(),
         ))
     }
@@ -91,8 +94,8 @@ pub fn const_int_of_arbitrary_precision(ty: &TypeRef, words: &[u64]) -> ValueRef
 ///
 /// Returns an instance of `ValueRef`, which encapsulates the constant integer value parsed from the string at compile time.
 #[must_use]
-pub fn const_int_of_string(ty: &TypeRef, text: &str, radix: u8) -> ValueRef {
-    let c_text = CString::from(text);
+pub fn const_int_of_string(ty: &TypeRef<'_>, text: &str, radix: u8) -> ValueRef {
+    let c_text = CString::try_from(text).expect("string contains an interior NUL byte");
     unsafe {
         ValueRef(core::LLVMConstIntOfString(
             ty.get_ref(),
@@ -102,6 +105,50 @@ pub fn const_int_of_string(ty: &TypeRef, text: &str, radix: u8) -> ValueRef {
     }
 }
 
+/// Obtain a constant value for an integer parsed from a string, without risking a null
+/// `ValueRef` on invalid input.
+///
+/// # Details
+///
+/// `const_int_of_string` silently wraps whatever `LLVMConstIntOfString` returns, including a
+/// null `LLVMValueRef` when `text` is not a valid number in the given `radix`. This function
+/// instead checks `radix` up front, since LLVM asserts rather than returning an error for
+/// anything other than 2, 8, 10, or 16, and null-checks the returned pointer, so callers can
+/// feed user-supplied literals into the IR builder without a null `ValueRef` propagating
+/// downstream.
+///
+/// # Parameters
+///
+/// - `ty`: A reference to the integer type (`TypeRef`) for the constant value.
+/// - `text`: A string slice that represents the integer value to be parsed.
+/// - `radix`: The radix (or base) used to interpret the string. Must be one of 2, 8, 10, or 16.
+///
+/// # Errors
+///
+/// Returns [`Error::ConstParse`] if `radix` is not one of 2, 8, 10, or 16, if `text` contains
+/// an interior NUL byte, or if LLVM rejected `text` as a valid literal in that radix.
+pub fn try_const_int_of_string(
+    ty: &TypeRef<'_>,
+    text: &str,
+    radix: u8,
+) -> Result<ValueRef, Error> {
+    if !matches!(radix, 2 | 8 | 10 | 16) {
+        return Err(Error::ConstParse(ConstParseError {
+            text: text.to_string(),
+            radix: Some(radix),
+        }));
+    }
+    let c_text = CString::try_from(text)?;
+    let value = unsafe { core::LLVMConstIntOfString(ty.get_ref(), c_text.as_ptr(), radix) };
+    if value.is_null() {
+        return Err(Error::ConstParse(ConstParseError {
+            text: text.to_string(),
+            radix: Some(radix),
+        }));
+    }
+    Ok(ValueRef(value))
+}
+
 /// Obtain a constant value for an integer parsed from a string with
 /// specified length.
 ///
@@ -129,18 +176,65 @@ pub fn const_int_of_string(ty: &TypeRef, text: &str, radix: u8) -> ValueRef {
 /// This function is recommended when the length of the string is known, as it may offer better performance
 /// compared to `const_int_of_string` by avoiding the overhead of calculating the string length within the function.
 #[must_use]
-pub fn const_int_of_string_and_size(ty: &TypeRef, text: &str, radix: u8) -> ValueRef {
-    let c_text = CString::from(text);
+pub fn const_int_of_string_and_size(ty: &TypeRef<'_>, text: &str, radix: u8) -> ValueRef {
+    let c_text = CString::try_from(text).expect("string contains an interior NUL byte");
     unsafe {
         ValueRef(core::LLVMConstIntOfStringAndSize(
             ty.get_ref(),
             c_text.as_ptr(),
-            *CUint::from(text.len()),
+            *CUint::try_from(text.len()).expect("value does not fit in c_uint"),
             radix,
         ))
     }
 }
 
+/// Obtain a constant value for an integer parsed from a string with specified length,
+/// without risking a null `ValueRef` on invalid input.
+///
+/// # Details
+///
+/// See [`try_const_int_of_string`] for the rationale; this is the size-aware counterpart to
+/// `const_int_of_string_and_size`, preferred when the string's length is already known.
+///
+/// # Parameters
+///
+/// - `ty`: A reference to the integer type (`TypeRef`) for the constant value.
+/// - `text`: A string slice that represents the integer value to be parsed.
+/// - `radix`: The radix (or base) used to interpret the string. Must be one of 2, 8, 10, or 16.
+///
+/// # Errors
+///
+/// Returns [`Error::ConstParse`] if `radix` is not one of 2, 8, 10, or 16, if `text` contains
+/// an interior NUL byte, or if LLVM rejected `text` as a valid literal in that radix.
+pub fn try_const_int_of_string_and_size(
+    ty: &TypeRef<'_>,
+    text: &str,
+    radix: u8,
+) -> Result<ValueRef, Error> {
+    if !matches!(radix, 2 | 8 | 10 | 16) {
+        return Err(Error::ConstParse(ConstParseError {
+            text: text.to_string(),
+            radix: Some(radix),
+        }));
+    }
+    let c_text = CString::try_from(text)?;
+    let value = unsafe {
+        core::LLVMConstIntOfStringAndSize(
+            ty.get_ref(),
+            c_text.as_ptr(),
+            *CUint::try_from(text.len()).expect("value does not fit in c_uint"),
+            radix,
+        )
+    };
+    if value.is_null() {
+        return Err(Error::ConstParse(ConstParseError {
+            text: text.to_string(),
+            radix: Some(radix),
+        }));
+    }
+    Ok(ValueRef(value))
+}
+
 /// Obtain a constant value referring to a double floating point value.
 ///
 /// # Details
@@ -160,7 +254,7 @@ pub fn const_int_of_string_and_size(ty: &TypeRef, text: &str, radix: u8) -> Valu
 ///
 /// Returns an instance of `ValueRef`, which encapsulates the constant floating-point value determined at compile time.
 #[must_use]
-pub fn const_real(ty: &TypeRef, n: f64) -> ValueRef {
+pub fn const_real(ty: &TypeRef<'_>, n: f64) -> ValueRef {
     unsafe { ValueRef(core::LLVMConstReal(ty.get_ref(), *CDouble::from(n))) }
 }
 
@@ -187,11 +281,42 @@ pub fn const_real(ty: &TypeRef, n: f64) -> ValueRef {
 ///
 /// Returns an instance of `ValueRef`, which encapsulates the constant floating-point value parsed from the string at compile time.
 #[must_use]
-pub fn const_real_of_string(ty: &TypeRef, text: &str) -> ValueRef {
-    let c_text = CString::from(text);
+pub fn const_real_of_string(ty: &TypeRef<'_>, text: &str) -> ValueRef {
+    let c_text = CString::try_from(text).expect("string contains an interior NUL byte");
     unsafe { ValueRef(core::LLVMConstRealOfString(ty.get_ref(), c_text.as_ptr())) }
 }
 
+/// Obtain a constant for a floating point value parsed from a string, without risking a null
+/// `ValueRef` on invalid input.
+///
+/// # Details
+///
+/// `const_real_of_string` silently wraps whatever `LLVMConstRealOfString` returns, including a
+/// null `LLVMValueRef` when `text` is not a valid floating-point literal. This function
+/// null-checks the returned pointer instead, so callers can feed user-supplied literals into
+/// the IR builder without a null `ValueRef` propagating downstream.
+///
+/// # Parameters
+///
+/// - `ty`: A reference to the floating-point type (`TypeRef`) for the constant value.
+/// - `text`: A string slice that represents the floating-point value to be parsed.
+///
+/// # Errors
+///
+/// Returns [`Error::ConstParse`] if `text` contains an interior NUL byte or LLVM rejected it
+/// as a valid floating-point literal.
+pub fn try_const_real_of_string(ty: &TypeRef<'_>, text: &str) -> Result<ValueRef, Error> {
+    let c_text = CString::try_from(text)?;
+    let value = unsafe { core::LLVMConstRealOfString(ty.get_ref(), c_text.as_ptr()) };
+    if value.is_null() {
+        return Err(Error::ConstParse(ConstParseError {
+            text: text.to_string(),
+            radix: None,
+        }));
+    }
+    Ok(ValueRef(value))
+}
+
 /// Obtain a constant for a floating point value parsed from a string with specified length.
 ///
 /// # Details
@@ -217,17 +342,48 @@ pub fn const_real_of_string(ty: &TypeRef, text: &str) -> ValueRef {
 /// This function is recommended when the length of the string is known, as it may offer better performance
 /// compared to `const_real_of_string` by avoiding the overhead of calculating the string length within the function.
 #[must_use]
-pub fn const_real_of_string_and_size(ty: &TypeRef, text: &str) -> ValueRef {
-    let c_text = CString::from(text);
+pub fn const_real_of_string_and_size(ty: &TypeRef<'_>, text: &str) -> ValueRef {
+    let c_text = CString::try_from(text).expect("string contains an interior NUL byte");
     unsafe {
         ValueRef(core::LLVMConstRealOfStringAndSize(
             ty.get_ref(),
             c_text.as_ptr(),
-            *CUint::from(text.len()),
+            *CUint::try_from(text.len()).expect("value does not fit in c_uint"),
         ))
     }
 }
 
+/// Obtain a constant for a floating point value parsed from a string with specified length,
+/// without risking a null `ValueRef` on invalid input.
+///
+/// # Details
+///
+/// See [`try_const_real_of_string`] for the rationale; this is the size-aware counterpart to
+/// `const_real_of_string_and_size`, preferred when the string's length is already known.
+///
+/// # Parameters
+///
+/// - `ty`: A reference to the floating-point type (`TypeRef`) for the constant value.
+/// - `text`: A string slice that represents the floating-point value to be parsed.
+///
+/// # Errors
+///
+/// Returns [`Error::ConstParse`] if `text` contains an interior NUL byte or LLVM rejected it
+/// as a valid floating-point literal.
+pub fn try_const_real_of_string_and_size(ty: &TypeRef<'_>, text: &str) -> Result<ValueRef, Error> {
+    let c_text = CString::try_from(text)?;
+    let value = unsafe {
+        core::LLVMConstRealOfStringAndSize(ty.get_ref(), c_text.as_ptr(), *CUint::try_from(text.len()).expect("value does not fit in c_uint"))
+    };
+    if value.is_null() {
+        return Err(Error::ConstParse(ConstParseError {
+            text: text.to_string(),
+            radix: None,
+        }));
+    }
+    Ok(ValueRef(value))
+}
+
 /// Obtain the zero extended value for an integer constant value.
 ///
 /// # Details
@@ -264,6 +420,357 @@ pub fn const_int_get_sext_value(val: &ValueRef) -> i64 {
     unsafe { core::LLVMConstIntGetSExtValue(val.get_ref()) }
 }
 
+/// Native Rust integer types that can be turned into an LLVM integer constant via
+/// `ValueRef::const_from`.
+///
+/// # Details
+///
+/// `const_int` takes a raw `u64` plus a `sign_extend` flag, which forces callers to hand-pack
+/// negative values themselves and offers no way to represent integers wider than 64 bits. This
+/// trait instead lets `ValueRef::const_from` pick the right extension and encoding directly from
+/// the Rust type: `i8`/`i16`/`i32`/`i64` sign-extend, `u8`/`u16`/`u32`/`u64` zero-extend, and the
+/// 128-bit types are split into two little-endian 64-bit words and routed through
+/// `const_int_of_arbitrary_precision`, with negative `i128` values two's-complement encoded into
+/// those words.
+pub trait ConstInt: Copy {
+    /// Whether this Rust type is signed, i.e. whether `ValueRef::const_from` should
+    /// sign-extend (`true`) or zero-extend (`false`) it to the target type's bit width.
+    const SIGNED: bool;
+
+    /// The number of 64-bit words from `to_words` that actually hold the value: `1` for every
+    /// type up to 64 bits, `2` for `i128`/`u128`.
+    const WORDS: usize;
+
+    /// Encode `self` as little-endian 64-bit words. Negative values are two's-complement
+    /// encoded. Only the first `Self::WORDS` entries are meaningful.
+    fn to_words(self) -> [u64; 2];
+}
+
+macro_rules! impl_const_int_narrow {
+    ($($ty:ty => $signed:expr),* $(,)?) => {
+        $(
+            impl ConstInt for $ty {
+                const SIGNED: bool = $signed;
+                const WORDS: usize = 1;
+
+                fn to_words(self) -> [u64; 2] {
+                    [self as i64 as u64, 0]
+                }
+            }
+        )*
+    };
+}
+
+impl_const_int_narrow! {
+    i8 => true, i16 => true, i32 => true, i64 => true,
+    u8 => false, u16 => false, u32 => false, u64 => false,
+}
+
+impl ConstInt for i128 {
+    const SIGNED: bool = true;
+    const WORDS: usize = 2;
+
+    fn to_words(self) -> [u64; 2] {
+        let bits = self as u128;
+        [bits as u64, (bits >> 64) as u64]
+    }
+}
+
+impl ConstInt for u128 {
+    const SIGNED: bool = false;
+    const WORDS: usize = 2;
+
+    fn to_words(self) -> [u64; 2] {
+        [self as u64, (self >> 64) as u64]
+    }
+}
+
+impl ValueRef {
+    /// Obtain a constant value for a native Rust integer, choosing the correct extension and
+    /// encoding from the Rust type itself.
+    ///
+    /// # Details
+    ///
+    /// This is a type-driven counterpart to `const_int`: instead of a raw `u64` plus a
+    /// `sign_extend` flag that the caller must get right by hand, `v`'s type determines whether
+    /// the value is sign- or zero-extended to `ty`. For `i128`/`u128`, `v` is instead split into
+    /// two little-endian 64-bit words (two's-complement encoded for negative values) and passed
+    /// to `const_int_of_arbitrary_precision`, since `LLVMConstInt` cannot represent more than 64
+    /// bits of magnitude.
+    ///
+    /// # Parameters
+    ///
+    /// - `ty`: A reference to the integer type (`TypeRef`) for the constant value.
+    /// - `v`: The Rust integer value to encode, any of `i8`/`i16`/`i32`/`i64`/`i128` or
+    ///   `u8`/`u16`/`u32`/`u64`/`u128`.
+    ///
+    /// # Returns
+    ///
+    /// Returns an instance of `ValueRef`, which encapsulates the constant integer value
+    /// determined at compile time.
+    #[must_use]
+    pub fn const_from<T: ConstInt>(ty: &TypeRef<'_>, v: T) -> Self {
+        let words = v.to_words();
+        if T::WORDS > 1 {
+            const_int_of_arbitrary_precision(ty, &words[..T::WORDS])
+        } else {
+            const_int(ty, words[0], T::SIGNED)
+        }
+    }
+
+    /// Build a `ConstantInt` splatted across every lane of a vector type.
+    ///
+    /// # Details
+    ///
+    /// Integer string constants can't easily be "splatted" across a vector the way
+    /// floating-point ones can, since there is no single `LLVMConst*Splat` entry point for
+    /// integers. This builds the scalar `ConstantInt` via `const_int` using `vec_ty`'s element
+    /// type, then replicates it into every lane to produce a `ConstantVector` of `vec_ty`'s
+    /// width, giving a one-call path to broadcast/splat constants for SIMD code instead of
+    /// manually assembling the element array.
+    ///
+    /// # Parameters
+    ///
+    /// - `vec_ty`: A reference to the vector type (`TypeRef`) to splat the constant across.
+    /// - `n`: The integer value to splat, interpreted according to the bit width of `vec_ty`'s element type.
+    /// - `sign_extend`: Whether `n` should be sign-extended (`true`) or zero-extended (`false`) to the element type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `vec_ty` is not a (fixed or scalable) vector type.
+    ///
+    /// # Returns
+    ///
+    /// Returns an instance of `ValueRef`, which encapsulates the constant vector value with
+    /// `n` replicated across every lane.
+    #[must_use]
+    pub fn const_int_splat(vec_ty: &TypeRef<'_>, n: u64, sign_extend: bool) -> Self {
+        let vector_ty = vec_ty
+            .as_vector()
+            .expect("const_int_splat: vec_ty is not a vector type");
+        let element = const_int(&vector_ty.get_element_type(), n, sign_extend);
+        let lanes = vec![Self(element.0); vector_ty.get_vector_size() as usize];
+        super::composite::const_vector(&lanes)
+    }
+
+    /// Build a `ConstantFP` splatted across every lane of a vector type.
+    ///
+    /// # Details
+    ///
+    /// This builds the scalar `ConstantFP` via `const_real` using `vec_ty`'s element type, then
+    /// replicates it into every lane to produce a `ConstantVector` of `vec_ty`'s width, giving a
+    /// one-call path to broadcast/splat constants for SIMD code instead of manually assembling
+    /// the element array.
+    ///
+    /// # Parameters
+    ///
+    /// - `vec_ty`: A reference to the vector type (`TypeRef`) to splat the constant across.
+    /// - `n`: The floating-point value to splat, interpreted according to the bit width of `vec_ty`'s element type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `vec_ty` is not a (fixed or scalable) vector type.
+    ///
+    /// # Returns
+    ///
+    /// Returns an instance of `ValueRef`, which encapsulates the constant vector value with
+    /// `n` replicated across every lane.
+    #[must_use]
+    pub fn const_real_splat(vec_ty: &TypeRef<'_>, n: f64) -> Self {
+        let vector_ty = vec_ty
+            .as_vector()
+            .expect("const_real_splat: vec_ty is not a vector type");
+        let element = const_real(&vector_ty.get_element_type(), n);
+        let lanes = vec![Self(element.0); vector_ty.get_vector_size() as usize];
+        super::composite::const_vector(&lanes)
+    }
+
+    /// Build a constant integer from a Rust `i128`, falling back to a poison value if it does
+    /// not fit `ty`'s bit width instead of silently truncating it.
+    ///
+    /// # Details
+    ///
+    /// `LLVMConstInt` truncates a value that is too wide for the target type without any
+    /// diagnostic. This mirrors ruby-llvm's `fits_width?` check before constructing the
+    /// constant: for `signed` values, `value` fits when its minimal two's-complement bit length
+    /// is strictly less than `ty`'s width (with a special allowance for the constant `1` in an
+    /// `i1`, since that's the idiomatic way to write a `true` literal even though `1` is not
+    /// representable as a signed `i1`); for unsigned values, `value` fits when it is
+    /// non-negative and its bit length is at most `ty`'s width. If the check fails,
+    /// `ValueRef::get_poison(ty)` is returned instead of a truncated constant.
+    ///
+    /// # Parameters
+    ///
+    /// - `ty`: A reference to the integer type (`TypeRef`) for the constant value.
+    /// - `value`: The value to encode, as a Rust `i128`.
+    /// - `signed`: Whether `value` should be checked and encoded as a signed or unsigned integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ty` is not an integer type.
+    ///
+    /// # Returns
+    ///
+    /// Returns an instance of `ValueRef` for `value` if it fits `ty`'s width, otherwise a
+    /// poison value of `ty`.
+    #[must_use]
+    pub fn const_int_checked(ty: &TypeRef<'_>, value: i128, signed: bool) -> Self {
+        let width = ty
+            .as_int()
+            .expect("const_int_checked: ty is not an integer type")
+            .get_int_type_width();
+        let fits = if signed {
+            (width == 1 && value == 1) || signed_bit_length(value) < width
+        } else {
+            value >= 0 && signed_bit_length(value) <= width
+        };
+        if !fits {
+            return Self::get_poison(ty);
+        }
+        if signed {
+            Self::const_from(ty, value)
+        } else {
+            Self::const_from(ty, value as u128)
+        }
+    }
+}
+
+impl ValueRef {
+    /// Build a constant integer of arbitrary width directly from a textual literal.
+    ///
+    /// # Details
+    ///
+    /// Front-ends that carry numeric literals wider than 64/128 bits (e.g. `i256` constants)
+    /// have no path to a constant value through the machine-width constructors in this crate.
+    /// This wraps `LLVMConstIntOfStringAndSize`, passing `text`'s byte length explicitly rather
+    /// than relying on LLVM to recompute it, so `ty` can be parsed directly from a decimal, hex,
+    /// octal, or binary literal regardless of its bit width.
+    ///
+    /// # Parameters
+    ///
+    /// - `ty`: A reference to the integer type (`TypeRef`) for the constant value.
+    /// - `text`: A string slice that represents the integer value to be parsed.
+    /// - `radix`: The radix (or base) used to interpret the string. Must be one of 2, 8, 10, or 16.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if `radix` is not one of 2, 8, 10, or 16: LLVM asserts on this
+    /// internally rather than returning an error.
+    #[must_use]
+    pub fn const_int_of_string(ty: &TypeRef<'_>, text: &str, radix: u8) -> Self {
+        debug_assert!(
+            matches!(radix, 2 | 8 | 10 | 16),
+            "const_int_of_string: radix must be one of 2, 8, 10, or 16"
+        );
+        let c_text = CString::try_from(text).expect("string contains an interior NUL byte");
+        unsafe {
+            Self(core::LLVMConstIntOfStringAndSize(
+                ty.get_ref(),
+                c_text.as_ptr(),
+                *CUint::try_from(text.len()).expect("value does not fit in c_uint"),
+                radix,
+            ))
+        }
+    }
+}
+
+/// The minimal number of bits needed to represent `value` in two's complement, not counting the
+/// sign bit: `0` for `0`, the position of the highest set bit plus one for positive values, and
+/// the same computed on `!value` for negative values (mirroring Ruby's `Integer#bit_length`).
+fn signed_bit_length(value: i128) -> u32 {
+    if value < 0 {
+        128 - (!value as u128).leading_zeros()
+    } else {
+        128 - (value as u128).leading_zeros()
+    }
+}
+
+/// Obtain the bit width of an integer constant's type.
+///
+/// # Details
+///
+/// A small helper so callers of [`const_int_get_words`] can size their buffers without having
+/// to downcast `val`'s type to `IntTypeRef` themselves.
+///
+/// # Panics
+///
+/// Panics if `val`'s type is not an integer type.
+///
+/// # Returns
+///
+/// Returns a `u32` representing the bit width of `val`'s integer type.
+#[must_use]
+pub fn const_int_bit_width(val: &ValueRef) -> u32 {
+    type_of(val)
+        .as_int()
+        .expect("const_int_bit_width: value is not an integer constant")
+        .get_int_type_width()
+}
+
+/// Obtain the full-precision value of an integer constant as little-endian 64-bit words.
+///
+/// # Details
+///
+/// `const_int_get_zext_value`/`const_int_get_sext_value` truncate to 64 bits, so a constant
+/// built with `const_int_of_arbitrary_precision` wider than one word cannot be recovered
+/// losslessly through them. This instead checks the integer type's bit width: for widths of 64
+/// bits or less it falls back to `const_int_get_zext_value`, and for wider constants it renders
+/// `val` to text via `print_value_to_string` and parses the decimal literal into the minimal
+/// little-endian array of 64-bit words, two's-complement encoding negative values. The result
+/// round-trips exactly through `const_int_of_arbitrary_precision`.
+///
+/// # Panics
+///
+/// Panics if `val`'s type is not an integer type, or if LLVM's printed representation of `val`
+/// is not of the expected `<type> <decimal>` form.
+///
+/// # Returns
+///
+/// Returns a `Vec<u64>` of `val`'s value as little-endian 64-bit words, sized to exactly cover
+/// `const_int_bit_width(val)` bits.
+#[must_use]
+pub fn const_int_get_words(val: &ValueRef) -> Vec<u64> {
+    let bit_width = const_int_bit_width(val);
+    if bit_width <= 64 {
+        return vec![const_int_get_zext_value(val)];
+    }
+
+    let text = print_value_to_string(val)
+        .expect("const_int_get_words: LLVM failed to print the constant");
+    let digits = text
+        .rsplit(' ')
+        .next()
+        .expect("const_int_get_words: unexpected printed constant format");
+    let (negative, digits) = match digits.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, digits),
+    };
+
+    let word_count = (bit_width as usize).div_ceil(64);
+    let mut words = vec![0u64; word_count];
+    for digit in digits.chars() {
+        let digit =
+            u64::from(digit.to_digit(10).expect("const_int_get_words: non-decimal constant"));
+        let mut carry = digit;
+        for word in &mut words {
+            let product = u128::from(*word) * 10 + u128::from(carry);
+            *word = product as u64;
+            carry = (product >> 64) as u64;
+        }
+    }
+
+    if negative {
+        let mut carry = 1u64;
+        for word in &mut words {
+            let (sum, overflow) = (!*word).overflowing_add(carry);
+            *word = sum;
+            carry = u64::from(overflow);
+        }
+    }
+
+    words
+}
+
 /// Obtain the double value for a floating point constant value.
 /// `losesInfo` indicates if some precision was lost in the conversion.
 ///
@@ -286,3 +793,58 @@ pub fn const_real_get_double(val: &ValueRef) -> (f64, bool) {
     let result = unsafe { core::LLVMConstRealGetDouble(val.get_ref(), &mut loses_info_c) };
     (result, loses_info_c != 0)
 }
+
+impl ValueRef {
+    /// Recover the sign-extended value of `self`, if it is a constant integer.
+    ///
+    /// # Details
+    ///
+    /// `const_int_get_sext_value` trusts the caller to already know `val` is a
+    /// `ConstantInt`; this checks `get_value_kind` first, so callers that only have an
+    /// arbitrary `ValueRef` in hand (e.g. the result of `const_trunc`, `const_ptr_to_int`, or
+    /// another constant-folding call) can safely ask "is this actually a constant integer, and
+    /// if so what is it" in one step.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(i64)` with the sign-extended value if `self` is a `ConstantInt`, otherwise
+    /// `None`.
+    #[must_use]
+    pub fn const_int_sext_value(&self) -> Option<i64> {
+        (get_value_kind(self) == ValueKind::ConstantInt)
+            .then(|| const_int_get_sext_value(self))
+    }
+
+    /// Recover the zero-extended value of `self`, if it is a constant integer.
+    ///
+    /// # Details
+    ///
+    /// See `const_int_sext_value`; this is the zero-extending counterpart, wrapping
+    /// `const_int_get_zext_value` behind the same `get_value_kind` check.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(u64)` with the zero-extended value if `self` is a `ConstantInt`, otherwise
+    /// `None`.
+    #[must_use]
+    pub fn const_int_zext_value(&self) -> Option<u64> {
+        (get_value_kind(self) == ValueKind::ConstantInt)
+            .then(|| const_int_get_zext_value(self))
+    }
+
+    /// Recover the double value of `self`, if it is a constant floating-point value.
+    ///
+    /// # Details
+    ///
+    /// See `const_int_sext_value`; this is the floating-point counterpart, wrapping
+    /// `const_real_get_double` behind a `get_value_kind` check for `ValueKind::ConstantFP`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some((f64, bool))` with the double value and whether precision was lost in the
+    /// conversion if `self` is a `ConstantFP`, otherwise `None`.
+    #[must_use]
+    pub fn const_real_double(&self) -> Option<(f64, bool)> {
+        (get_value_kind(self) == ValueKind::ConstantFP).then(|| const_real_get_double(self))
+    }
+}
@@ -232,3 +232,101 @@ pub fn set_param_alignment(arg: &ValueRef, align: u32) {
         core::LLVMSetParamAlignment(arg.0, align);
     }
 }
+
+/// An iterator over a function's parameters.
+///
+/// Walks the parameter list using `LLVMGetFirstParam`/`LLVMGetNextParam` internally, so callers
+/// do not need to pre-allocate a slice of the correct length up front. Also supports iterating
+/// from the back via `LLVMGetLastParam`/`LLVMGetPreviousParam`, so `.rev()` and `.next_back()`
+/// work as expected, and reports an exact `len()` sourced from `count_params`.
+pub struct ParamIter {
+    front: Option<ValueRef>,
+    back: Option<ValueRef>,
+    remaining: usize,
+}
+
+impl Iterator for ParamIter {
+    type Item = ValueRef;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.front.take()?;
+        self.remaining = self.remaining.saturating_sub(1);
+        if self.back.as_ref().is_some_and(|back| back.0 == current.0) {
+            self.back = None;
+        } else {
+            self.front = get_next_param(&current);
+        }
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl DoubleEndedIterator for ParamIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let current = self.back.take()?;
+        self.remaining = self.remaining.saturating_sub(1);
+        if self.front.as_ref().is_some_and(|front| front.0 == current.0) {
+            self.front = None;
+        } else {
+            self.back = get_previous_param(&current);
+        }
+        Some(current)
+    }
+}
+
+impl ExactSizeIterator for ParamIter {
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+/// Returns an iterator over the parameters of a function.
+///
+/// This walks the function's parameter list via `get_first_param`/`get_next_param` rather than
+/// requiring a pre-sized slice, so it is safe to use regardless of the parameter count.
+///
+/// # Parameters
+///
+/// - `fn_val`: The `ValueRef` representing the function.
+///
+/// # Returns
+///
+/// Returns a `ParamIter` yielding each parameter as a `ValueRef`, in order.
+///
+/// # Safety
+///
+/// - The `ValueRef` must represent a valid function within a module.
+#[must_use]
+pub fn params_iter(fn_val: &ValueRef) -> ParamIter {
+    ParamIter {
+        front: get_first_param(fn_val),
+        back: get_last_param(fn_val),
+        remaining: count_params(fn_val) as usize,
+    }
+}
+
+/// Collects all parameters of a function into a `Vec`.
+///
+/// This is a convenience wrapper around `params_iter` that sizes the resulting `Vec` from
+/// `count_params` up front.
+///
+/// # Parameters
+///
+/// - `fn_val`: The `ValueRef` representing the function.
+///
+/// # Returns
+///
+/// Returns a `Vec<ValueRef>` containing the function's parameters, in order.
+///
+/// # Safety
+///
+/// - The `ValueRef` must represent a valid function within a module.
+#[must_use]
+pub fn collect_params(fn_val: &ValueRef) -> Vec<ValueRef> {
+    let mut params = Vec::with_capacity(count_params(fn_val) as usize);
+    params.extend(params_iter(fn_val));
+    params
+}
@@ -2,11 +2,39 @@ use super::ValueRef;
 use crate::core::context::{AttributeRef, ContextRef};
 use crate::core::module::ModuleRef;
 use crate::core::types::TypeRef;
+use crate::core::{DLLStorageClass, Linkage, Visibility};
 use crate::{CStr, CString, CUint, GetRef, SizeT};
 use llvm_sys::core;
 
 pub mod function_parameters;
 
+/// Represents the index space used by LLVM's function-attribute APIs.
+///
+/// LLVM encodes three distinct things in a single `LLVMAttributeIndex`: the function's return
+/// value, the function itself, and its parameters (which are 1-based in this index space). Using
+/// a bare `u32` for this makes it easy to pass `0` meaning "first parameter" when it actually
+/// means "return value". `AttributeIndex` makes the three cases explicit and converts to the raw
+/// LLVM encoding internally.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AttributeIndex {
+    /// The function's return value.
+    Return,
+    /// The function itself (as opposed to one of its parameters).
+    Function,
+    /// The zero-based parameter at the given index.
+    Param(u32),
+}
+
+impl From<AttributeIndex> for u32 {
+    fn from(idx: AttributeIndex) -> Self {
+        match idx {
+            AttributeIndex::Return => 0,
+            AttributeIndex::Function => u32::MAX,
+            AttributeIndex::Param(n) => n + 1,
+        }
+    }
+}
+
 /// Removes a function from its containing module and deallocates it.
 ///
 /// This function wraps the `LLVMDeleteFunction` function from the LLVM core library. It removes the function
@@ -98,10 +126,10 @@ pub fn set_personality_fn(val: &ValueRef, personality_fn: &ValueRef) {
 /// matching intrinsic exists within the module.
 #[must_use]
 pub fn lookup_intrinsic_id(name: &str) -> Option<u32> {
-    let c_string = CString::from(name);
+    let c_string = CString::try_from(name).expect("string contains an interior NUL byte");
     unsafe {
         let id =
-            core::LLVMLookupIntrinsicID(c_string.as_ptr(), *SizeT::from(c_string.count_bytes()));
+            core::LLVMLookupIntrinsicID(c_string.as_ptr(), *SizeT::try_from(c_string.count_bytes()).expect("value does not fit in size_t"));
         if id == 0 {
             None
         } else {
@@ -147,14 +175,14 @@ pub fn get_intrinsic_id(val: &ValueRef) -> u32 {
 /// cannot be created or retrieved, the returned `ValueRef` may be null, so users should ensure that the
 /// declaration was successfully obtained.
 #[must_use]
-pub fn get_intrinsic_declaration(m: &ModuleRef, id: u32, param_types: &[TypeRef]) -> ValueRef {
+pub fn get_intrinsic_declaration(m: &ModuleRef, id: u32, param_types: &[TypeRef<'_>]) -> ValueRef {
     let param_types_ptr = crate::to_mut_ptr!(param_types);
     unsafe {
         let intrinsic = core::LLVMGetIntrinsicDeclaration(
             m.get_ref(),
-            *CUint::from(id),
+            *CUint::try_from(id).expect("value does not fit in c_uint"),
             param_types_ptr,
-            *SizeT::from(param_types.len()),
+            *SizeT::try_from(param_types.len()).expect("value does not fit in size_t"),
         );
         ValueRef(intrinsic)
     }
@@ -179,14 +207,14 @@ pub fn get_intrinsic_declaration(m: &ModuleRef, id: u32, param_types: &[TypeRef]
 /// Returns an `LLVMTypeRef` representing the type of the intrinsic. If the intrinsic does not exist or the
 /// parameter types do not match any overload, the returned type may be null.
 #[must_use]
-pub fn intrinsic_get_type(ctx: &ContextRef, id: u32, param_types: &[TypeRef]) -> TypeRef {
+pub fn intrinsic_get_type<'ctx>(ctx: &'ctx ContextRef, id: u32, param_types: &[TypeRef<'_>]) -> TypeRef<'ctx> {
     let param_types_ptr = crate::to_mut_ptr!(param_types);
     unsafe {
         let type_ref = core::LLVMIntrinsicGetType(
             ctx.get_ref(),
-            *CUint::from(id),
+            *CUint::try_from(id).expect("value does not fit in c_uint"),
             param_types_ptr,
-            *SizeT::from(param_types.len()),
+            *SizeT::try_from(param_types.len()).expect("value does not fit in size_t"),
         );
         TypeRef::from(type_ref)
     }
@@ -211,6 +239,32 @@ pub fn intrinsic_get_name(id: u32, name_length: &mut usize) -> *const i8 {
     unsafe { core::LLVMIntrinsicGetName(id, name_length) }
 }
 
+/// Retrieves the name of an intrinsic as an owned, safe `String`.
+///
+/// This is the safe counterpart to `intrinsic_get_name`. Rather than handing back the raw,
+/// non-null-terminated `(*const i8, length)` pair from the LLVM C ABI, it reconstructs the string
+/// from the returned pointer and length and returns an owned `String`. The underlying name is
+/// owned by LLVM and must NOT be freed, unlike the string returned by
+/// `intrinsic_copy_overloaded_name2`.
+///
+/// # Parameters
+///
+/// - `id`: The intrinsic ID (`u32`) corresponding to the desired intrinsic function.
+///
+/// # Returns
+///
+/// Returns `None` if LLVM has no name for the given `id`.
+#[must_use]
+pub fn intrinsic_name(id: u32) -> Option<String> {
+    let mut length: usize = 0;
+    let c_str = intrinsic_get_name(id, &mut length);
+    if c_str.is_null() {
+        return None;
+    }
+    let bytes = unsafe { std::slice::from_raw_parts(c_str.cast::<u8>(), length) };
+    Some(String::from_utf8_lossy(bytes).into_owned())
+}
+
 /// Copies the name of an overloaded intrinsic identified by a given list of
 /// parameter types.
 ///
@@ -236,17 +290,17 @@ pub fn intrinsic_get_name(id: u32, name_length: &mut usize) -> *const i8 {
 pub fn intrinsic_copy_overloaded_name2(
     m: &ModuleRef,
     id: u32,
-    param_types: &[TypeRef],
+    param_types: &[TypeRef<'_>],
 ) -> Option<String> {
     let param_types_ptr = crate::to_mut_ptr!(param_types);
     unsafe {
-        let mut length = *SizeT::from(0_usize);
+        let mut length = *SizeT::try_from(0_usize).expect("value does not fit in size_t");
 
         let c_str = core::LLVMIntrinsicCopyOverloadedName2(
             m.get_ref(),
             id,
             param_types_ptr,
-            *SizeT::from(param_types.len()),
+            *SizeT::try_from(param_types.len()).expect("value does not fit in size_t"),
             &mut length,
         );
         if c_str.is_null() {
@@ -270,7 +324,160 @@ pub fn intrinsic_copy_overloaded_name2(
 /// Returns `true` if the intrinsic is overloaded, or `false` otherwise.
 #[must_use]
 pub fn intrinsic_is_overloaded(id: u32) -> bool {
-    unsafe { core::LLVMIntrinsicIsOverloaded(*CUint::from(id)) != 0 }
+    unsafe { core::LLVMIntrinsicIsOverloaded(*CUint::try_from(id).expect("value does not fit in c_uint")) != 0 }
+}
+
+/// High-level wrapper around an LLVM intrinsic ID.
+///
+/// The loose `intrinsic_*`/`*_intrinsic_*` functions in this module all thread a bare `u32` ID
+/// through the LLVM C API. `Intrinsic` bundles that ID with ergonomic methods so the scattered
+/// ID-passing turns into one coherent, hard-to-misuse API.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Intrinsic(u32);
+
+impl Intrinsic {
+    /// Looks up an intrinsic by its function name, e.g. `"llvm.sadd.with.overflow"`.
+    #[must_use]
+    pub fn find(name: &str) -> Option<Self> {
+        lookup_intrinsic_id(name).map(Self)
+    }
+
+    /// Returns the intrinsic attached to `val`, or `None` if `val` is not an intrinsic function.
+    #[must_use]
+    pub fn from_function(val: &ValueRef) -> Option<Self> {
+        let id = get_intrinsic_id(val);
+        if id == 0 {
+            None
+        } else {
+            Some(Self(id))
+        }
+    }
+
+    /// Returns `true` if this intrinsic is overloaded, i.e. it has multiple versions
+    /// differentiated by their parameter types.
+    #[must_use]
+    pub fn is_overloaded(&self) -> bool {
+        intrinsic_is_overloaded(self.0)
+    }
+
+    /// Returns the name of this intrinsic as an owned `String`.
+    #[must_use]
+    pub fn name(&self) -> Option<String> {
+        intrinsic_name(self.0)
+    }
+
+    /// Returns the name of this intrinsic for the given overload, identified by `param_types`.
+    #[must_use]
+    pub fn overloaded_name(&self, module: &ModuleRef, param_types: &[TypeRef<'_>]) -> Option<String> {
+        intrinsic_copy_overloaded_name2(module, self.0, param_types)
+    }
+
+    /// Returns the type of this intrinsic. For overloaded intrinsics, `param_types` must be
+    /// provided to uniquely identify the desired overload.
+    #[must_use]
+    pub fn get_type<'ctx>(&self, ctx: &'ctx ContextRef, param_types: &[TypeRef<'_>]) -> TypeRef<'ctx> {
+        intrinsic_get_type(ctx, self.0, param_types)
+    }
+
+    /// Creates or inserts the declaration of this intrinsic within `module`. For overloaded
+    /// intrinsics, `param_types` must be provided to uniquely identify the desired overload.
+    #[must_use]
+    pub fn get_declaration(&self, module: &ModuleRef, param_types: &[TypeRef<'_>]) -> ValueRef {
+        get_intrinsic_declaration(module, self.0, param_types)
+    }
+}
+
+/// Represents an LLVM calling convention.
+///
+/// LLVM identifies calling conventions by a raw numeric ID. `CallingConvention` gives the common
+/// conventions names so callers don't have to memorize LLVM's numeric IDs, while `Other` preserves
+/// forward-compatibility with conventions not covered by a named variant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CallingConvention {
+    /// The default C calling convention.
+    C,
+    /// Fast calling convention, attempts to make calls as fast as possible.
+    Fast,
+    /// Cold calling convention, used for code that is rarely called.
+    Cold,
+    /// Calling convention used by the Glasgow Haskell Compiler (GHC).
+    GHC,
+    /// Calling convention used by the High-Performance Erlang (HiPE) compiler.
+    HiPE,
+    /// `WebKit` JS calling convention.
+    WebKitJS,
+    /// Calling convention for stack-based JIT calls that preserve all registers.
+    AnyReg,
+    /// Calling convention for runtime calls that preserves most registers.
+    PreserveMost,
+    /// Calling convention for runtime calls that preserves all registers.
+    PreserveAll,
+    /// Swift calling convention.
+    Swift,
+    /// Calling convention for access functions, supports tail calls.
+    Tail,
+    /// `x86` `stdcall` calling convention.
+    X86Stdcall,
+    /// `x86` `fastcall` calling convention.
+    X86Fastcall,
+    /// `x86_64` System V calling convention.
+    X86_64SysV,
+    /// `x86_64` Win64 calling convention.
+    X86_64Win64,
+    /// ARM APCS calling convention.
+    ARMAPCS,
+    /// Any other calling convention identified by its raw LLVM numeric ID.
+    Other(u32),
+}
+
+impl CallingConvention {
+    /// Builds a `CallingConvention` from LLVM's raw numeric calling-convention ID.
+    #[must_use]
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            0 => Self::C,
+            8 => Self::Fast,
+            9 => Self::Cold,
+            10 => Self::GHC,
+            11 => Self::HiPE,
+            12 => Self::WebKitJS,
+            13 => Self::AnyReg,
+            14 => Self::PreserveMost,
+            15 => Self::PreserveAll,
+            16 => Self::Swift,
+            17 => Self::Tail,
+            64 => Self::X86Stdcall,
+            65 => Self::X86Fastcall,
+            78 => Self::X86_64SysV,
+            79 => Self::X86_64Win64,
+            66 => Self::ARMAPCS,
+            other => Self::Other(other),
+        }
+    }
+
+    /// Converts this `CallingConvention` back to LLVM's raw numeric calling-convention ID.
+    #[must_use]
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            Self::C => 0,
+            Self::Fast => 8,
+            Self::Cold => 9,
+            Self::GHC => 10,
+            Self::HiPE => 11,
+            Self::WebKitJS => 12,
+            Self::AnyReg => 13,
+            Self::PreserveMost => 14,
+            Self::PreserveAll => 15,
+            Self::Swift => 16,
+            Self::Tail => 17,
+            Self::X86Stdcall => 64,
+            Self::X86Fastcall => 65,
+            Self::X86_64SysV => 78,
+            Self::X86_64Win64 => 79,
+            Self::ARMAPCS => 66,
+            Self::Other(other) => other,
+        }
+    }
 }
 
 /// Obtains the calling convention of a function.
@@ -305,6 +512,270 @@ pub fn set_function_call_conv(fn_val: &ValueRef, cc: u32) {
     }
 }
 
+/// Obtains the calling convention of a function as a typed `CallingConvention`.
+///
+/// This mirrors `get_function_call_conv`, but decodes the raw numeric ID into a `CallingConvention`.
+///
+/// # Parameters
+///
+/// - `fn_val`: The `ValueRef` representing the function.
+#[must_use]
+pub fn get_calling_convention(fn_val: &ValueRef) -> CallingConvention {
+    CallingConvention::from_u32(get_function_call_conv(fn_val))
+}
+
+/// Sets the calling convention of a function from a typed `CallingConvention`.
+///
+/// This mirrors `set_function_call_conv`, but accepts a `CallingConvention` instead of a raw `u32`.
+///
+/// # Parameters
+///
+/// - `fn_val`: The `ValueRef` representing the function.
+/// - `cc`: The `CallingConvention` to set for the function.
+pub fn set_calling_convention(fn_val: &ValueRef, cc: CallingConvention) {
+    set_function_call_conv(fn_val, cc.to_u32());
+}
+
+/// Obtains the linkage of a function.
+///
+/// This function wraps the `LLVMGetLinkage` function from the LLVM core library. The linkage
+/// type determines how the function's symbol is treated during linking, such as whether it is
+/// visible to other modules.
+///
+/// # Parameters
+///
+/// - `fn_val`: The `ValueRef` representing the function.
+#[must_use]
+pub fn get_function_linkage(fn_val: &ValueRef) -> Linkage {
+    unsafe { Linkage::from(core::LLVMGetLinkage(fn_val.0)) }
+}
+
+/// Sets the linkage of a function.
+///
+/// This function wraps the `LLVMSetLinkage` function from the LLVM core library.
+///
+/// # Parameters
+///
+/// - `fn_val`: The `ValueRef` representing the function.
+/// - `linkage`: The `Linkage` to set for the function.
+pub fn set_function_linkage(fn_val: &ValueRef, linkage: Linkage) {
+    unsafe {
+        core::LLVMSetLinkage(fn_val.0, linkage.into());
+    }
+}
+
+/// Obtains the visibility of a function.
+///
+/// This function wraps the `LLVMGetVisibility` function from the LLVM core library. Visibility
+/// determines whether the function's symbol can be seen by other modules or shared libraries.
+///
+/// # Parameters
+///
+/// - `fn_val`: The `ValueRef` representing the function.
+#[must_use]
+pub fn get_function_visibility(fn_val: &ValueRef) -> Visibility {
+    unsafe { Visibility::from(core::LLVMGetVisibility(fn_val.0)) }
+}
+
+/// Sets the visibility of a function.
+///
+/// This function wraps the `LLVMSetVisibility` function from the LLVM core library.
+///
+/// # Parameters
+///
+/// - `fn_val`: The `ValueRef` representing the function.
+/// - `visibility`: The `Visibility` to set for the function.
+pub fn set_function_visibility(fn_val: &ValueRef, visibility: Visibility) {
+    unsafe {
+        core::LLVMSetVisibility(fn_val.0, visibility.into());
+    }
+}
+
+/// Obtains the DLL storage class of a function.
+///
+/// This function wraps the `LLVMGetDLLStorageClass` function from the LLVM core library. The DLL
+/// storage class determines how the function is treated with respect to dynamic link libraries on
+/// platforms like Windows.
+///
+/// # Parameters
+///
+/// - `fn_val`: The `ValueRef` representing the function.
+#[must_use]
+pub fn get_function_dll_storage_class(fn_val: &ValueRef) -> DLLStorageClass {
+    unsafe { DLLStorageClass::from(core::LLVMGetDLLStorageClass(fn_val.0)) }
+}
+
+/// Sets the DLL storage class of a function.
+///
+/// This function wraps the `LLVMSetDLLStorageClass` function from the LLVM core library.
+///
+/// # Parameters
+///
+/// - `fn_val`: The `ValueRef` representing the function.
+/// - `class`: The `DLLStorageClass` to set for the function.
+pub fn set_function_dll_storage_class(fn_val: &ValueRef, class: DLLStorageClass) {
+    unsafe {
+        core::LLVMSetDLLStorageClass(fn_val.0, class.into());
+    }
+}
+
+/// Obtains the section in which a function is placed.
+///
+/// This function wraps the `LLVMGetSection` function from the LLVM core library.
+///
+/// # Parameters
+///
+/// - `fn_val`: The `ValueRef` representing the function.
+///
+/// # Returns
+///
+/// Returns `Some(String)` with the section name if the function is placed in a specific section,
+/// or `None` if it is not associated with any section.
+#[must_use]
+pub fn get_function_section(fn_val: &ValueRef) -> Option<String> {
+    unsafe {
+        let c_str = core::LLVMGetSection(fn_val.0);
+        if c_str.is_null() {
+            return None;
+        }
+        Some(CStr::new(c_str).to_string())
+    }
+}
+
+/// Sets the section in which a function should be placed.
+///
+/// This function wraps the `LLVMSetSection` function from the LLVM core library.
+///
+/// # Parameters
+///
+/// - `fn_val`: The `ValueRef` representing the function.
+/// - `section`: The name of the section where the function should be placed.
+pub fn set_function_section(fn_val: &ValueRef, section: &str) {
+    let c_section = CString::try_from(section).expect("string contains an interior NUL byte");
+    unsafe {
+        core::LLVMSetSection(fn_val.0, c_section.as_ptr());
+    }
+}
+
+/// Distinguishes a measured function entry count from one LLVM only estimated.
+///
+/// Mirrors `llvm::Function::ProfileCount::ProfileCountType`: a `Real` count comes from profiling
+/// data (instrumentation or sampling), while a `Synthetic` count is a heuristic estimate used when
+/// no profile is available, e.g. for functions synthesized by the compiler itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProfileCountKind {
+    /// The count was measured by profiling.
+    Real,
+    /// The count was estimated by a heuristic rather than measured.
+    Synthetic,
+}
+
+impl ProfileCountKind {
+    /// The `!prof` metadata tag this kind is recorded under.
+    const fn tag(self) -> &'static str {
+        match self {
+            Self::Real => "function_entry_count",
+            Self::Synthetic => "synthetic_function_entry_count",
+        }
+    }
+}
+
+/// A function's entry execution count, as recorded in its `!prof` metadata.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EntryCount {
+    /// The number of times the function was entered.
+    pub count: u64,
+    /// Whether `count` was measured or estimated.
+    pub kind: ProfileCountKind,
+}
+
+/// Records `fn_val`'s entry execution count as `!prof` metadata.
+///
+/// This attaches a two-operand MDNode tagged `"function_entry_count"` (or
+/// `"synthetic_function_entry_count"` when `synthetic` is `true`) under the `prof` metadata kind,
+/// matching `llvm::Function::setEntryCount`.
+///
+/// # Parameters
+///
+/// - `fn_val`: The `ValueRef` representing the function.
+/// - `count`: The number of times the function is entered.
+/// - `synthetic`: Whether `count` is a heuristic estimate rather than a profiled measurement.
+pub fn set_function_entry_count(fn_val: &ValueRef, count: u64, synthetic: bool) {
+    let kind = if synthetic {
+        ProfileCountKind::Synthetic
+    } else {
+        ProfileCountKind::Real
+    };
+    unsafe {
+        let context = ContextRef::from(core::LLVMGetModuleContext(core::LLVMGetGlobalParent(
+            fn_val.0,
+        )));
+        let prof_kind_id = context.get_md_kind_id_in_context("prof").0;
+
+        let tag = CString::try_from(kind.tag()).expect("string contains an interior NUL byte");
+        let tag_md =
+            core::LLVMMDStringInContext2(context.get_ref(), tag.as_ptr(), tag.to_bytes().len());
+
+        let i64_ty = core::LLVMInt64TypeInContext(context.get_ref());
+        let count_value = core::LLVMConstInt(i64_ty, count, 0);
+        let count_md = core::LLVMValueAsMetadata(count_value);
+
+        let mut operands = [tag_md, count_md];
+        let node =
+            core::LLVMMDNodeInContext2(context.get_ref(), operands.as_mut_ptr(), operands.len());
+        let node_value = core::LLVMMetadataAsValue(context.get_ref(), node);
+
+        core::LLVMSetMetadata(fn_val.0, prof_kind_id, node_value);
+    }
+}
+
+/// Reads back `fn_val`'s entry execution count from its `!prof` metadata, if any was attached via
+/// [`set_function_entry_count`].
+///
+/// # Parameters
+///
+/// - `fn_val`: The `ValueRef` representing the function.
+///
+/// # Returns
+///
+/// Returns `None` if the function has no `!prof` metadata, or if its tag does not match one of the
+/// entry-count tags this crate writes.
+#[must_use]
+pub fn get_function_entry_count(fn_val: &ValueRef) -> Option<EntryCount> {
+    unsafe {
+        let context = ContextRef::from(core::LLVMGetModuleContext(core::LLVMGetGlobalParent(
+            fn_val.0,
+        )));
+        let prof_kind_id = context.get_md_kind_id_in_context("prof").0;
+
+        let node_value = core::LLVMGetMetadata(fn_val.0, prof_kind_id);
+        if node_value.is_null() || core::LLVMGetMDNodeNumOperands(node_value) != 2 {
+            return None;
+        }
+
+        let mut operands: [llvm_sys::prelude::LLVMValueRef; 2] = [std::ptr::null_mut(); 2];
+        core::LLVMGetMDNodeOperands(node_value, operands.as_mut_ptr());
+
+        let mut tag_len = 0;
+        let tag_ptr = core::LLVMGetMDString(operands[0], &mut tag_len);
+        if tag_ptr.is_null() {
+            return None;
+        }
+        let tag = CStr::new(tag_ptr).to_string();
+
+        let kind = if tag == ProfileCountKind::Real.tag() {
+            ProfileCountKind::Real
+        } else if tag == ProfileCountKind::Synthetic.tag() {
+            ProfileCountKind::Synthetic
+        } else {
+            return None;
+        };
+
+        let count = core::LLVMConstIntGetZExtValue(operands[1]);
+        Some(EntryCount { count, kind })
+    }
+}
+
 /// Obtains the name of the garbage collector to use during code generation.
 ///
 /// This function wraps the `LLVMGetGC` function from the LLVM core library. The garbage collector name
@@ -354,7 +825,7 @@ pub fn get_gc(fn_val: &ValueRef) -> Option<String> {
 /// set_gc(function, "my_gc");
 /// ```
 pub fn set_gc(fn_val: &ValueRef, name: &str) {
-    let c_string = CString::from(name);
+    let c_string = CString::try_from(name).expect("string contains an interior NUL byte");
     unsafe {
         core::LLVMSetGC(fn_val.0, c_string.as_ptr());
     }
@@ -508,16 +979,16 @@ pub fn set_prologue_data(fn_val: &ValueRef, prologue_data: &ValueRef) {
 /// # Parameters
 ///
 /// - `fn_val`: The `ValueRef` representing the function.
-/// - `idx`: The `LLVMAttributeIndex` indicating where to add the attribute (e.g., function attributes, return attributes).
+/// - `idx`: The `AttributeIndex` indicating where to add the attribute (e.g., function attributes, return attributes).
 /// - `attr`: The `LLVMAttributeRef` representing the attribute to add.
 ///
 /// # Safety
 ///
 /// - The `ValueRef` must represent a valid function within a module.
 /// - The `LLVMAttributeRef` must represent a valid attribute.
-pub fn add_attribute_at_index(fn_val: &ValueRef, idx: u32, attr: &AttributeRef) {
+pub fn add_attribute_at_index(fn_val: &ValueRef, idx: AttributeIndex, attr: &AttributeRef) {
     unsafe {
-        core::LLVMAddAttributeAtIndex(fn_val.0, *CUint::from(idx), attr.get_ref());
+        core::LLVMAddAttributeAtIndex(fn_val.0, *CUint::try_from(u32::from(idx)).expect("value does not fit in c_uint"), attr.get_ref());
     }
 }
 
@@ -529,7 +1000,7 @@ pub fn add_attribute_at_index(fn_val: &ValueRef, idx: u32, attr: &AttributeRef)
 /// # Parameters
 ///
 /// - `fn_val`: The `ValueRef` representing the function.
-/// - `idx`: The `LLVMAttributeIndex` indicating which attribute set to query.
+/// - `idx`: The `AttributeIndex` indicating which attribute set to query.
 ///
 /// # Returns
 ///
@@ -538,10 +1009,10 @@ pub fn add_attribute_at_index(fn_val: &ValueRef, idx: u32, attr: &AttributeRef)
 /// # Safety
 ///
 /// - The `ValueRef` must represent a valid function within a module.
-/// - The `LLVMAttributeIndex` must be valid for the function.
+/// - The `AttributeIndex` must be valid for the function.
 #[must_use]
-pub fn get_attribute_count_at_index(fn_val: &ValueRef, idx: u32) -> u32 {
-    unsafe { core::LLVMGetAttributeCountAtIndex(fn_val.0, *CUint::from(idx)) }
+pub fn get_attribute_count_at_index(fn_val: &ValueRef, idx: AttributeIndex) -> u32 {
+    unsafe { core::LLVMGetAttributeCountAtIndex(fn_val.0, *CUint::try_from(u32::from(idx)).expect("value does not fit in c_uint")) }
 }
 
 /// Retrieves all attributes at a specified index for a function.
@@ -552,19 +1023,46 @@ pub fn get_attribute_count_at_index(fn_val: &ValueRef, idx: u32) -> u32 {
 /// # Parameters
 ///
 /// - `fn_val`: The `ValueRef` representing the function.
-/// - `idx`: The `LLVMAttributeIndex` indicating which attribute set to query.
+/// - `idx`: The `AttributeIndex` indicating which attribute set to query.
 /// - `attrs`: A mutable slice of `LLVMAttributeRef` where the attributes will be stored.
 ///
 /// # Safety
 ///
 /// - The `ValueRef` must represent a valid function within a module.
-/// - The `LLVMAttributeIndex` must be valid for the function.
+/// - The `AttributeIndex` must be valid for the function.
 /// - The `attrs` slice must be large enough to hold all attributes at the specified index.
-pub fn get_attributes_at_index(fn_val: &ValueRef, idx: u32, attrs: &[AttributeRef]) {
+pub fn get_attributes_at_index(fn_val: &ValueRef, idx: AttributeIndex, attrs: &[AttributeRef]) {
     let attrs_ptr = crate::to_mut_ptr!(attrs);
     unsafe {
-        core::LLVMGetAttributesAtIndex(fn_val.0, *CUint::from(idx), attrs_ptr);
+        core::LLVMGetAttributesAtIndex(fn_val.0, *CUint::try_from(u32::from(idx)).expect("value does not fit in c_uint"), attrs_ptr);
+    }
+}
+
+/// Retrieves all attributes at a specified index for a function as an owned collection.
+///
+/// Unlike `get_attributes_at_index`, which requires the caller to pre-size a slice via
+/// `get_attribute_count_at_index`, this function allocates a correctly sized buffer itself and
+/// returns it as a `Vec`, removing the footgun of an under-sized buffer causing an out-of-bounds
+/// write.
+///
+/// # Parameters
+///
+/// - `fn_val`: The `ValueRef` representing the function.
+/// - `idx`: The `AttributeIndex` indicating which attribute set to query.
+///
+/// # Returns
+///
+/// Returns a `Vec<AttributeRef>` containing all attributes present at the specified index. The
+/// vector is empty if there are none.
+#[must_use]
+pub fn get_all_attributes_at_index(fn_val: &ValueRef, idx: AttributeIndex) -> Vec<AttributeRef> {
+    let count = get_attribute_count_at_index(fn_val, idx) as usize;
+    let mut attrs: Vec<llvm_sys::prelude::LLVMAttributeRef> = Vec::with_capacity(count);
+    unsafe {
+        core::LLVMGetAttributesAtIndex(fn_val.0, *CUint::try_from(u32::from(idx)).expect("value does not fit in c_uint"), attrs.as_mut_ptr());
+        attrs.set_len(count);
     }
+    attrs.into_iter().map(AttributeRef::from).collect()
 }
 
 /// Retrieves an enum attribute at a specified index for a function.
@@ -575,7 +1073,7 @@ pub fn get_attributes_at_index(fn_val: &ValueRef, idx: u32, attrs: &[AttributeRe
 /// # Parameters
 ///
 /// - `fn_val`: The `ValueRef` representing the function.
-/// - `idx`: The `LLVMAttributeIndex` indicating where to retrieve the attribute.
+/// - `idx`: The `AttributeIndex` indicating where to retrieve the attribute.
 /// - `kind_id`: The `unsigned` integer representing the kind of enum attribute to retrieve.
 ///
 /// # Returns
@@ -589,12 +1087,15 @@ pub fn get_attributes_at_index(fn_val: &ValueRef, idx: u32, attrs: &[AttributeRe
 #[must_use]
 pub fn get_enum_attribute_at_index(
     fn_val: &ValueRef,
-    idx: u32,
+    idx: AttributeIndex,
     kind_id: u32,
 ) -> Option<AttributeRef> {
     unsafe {
-        let attr =
-            core::LLVMGetEnumAttributeAtIndex(fn_val.0, *CUint::from(idx), *CUint::from(kind_id));
+        let attr = core::LLVMGetEnumAttributeAtIndex(
+            fn_val.0,
+            *CUint::try_from(u32::from(idx)).expect("value does not fit in c_uint"),
+            *CUint::try_from(kind_id).expect("value does not fit in c_uint"),
+        );
         if attr.is_null() {
             None
         } else {
@@ -611,7 +1112,7 @@ pub fn get_enum_attribute_at_index(
 /// # Parameters
 ///
 /// - `fn_val`: The `ValueRef` representing the function.
-/// - `idx`: The `LLVMAttributeIndex` indicating where to retrieve the attribute.
+/// - `idx`: The `AttributeIndex` indicating where to retrieve the attribute.
 /// - `key`: The key (`&str`) identifying the string attribute.
 ///
 /// # Returns
@@ -625,16 +1126,16 @@ pub fn get_enum_attribute_at_index(
 #[must_use]
 pub fn get_string_attribute_at_index(
     fn_val: &ValueRef,
-    idx: u32,
+    idx: AttributeIndex,
     key: &str,
 ) -> Option<AttributeRef> {
-    let c_key = CString::from(key);
+    let c_key = CString::try_from(key).expect("string contains an interior NUL byte");
     unsafe {
         let attr = core::LLVMGetStringAttributeAtIndex(
             fn_val.0,
-            *CUint::from(idx),
+            *CUint::try_from(u32::from(idx)).expect("value does not fit in c_uint"),
             c_key.as_ptr(),
-            *CUint::from(c_key.count_bytes()),
+            *CUint::try_from(c_key.count_bytes()).expect("value does not fit in c_uint"),
         );
         if attr.is_null() {
             None
@@ -652,16 +1153,20 @@ pub fn get_string_attribute_at_index(
 /// # Parameters
 ///
 /// - `fn_val`: The `ValueRef` representing the function.
-/// - `idx`: The `LLVMAttributeIndex` indicating where to remove the attribute.
+/// - `idx`: The `AttributeIndex` indicating where to remove the attribute.
 /// - `kind_id`: The `unsigned` integer representing the kind of enum attribute to remove.
 ///
 /// # Safety
 ///
 /// - The `ValueRef` must represent a valid function within a module.
 /// - The `KindID` must correspond to a valid enum attribute.
-pub fn remove_enum_attribute_at_index(fn_val: &ValueRef, idx: u32, kind_id: u32) {
+pub fn remove_enum_attribute_at_index(fn_val: &ValueRef, idx: AttributeIndex, kind_id: u32) {
     unsafe {
-        core::LLVMRemoveEnumAttributeAtIndex(fn_val.0, *CUint::from(idx), *CUint::from(kind_id));
+        core::LLVMRemoveEnumAttributeAtIndex(
+            fn_val.0,
+            *CUint::try_from(u32::from(idx)).expect("value does not fit in c_uint"),
+            *CUint::try_from(kind_id).expect("value does not fit in c_uint"),
+        );
     }
 }
 
@@ -673,21 +1178,21 @@ pub fn remove_enum_attribute_at_index(fn_val: &ValueRef, idx: u32, kind_id: u32)
 /// # Parameters
 ///
 /// - `fn_val`: The `ValueRef` representing the function.
-/// - `idx`: The `LLVMAttributeIndex` indicating where to remove the attribute.
+/// - `idx`: The `AttributeIndex` indicating where to remove the attribute.
 /// - `key`: The key (`&str`) identifying the string attribute to remove.
 ///
 /// # Safety
 ///
 /// - The `ValueRef` must represent a valid function within a module.
 /// - The `key` must not contain null bytes.
-pub fn remove_string_attribute_at_index(fn_val: &ValueRef, idx: u32, key: &str) {
-    let c_key = CString::from(key);
+pub fn remove_string_attribute_at_index(fn_val: &ValueRef, idx: AttributeIndex, key: &str) {
+    let c_key = CString::try_from(key).expect("string contains an interior NUL byte");
     unsafe {
         core::LLVMRemoveStringAttributeAtIndex(
             fn_val.0,
-            *CUint::from(idx),
+            *CUint::try_from(u32::from(idx)).expect("value does not fit in c_uint"),
             c_key.as_ptr(),
-            *CUint::from(c_key.count_bytes()),
+            *CUint::try_from(c_key.count_bytes()).expect("value does not fit in c_uint"),
         );
     }
 }
@@ -710,9 +1215,71 @@ pub fn remove_string_attribute_at_index(fn_val: &ValueRef, idx: u32, key: &str)
 /// - The `attribute` and `value` strings must not contain null bytes.
 ///
 pub fn add_target_dependent_function_attr(fn_val: &ValueRef, attribute: &str, value: &str) {
-    let attr_cstr = CString::from(attribute);
-    let value_cstr = CString::from(value);
+    let attr_cstr = CString::try_from(attribute).expect("string contains an interior NUL byte");
+    let value_cstr = CString::try_from(value).expect("string contains an interior NUL byte");
     unsafe {
         core::LLVMAddTargetDependentFunctionAttr(fn_val.0, attr_cstr.as_ptr(), value_cstr.as_ptr());
     }
 }
+
+/// A snapshot of a function's properties, mirroring the shape of llvm-ir's `Function` record.
+///
+/// Inspecting or cloning a function's properties otherwise means orchestrating a dozen separate
+/// FFI calls (`get_function_call_conv`, `get_gc`, `get_personality_fn`, `get_prefix_data`, ...).
+/// `FunctionInfo` snapshots all of them into one ergonomic value via `read`, which can be
+/// inspected, diffed, or written back onto a (possibly different) function via `apply`.
+pub struct FunctionInfo {
+    /// The function's calling convention.
+    pub calling_convention: CallingConvention,
+    /// The name of the garbage collector strategy used by the function, if any.
+    pub gc: Option<String>,
+    /// The personality function attached to the function, if any.
+    pub personality_fn: Option<ValueRef>,
+    /// The prefix data attached to the function, if any.
+    pub prefix_data: Option<ValueRef>,
+    /// The prologue data attached to the function, if any.
+    pub prologue_data: Option<ValueRef>,
+    /// The function-level attributes (`AttributeIndex::Function`).
+    pub function_attributes: Vec<AttributeRef>,
+    /// The return-value attributes (`AttributeIndex::Return`).
+    pub return_attributes: Vec<AttributeRef>,
+}
+
+impl FunctionInfo {
+    /// Snapshots the properties of `fn_val` into a `FunctionInfo`.
+    #[must_use]
+    pub fn read(fn_val: &ValueRef) -> Self {
+        Self {
+            calling_convention: get_calling_convention(fn_val),
+            gc: get_gc(fn_val),
+            personality_fn: get_personality_fn(fn_val),
+            prefix_data: get_prefix_data(fn_val),
+            prologue_data: get_prologue_data(fn_val),
+            function_attributes: get_all_attributes_at_index(fn_val, AttributeIndex::Function),
+            return_attributes: get_all_attributes_at_index(fn_val, AttributeIndex::Return),
+        }
+    }
+
+    /// Writes the snapshotted, mutable properties of this `FunctionInfo` back onto `fn_val`.
+    pub fn apply(&self, fn_val: &ValueRef) {
+        set_calling_convention(fn_val, self.calling_convention);
+        if let Some(gc) = &self.gc {
+            set_gc(fn_val, gc);
+        }
+        if let Some(personality_fn) = &self.personality_fn {
+            set_personality_fn(fn_val, personality_fn);
+        }
+        if let Some(prefix_data) = &self.prefix_data {
+            set_prefix_data(fn_val, prefix_data);
+        }
+        if let Some(prologue_data) = &self.prologue_data {
+            set_prologue_data(fn_val, prologue_data);
+        }
+        for attr in &self.function_attributes {
+            add_attribute_at_index(fn_val, AttributeIndex::Function, attr);
+        }
+        for attr in &self.return_attributes {
+            add_attribute_at_index(fn_val, AttributeIndex::Return, attr);
+        }
+    }
+}
@@ -21,7 +21,7 @@ use llvm_sys::core;
 ///
 /// Returns a `TypeRef` that represents the LLVM type of the value.
 #[must_use]
-pub fn type_of(val: &ValueRef) -> TypeRef {
+pub fn type_of(val: &ValueRef) -> TypeRef<'_> {
     unsafe { TypeRef::from(core::LLVMTypeOf(val.get_ref())) }
 }
 
@@ -62,7 +62,7 @@ pub fn get_value_kind(val: &ValueRef) -> ValueKind {
 #[must_use]
 pub fn get_value_name(val: &ValueRef) -> Option<String> {
     unsafe {
-        let mut length = SizeT::from(0);
+        let mut length = SizeT::try_from(0).expect("value does not fit in size_t");
         let c_str = core::LLVMGetValueName2(val.get_ref(), &mut *length);
         if c_str.is_null() {
             return None;
@@ -95,9 +95,9 @@ pub fn get_value_name(val: &ValueRef) -> Option<String> {
 ///
 /// After calling this function, the value will be named "`my_value_name`" in the LLVM IR.
 pub fn set_value_name(val: &ValueRef, name: &str) {
-    let c_string = CString::from(name);
+    let c_string = CString::try_from(name).expect("string contains an interior NUL byte");
     unsafe {
-        core::LLVMSetValueName2(val.get_ref(), c_string.as_ptr(), *SizeT::from(name.len()));
+        core::LLVMSetValueName2(val.get_ref(), c_string.as_ptr(), *SizeT::try_from(name.len()).expect("value does not fit in size_t"));
     }
 }
 
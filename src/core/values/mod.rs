@@ -1,6 +1,6 @@
-use crate::core::module::InlineAsmDialect;
+use crate::core::module::{InlineAsmDialect, MetadataRef};
 use crate::core::types::TypeRef;
-use crate::{CStr, CUint, GetRef, SizeT};
+use crate::{CInt, CStr, CString, CUint, GetRef, SizeT};
 use llvm_sys::core;
 use llvm_sys::prelude::LLVMValueRef;
 use llvm_sys::LLVMValueKind;
@@ -136,6 +136,58 @@ impl From<LLVMValueRef> for ValueRef {
 
 /// That implementations related to LLVM Modules `MemoryDef`.
 impl ValueRef {
+    /// Create an inline assembly value of the given function type.
+    ///
+    /// # Details
+    ///
+    /// This function wraps the `LLVMGetInlineAsm` function from the LLVM core library. It
+    /// constructs a new inline assembly snippet with the given assembly template (`asm`) and
+    /// operand constraint string (`constraints`), typed according to `fn_type`. The resulting
+    /// value is the counterpart that the `get_inline_asm_*` accessors on this type read back:
+    /// `get_inline_asm_asm_string` returns `asm`, `get_inline_asm_constraint_string` returns
+    /// `constraints`, `get_inline_asm_function_type` returns `fn_type`, and so on for the
+    /// remaining flags.
+    ///
+    /// # Parameters
+    ///
+    /// - `fn_type`: The function type describing the inline assembly's operand and result types.
+    /// - `asm`: The assembly template string.
+    /// - `constraints`: The operand constraint string.
+    /// - `has_side_effects`: Whether the assembly has side effects.
+    /// - `needs_aligned_stack`: Whether the assembly requires a stack alignment.
+    /// - `dialect`: The assembly dialect (AT&T or Intel).
+    /// - `can_unwind`: Whether the assembly may unwind the stack.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `ValueRef` representing the newly created inline assembly value.
+    #[must_use]
+    pub fn get_inline_asm(
+        fn_type: &TypeRef<'_>,
+        asm: &str,
+        constraints: &str,
+        has_side_effects: bool,
+        needs_aligned_stack: bool,
+        dialect: InlineAsmDialect,
+        can_unwind: bool,
+    ) -> Self {
+        let c_asm = CString::try_from(asm).expect("string contains an interior NUL byte");
+        let c_constraints = CString::try_from(constraints).expect("string contains an interior NUL byte");
+        unsafe {
+            Self(core::LLVMGetInlineAsm(
+                fn_type.get_ref(),
+                c_asm.as_ptr(),
+                *SizeT::try_from(c_asm.to_bytes().len()).expect("value does not fit in size_t"),
+                c_constraints.as_ptr(),
+                *SizeT::try_from(c_constraints.to_bytes().len()).expect("value does not fit in size_t"),
+                *CInt::from(has_side_effects),
+                *CInt::from(needs_aligned_stack),
+                dialect.into(),
+                *CInt::from(can_unwind),
+            ))
+        }
+    }
+
     /// Get the template string used for an inline assembly snippet.
     ///
     /// # Details
@@ -156,7 +208,7 @@ impl ValueRef {
     #[must_use]
     pub fn get_inline_asm_asm_string(&self) -> Option<String> {
         unsafe {
-            let mut length = SizeT::from(0_usize);
+            let mut length = SizeT::try_from(0_usize).expect("value does not fit in size_t");
             let c_str = core::LLVMGetInlineAsmAsmString(self.0, &mut *length);
             if c_str.is_null() {
                 return None;
@@ -185,7 +237,7 @@ impl ValueRef {
     #[must_use]
     pub fn get_inline_asm_constraint_string(&self) -> Option<String> {
         unsafe {
-            let mut length = SizeT::from(0_usize);
+            let mut length = SizeT::try_from(0_usize).expect("value does not fit in size_t");
             let c_str = core::LLVMGetInlineAsmConstraintString(self.0, &mut *length);
             if c_str.is_null() {
                 return None;
@@ -230,7 +282,7 @@ impl ValueRef {
     ///
     /// Returns a `TypeRef` that represents the function type of the inline assembly block.
     #[must_use]
-    pub fn get_inline_asm_function_type(&self) -> TypeRef {
+    pub fn get_inline_asm_function_type(&self) -> TypeRef<'_> {
         TypeRef::from(unsafe { core::LLVMGetInlineAsmFunctionType(self.0) })
     }
 
@@ -309,7 +361,7 @@ impl ValueRef {
     #[must_use]
     pub fn get_debug_loc_directory(&self) -> Option<String> {
         unsafe {
-            let mut length = CUint::from(0_usize);
+            let mut length = CUint::try_from(0_usize).expect("value does not fit in c_uint");
             let c_str = core::LLVMGetDebugLocDirectory(self.0, &mut *length);
             if c_str.is_null() {
                 return None;
@@ -337,7 +389,7 @@ impl ValueRef {
     #[must_use]
     pub fn get_debug_loc_filename(&self) -> Option<String> {
         unsafe {
-            let mut length = CUint::from(0_usize);
+            let mut length = CUint::try_from(0_usize).expect("value does not fit in c_uint");
             let c_str = core::LLVMGetDebugLocFilename(self.0, &mut *length);
             if c_str.is_null() {
                 return None;
@@ -384,6 +436,37 @@ impl ValueRef {
         unsafe { core::LLVMGetDebugLocColumn(self.0) }
     }
 
+    /// Attach `loc` as the debug location of this value, which must be an LLVM `Instruction`.
+    ///
+    /// This function wraps the `LLVMInstructionSetDebugLoc` function from the LLVM core library.
+    /// `loc` is typically a `DILocation` node produced by
+    /// [`DebugInfoBuilder::create_debug_location`](crate::core::debug_info::DebugInfoBuilder::create_debug_location),
+    /// after which this instruction's `get_debug_loc_*` accessors reflect `loc`'s line, column and
+    /// file.
+    pub fn set_debug_loc(&self, loc: &MetadataRef) {
+        unsafe { core::LLVMInstructionSetDebugLoc(self.0, loc.get_ref()) }
+    }
+
+    /// Return the `DILocation` metadata node attached to this value, which must be an LLVM
+    /// `Instruction`.
+    ///
+    /// This function wraps the `LLVMInstructionGetDebugLoc` function from the LLVM core library.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` if no debug location is attached.
+    #[must_use]
+    pub fn get_debug_loc(&self) -> Option<MetadataRef> {
+        unsafe {
+            let metadata = core::LLVMInstructionGetDebugLoc(self.0);
+            if metadata.is_null() {
+                None
+            } else {
+                Some(MetadataRef::from(metadata))
+            }
+        }
+    }
+
     /// Advance a `Function` iterator to the next Function.
     ///
     /// Returns `None` if the iterator was already at the end and there are no more functions.
@@ -441,4 +524,357 @@ impl ValueRef {
             }
         }
     }
+
+    /// Returns the kind of the given LLVM value.
+    ///
+    /// # Details
+    ///
+    /// This method wraps the `LLVMGetValueKind` function from the LLVM core library. It returns a `ValueKind`
+    /// enumeration that identifies the specific kind of the value, such as whether it is an instruction, a constant,
+    /// a global variable, a function, etc. It underlies [`Self::classify`], which turns this raw kind into a
+    /// statically typed view of the value.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `ValueKind` enumeration that represents the kind of the value.
+    #[must_use]
+    pub fn get_value_kind(&self) -> ValueKind {
+        unsafe { ValueKind::from(core::LLVMGetValueKind(self.0)) }
+    }
+
+    /// Downcast this value into a statically typed [`Value`] variant based on its [`ValueKind`].
+    ///
+    /// # Details
+    ///
+    /// Many LLVM APIs are only meaningful for a specific kind of value (for example, inline assembly
+    /// accessors only make sense on values for which [`ValueKind::InlineAsm`] holds). Rather than requiring
+    /// every caller to check [`Self::get_value_kind`] before calling such an API, `classify` consumes the
+    /// generic `ValueRef` once and returns a [`Value`] enum whose variants wrap it in a kind-specific newtype.
+    /// Kinds without a dedicated variant are preserved in [`Value::Other`] together with their [`ValueKind`],
+    /// so no information is lost.
+    ///
+    /// # Returns
+    ///
+    /// Returns the [`Value`] variant matching this value's [`ValueKind`].
+    #[must_use]
+    pub fn classify(self) -> Value {
+        match self.get_value_kind() {
+            ValueKind::Function => Value::Function(FunctionValue(self)),
+            ValueKind::GlobalVariable => Value::GlobalVariable(GlobalVariableValue(self)),
+            ValueKind::Instruction => Value::Instruction(InstructionValue(self)),
+            ValueKind::ConstantInt => Value::ConstantInt(ConstantIntValue(self)),
+            ValueKind::InlineAsm => Value::InlineAsm(InlineAsmValue(self)),
+            kind => Value::Other(self, kind),
+        }
+    }
+
+    /// Attempt to downcast this value into the concrete kind `T`, using the `LLVMIsA*`
+    /// check that `T` wraps.
+    ///
+    /// # Details
+    ///
+    /// Unlike [`Self::classify`], which dispatches once on [`ValueKind`], `dyn_cast` goes
+    /// straight to the `LLVMIsA*` family of checked casts: each of these returns the value
+    /// itself when it is (or derives from) the requested LLVM class, or a null pointer
+    /// otherwise. This mirrors LLVM's own `isa`/`dyn_cast` idiom and lets callers target a
+    /// specific wrapper (for example [`ConstantFPValue`]) without routing through `ValueKind`
+    /// first.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(T)` if this value is an instance of `T`, otherwise `None`.
+    #[must_use]
+    pub fn dyn_cast<T: FromValue>(&self) -> Option<T> {
+        T::from_value(self)
+    }
+
+    /// Returns whether this value is an instance of the concrete kind `T`.
+    ///
+    /// # Details
+    ///
+    /// Equivalent to `self.dyn_cast::<T>().is_some()`, provided for callers that only need
+    /// the predicate and not the downcast value itself.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if this value is an instance of `T`, otherwise `false`.
+    #[must_use]
+    pub fn is_a<T: FromValue>(&self) -> bool {
+        T::from_value(self).is_some()
+    }
+}
+
+/// A marker type that can be safely downcast from a generic [`ValueRef`].
+///
+/// Each implementor corresponds to one of LLVM's `LLVMIsA*` checked casts, which returns
+/// the value itself when it is (or derives from) the requested class, or a null pointer
+/// otherwise. [`ValueRef::dyn_cast`] and [`ValueRef::is_a`] are the intended entry points;
+/// implementing this trait directly is only needed when adding a new downcast target.
+pub trait FromValue: Sized {
+    /// Attempt the downcast, returning `None` if `val` is not an instance of this kind.
+    fn from_value(val: &ValueRef) -> Option<Self>;
+}
+
+/// A [`ValueRef`] statically known (via [`ValueRef::classify`]) to have [`ValueKind::Function`].
+///
+/// Derefs to the underlying [`ValueRef`], so every generic value accessor remains available.
+#[derive(Debug)]
+pub struct FunctionValue(ValueRef);
+
+impl Deref for FunctionValue {
+    type Target = ValueRef;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl GetRef for FunctionValue {
+    type RawRef = LLVMValueRef;
+    fn get_ref(&self) -> Self::RawRef {
+        self.0.get_ref()
+    }
+}
+
+impl FromValue for FunctionValue {
+    fn from_value(val: &ValueRef) -> Option<Self> {
+        let raw = unsafe { core::LLVMIsAFunction(val.get_ref()) };
+        if raw.is_null() { None } else { Some(Self(ValueRef(raw))) }
+    }
+}
+
+/// A [`ValueRef`] statically known (via [`ValueRef::classify`]) to have [`ValueKind::GlobalVariable`].
+///
+/// Derefs to the underlying [`ValueRef`], so every generic value accessor remains available.
+#[derive(Debug)]
+pub struct GlobalVariableValue(ValueRef);
+
+impl Deref for GlobalVariableValue {
+    type Target = ValueRef;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl GetRef for GlobalVariableValue {
+    type RawRef = LLVMValueRef;
+    fn get_ref(&self) -> Self::RawRef {
+        self.0.get_ref()
+    }
+}
+
+impl FromValue for GlobalVariableValue {
+    fn from_value(val: &ValueRef) -> Option<Self> {
+        let raw = unsafe { core::LLVMIsAGlobalVariable(val.get_ref()) };
+        if raw.is_null() { None } else { Some(Self(ValueRef(raw))) }
+    }
+}
+
+/// A [`ValueRef`] statically known (via [`ValueRef::classify`]) to have [`ValueKind::Instruction`].
+///
+/// Derefs to the underlying [`ValueRef`], so every generic value accessor remains available.
+#[derive(Debug)]
+pub struct InstructionValue(ValueRef);
+
+impl Deref for InstructionValue {
+    type Target = ValueRef;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl GetRef for InstructionValue {
+    type RawRef = LLVMValueRef;
+    fn get_ref(&self) -> Self::RawRef {
+        self.0.get_ref()
+    }
+}
+
+impl FromValue for InstructionValue {
+    fn from_value(val: &ValueRef) -> Option<Self> {
+        let raw = unsafe { core::LLVMIsAInstruction(val.get_ref()) };
+        if raw.is_null() { None } else { Some(Self(ValueRef(raw))) }
+    }
+}
+
+/// A [`ValueRef`] statically known (via [`ValueRef::classify`]) to have [`ValueKind::ConstantInt`].
+///
+/// Derefs to the underlying [`ValueRef`], so every generic value accessor remains available.
+#[derive(Debug)]
+pub struct ConstantIntValue(ValueRef);
+
+impl Deref for ConstantIntValue {
+    type Target = ValueRef;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl GetRef for ConstantIntValue {
+    type RawRef = LLVMValueRef;
+    fn get_ref(&self) -> Self::RawRef {
+        self.0.get_ref()
+    }
+}
+
+impl FromValue for ConstantIntValue {
+    fn from_value(val: &ValueRef) -> Option<Self> {
+        let raw = unsafe { core::LLVMIsAConstantInt(val.get_ref()) };
+        if raw.is_null() { None } else { Some(Self(ValueRef(raw))) }
+    }
+}
+
+/// A [`ValueRef`] statically known (via [`ValueRef::classify`]) to have [`ValueKind::InlineAsm`].
+///
+/// Derefs to the underlying [`ValueRef`], so every generic value accessor remains available.
+#[derive(Debug)]
+pub struct InlineAsmValue(ValueRef);
+
+impl Deref for InlineAsmValue {
+    type Target = ValueRef;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl GetRef for InlineAsmValue {
+    type RawRef = LLVMValueRef;
+    fn get_ref(&self) -> Self::RawRef {
+        self.0.get_ref()
+    }
+}
+
+impl FromValue for InlineAsmValue {
+    fn from_value(val: &ValueRef) -> Option<Self> {
+        let raw = unsafe { core::LLVMIsAInlineAsm(val.get_ref()) };
+        if raw.is_null() { None } else { Some(Self(ValueRef(raw))) }
+    }
+}
+
+/// A [`ValueRef`] statically known (via [`ValueRef::dyn_cast`]) to have [`ValueKind::Argument`].
+///
+/// Derefs to the underlying [`ValueRef`], so every generic value accessor remains available.
+#[derive(Debug)]
+pub struct ArgumentValue(ValueRef);
+
+impl Deref for ArgumentValue {
+    type Target = ValueRef;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl GetRef for ArgumentValue {
+    type RawRef = LLVMValueRef;
+    fn get_ref(&self) -> Self::RawRef {
+        self.0.get_ref()
+    }
+}
+
+impl FromValue for ArgumentValue {
+    fn from_value(val: &ValueRef) -> Option<Self> {
+        let raw = unsafe { core::LLVMIsAArgument(val.get_ref()) };
+        if raw.is_null() { None } else { Some(Self(ValueRef(raw))) }
+    }
+}
+
+/// A [`ValueRef`] statically known (via [`ValueRef::dyn_cast`]) to have [`ValueKind::BasicBlock`].
+///
+/// Derefs to the underlying [`ValueRef`], so every generic value accessor remains available.
+#[derive(Debug)]
+pub struct BasicBlockValue(ValueRef);
+
+impl Deref for BasicBlockValue {
+    type Target = ValueRef;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl GetRef for BasicBlockValue {
+    type RawRef = LLVMValueRef;
+    fn get_ref(&self) -> Self::RawRef {
+        self.0.get_ref()
+    }
+}
+
+impl FromValue for BasicBlockValue {
+    fn from_value(val: &ValueRef) -> Option<Self> {
+        let raw = unsafe { core::LLVMIsABasicBlock(val.get_ref()) };
+        if raw.is_null() { None } else { Some(Self(ValueRef(raw))) }
+    }
+}
+
+/// A [`ValueRef`] statically known (via [`ValueRef::dyn_cast`]) to have [`ValueKind::ConstantFP`].
+///
+/// Derefs to the underlying [`ValueRef`], so every generic value accessor remains available.
+#[derive(Debug)]
+pub struct ConstantFPValue(ValueRef);
+
+impl Deref for ConstantFPValue {
+    type Target = ValueRef;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl GetRef for ConstantFPValue {
+    type RawRef = LLVMValueRef;
+    fn get_ref(&self) -> Self::RawRef {
+        self.0.get_ref()
+    }
+}
+
+impl FromValue for ConstantFPValue {
+    fn from_value(val: &ValueRef) -> Option<Self> {
+        let raw = unsafe { core::LLVMIsAConstantFP(val.get_ref()) };
+        if raw.is_null() { None } else { Some(Self(ValueRef(raw))) }
+    }
+}
+
+/// A [`ValueRef`] statically known (via [`ValueRef::dyn_cast`]) to have [`ValueKind::ConstantExpr`].
+///
+/// Derefs to the underlying [`ValueRef`], so every generic value accessor remains available.
+#[derive(Debug)]
+pub struct ConstantExprValue(ValueRef);
+
+impl Deref for ConstantExprValue {
+    type Target = ValueRef;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl GetRef for ConstantExprValue {
+    type RawRef = LLVMValueRef;
+    fn get_ref(&self) -> Self::RawRef {
+        self.0.get_ref()
+    }
+}
+
+impl FromValue for ConstantExprValue {
+    fn from_value(val: &ValueRef) -> Option<Self> {
+        let raw = unsafe { core::LLVMIsAConstantExpr(val.get_ref()) };
+        if raw.is_null() { None } else { Some(Self(ValueRef(raw))) }
+    }
+}
+
+/// A statically typed view of a [`ValueRef`], produced by [`ValueRef::classify`].
+///
+/// Each variant wraps the original value in a newtype named after its [`ValueKind`]; kinds that
+/// do not (yet) have a dedicated variant are carried in [`Self::Other`] alongside their `ValueKind`,
+/// so classifying a value is always lossless.
+#[derive(Debug)]
+pub enum Value {
+    /// A function, as reported by [`ValueKind::Function`].
+    Function(FunctionValue),
+    /// A global variable, as reported by [`ValueKind::GlobalVariable`].
+    GlobalVariable(GlobalVariableValue),
+    /// An instruction, as reported by [`ValueKind::Instruction`].
+    Instruction(InstructionValue),
+    /// A constant integer, as reported by [`ValueKind::ConstantInt`].
+    ConstantInt(ConstantIntValue),
+    /// Inline assembly, as reported by [`ValueKind::InlineAsm`].
+    InlineAsm(InlineAsmValue),
+    /// Any `ValueKind` without a dedicated variant, paired with the kind it was classified as.
+    Other(ValueRef, ValueKind),
 }
@@ -144,7 +144,7 @@ pub fn get_used_value(u: &UseRef) -> ValueRef {
 /// - `None` if the index is out of bounds or the operand cannot be retrieved.
 #[must_use]
 pub fn get_operand(val: &ValueRef, index: u32) -> Option<ValueRef> {
-    let operand = unsafe { core::LLVMGetOperand(val.0, *CUint::from(index)) };
+    let operand = unsafe { core::LLVMGetOperand(val.0, *CUint::try_from(index).expect("value does not fit in c_uint")) };
     if operand.is_null() {
         None
     } else {
@@ -174,7 +174,7 @@ pub fn get_operand(val: &ValueRef, index: u32) -> Option<ValueRef> {
 /// - `None` if the index is out of bounds or the operand use cannot be retrieved.
 #[must_use]
 pub fn get_operand_use(val: &ValueRef, index: u32) -> Option<UseRef> {
-    let operand_use = unsafe { core::LLVMGetOperandUse(val.0, *CUint::from(index)) };
+    let operand_use = unsafe { core::LLVMGetOperandUse(val.0, *CUint::try_from(index).expect("value does not fit in c_uint")) };
     if operand_use.is_null() {
         None
     } else {
@@ -0,0 +1,111 @@
+//! Crate-wide error type for fallible conversions between Rust values and the C types LLVM's API
+//! expects.
+
+use std::fmt;
+
+/// Errors produced when converting between Rust values and LLVM's C API types.
+#[derive(Debug)]
+pub enum Error {
+    /// A Rust integer did not fit in the target C integer type (`c_uint`, `c_int`, `size_t`).
+    IntCast {
+        /// A string representation of the value that failed to convert.
+        value: String,
+        /// The name of the C type the value was being converted to, e.g. `"c_uint"`.
+        target: &'static str,
+    },
+    /// A Rust `&str` contained an interior NUL byte, so it could not become a C string.
+    NulInString(std::ffi::NulError),
+    /// A C string handed back by LLVM was not valid UTF-8.
+    NonUtf8FromLlvm(std::str::Utf8Error),
+    /// A constant could not be parsed from its string representation; see [`ConstParseError`].
+    ConstParse(ConstParseError),
+    /// A vector `getelementptr` was given vector-typed indices whose lane counts disagree with
+    /// each other or with the base pointer vector, mirroring LLVM's verifier rule that every
+    /// vector operand of a GEP must have the same element count.
+    GepVectorWidthMismatch {
+        /// The lane count established by the base pointer or an earlier vector-typed index.
+        expected: u32,
+        /// The lane count of the vector-typed index that disagreed with it.
+        found: u32,
+    },
+    /// A string did not parse as one of the `.ll`-syntax symbol-attribute keywords; see
+    /// [`ParseLinkageError`].
+    ParseLinkage(ParseLinkageError),
+    /// A value was used somewhere LLVM requires a constant (for example, a global variable's
+    /// initializer) but `LLVMIsConstant` reported it was not one.
+    NotAConstant {
+        /// What the value was being used as, e.g. `"global variable initializer"`.
+        context: &'static str,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IntCast { value, target } => {
+                write!(f, "value `{value}` does not fit in `{target}`")
+            }
+            Self::NulInString(err) => write!(f, "string contains an interior NUL byte: {err}"),
+            Self::NonUtf8FromLlvm(err) => write!(f, "LLVM returned a non-UTF-8 string: {err}"),
+            Self::ConstParse(err) => write!(f, "{err}"),
+            Self::GepVectorWidthMismatch { expected, found } => write!(
+                f,
+                "vector GEP index has {found} lanes, expected {expected} to match the other vector operands"
+            ),
+            Self::ParseLinkage(err) => write!(f, "{err}"),
+            Self::NotAConstant { context } => {
+                write!(f, "value is not a constant, which is required for {context}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Error returned when a string fails to parse into an LLVM constant integer or
+/// floating-point value.
+///
+/// This covers both a radix LLVM does not accept (only 2, 8, 10, and 16 are valid for
+/// integers) and text that LLVM's parser itself rejected, which surfaces as a null
+/// `LLVMValueRef` rather than an error code.
+#[derive(Debug)]
+pub struct ConstParseError {
+    /// The text that failed to parse.
+    pub text: String,
+    /// The radix it was parsed against, or `None` for a floating-point constant, which has
+    /// no radix.
+    pub radix: Option<u8>,
+}
+
+impl fmt::Display for ConstParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.radix {
+            Some(radix) => {
+                let text = &self.text;
+                write!(f, "`{text}` is not a valid base-{radix} integer literal")
+            }
+            None => write!(f, "`{}` is not a valid floating-point literal", self.text),
+        }
+    }
+}
+
+impl std::error::Error for ConstParseError {}
+
+/// Error returned when a string fails to parse as one of the `.ll`-syntax symbol-attribute
+/// keywords for `Linkage`, `Visibility`, `DLLStorageClass`, or `UnnamedAddr`.
+#[derive(Debug)]
+pub struct ParseLinkageError {
+    /// The text that failed to parse.
+    pub text: String,
+    /// The name of the enum the text was being parsed as, e.g. `"Linkage"`.
+    pub kind: &'static str,
+}
+
+impl fmt::Display for ParseLinkageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { text, kind } = self;
+        write!(f, "`{text}` is not a valid {kind} keyword")
+    }
+}
+
+impl std::error::Error for ParseLinkageError {}
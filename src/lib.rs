@@ -1,9 +1,10 @@
 #![deny(clippy::nursery, clippy::pedantic)]
 #![allow(clippy::module_name_repetitions, clippy::new_without_default)]
 pub mod basic_block;
-pub mod builder;
 pub mod core;
+pub mod error;
 
+use error::Error;
 use libc::{c_char, c_int, c_uint, size_t};
 use std::ops::{Deref, DerefMut};
 
@@ -19,10 +20,12 @@ pub trait GetRef {
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct CUint(c_uint);
 
-impl From<u32> for CUint {
-    fn from(value: u32) -> Self {
-        // Force to unwrap c_uint
-        Self(c_uint::try_from(value).expect("c_unit casting fail from u32"))
+impl TryFrom<u32> for CUint {
+    type Error = Error;
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        c_uint::try_from(value)
+            .map(Self)
+            .map_err(|_| Error::IntCast { value: value.to_string(), target: "c_uint" })
     }
 }
 
@@ -32,10 +35,12 @@ impl From<CUint> for u32 {
     }
 }
 
-impl From<usize> for CUint {
-    fn from(value: usize) -> Self {
-        // Force to unwrap c_uint
-        Self(c_uint::try_from(value).expect("c_uint casting fail from usize"))
+impl TryFrom<usize> for CUint {
+    type Error = Error;
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        c_uint::try_from(value)
+            .map(Self)
+            .map_err(|_| Error::IntCast { value: value.to_string(), target: "c_uint" })
     }
 }
 
@@ -56,10 +61,12 @@ impl DerefMut for CUint {
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct CInt(c_int);
 
-impl From<i32> for CInt {
-    fn from(value: i32) -> Self {
-        // Force to unwrap c_int
-        Self(c_int::try_from(value).expect("c_int casting fail from i32"))
+impl TryFrom<i32> for CInt {
+    type Error = Error;
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        c_int::try_from(value)
+            .map(Self)
+            .map_err(|_| Error::IntCast { value: value.to_string(), target: "c_int" })
     }
 }
 
@@ -80,10 +87,12 @@ impl Deref for CInt {
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct SizeT(size_t);
 
-impl From<usize> for SizeT {
-    fn from(value: usize) -> Self {
-        // Force to unwrap size_t
-        Self(size_t::try_from(value).expect("size_t casting fail from usize"))
+impl TryFrom<usize> for SizeT {
+    type Error = Error;
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        size_t::try_from(value)
+            .map(Self)
+            .map_err(|_| Error::IntCast { value: value.to_string(), target: "size_t" })
     }
 }
 
@@ -104,10 +113,12 @@ impl DerefMut for SizeT {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CString(std::ffi::CString);
 
-impl From<&str> for CString {
-    fn from(value: &str) -> Self {
-        // Force to unwrap `CString`
-        Self(std::ffi::CString::new(value).expect("CString casting fail from str"))
+impl TryFrom<&str> for CString {
+    type Error = Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        std::ffi::CString::new(value)
+            .map(Self)
+            .map_err(Error::NulInString)
     }
 }
 
@@ -130,6 +141,18 @@ impl<'a> CStr<'a> {
     pub unsafe fn new(value: *const c_char) -> Self {
         unsafe { Self(std::ffi::CStr::from_ptr(value)) }
     }
+
+    /// Fallibly convert this `CStr` into an owned, UTF-8 `String`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NonUtf8FromLlvm`] if the underlying bytes are not valid UTF-8.
+    pub fn try_to_string(&self) -> Result<String, Error> {
+        self.0
+            .to_str()
+            .map(ToString::to_string)
+            .map_err(Error::NonUtf8FromLlvm)
+    }
 }
 
 impl<'a> Deref for CStr<'a> {
@@ -142,10 +165,7 @@ impl<'a> Deref for CStr<'a> {
 #[allow(clippy::to_string_trait_impl)]
 impl<'a> ToString for CStr<'a> {
     fn to_string(&self) -> String {
-        self.0
-            .to_str()
-            .map(ToString::to_string)
-            .expect("Failed to convert CStr to String")
+        self.try_to_string().expect("Failed to convert CStr to String")
     }
 }
 